@@ -1,5 +1,18 @@
 use crate::config::Config;
-use crate::rpc::{AddressInfo, BlockInfo, BlockStats, NetworkInfo, RpcClient, TxInfo, TxSummary};
+use crate::rpc::{
+    chain_info, parse_block_id, AbiRegistry, AccountStateDiff, ActivityScan, AddressInfo,
+    BlockInfo, BlockStats, CallResult, CallTrace, ChainInfo, ContractCall, DecodedLog, LogPage,
+    LogQuery, Mempool, NetworkInfo, PoolTx, RpcError, RpcTransport, SharedClient, SignatureLookup,
+    SlotExpr, StorageRead, TokenFlow, TokenLookup, TokenScan, TxInfo, TxSummary,
+    DEFAULT_BLOCK_TIME,
+};
+use alloy::eips::BlockId;
+use alloy::primitives::{Address, B256};
+use anyhow::Context;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::AbortHandle;
 use tui_input::Input;
 
 #[derive(Debug, Clone)]
@@ -9,6 +22,14 @@ pub enum Screen {
     BlockResult(BlockResult),
     TxResult(TxResult),
     AddressResult(AddressResult),
+    LiveBlocks(LiveBlocksResult),
+    Mempool(MempoolResult),
+    Trace(TraceResult),
+    Signature(SignatureLookup),
+    Activity(ActivityResult),
+    Token(TokenResult),
+    LogQuery(LogQueryForm),
+    Logs(LogsResult),
     Error(String),
 }
 
@@ -24,19 +45,459 @@ pub struct BlockResult {
 #[derive(Debug, Clone)]
 pub struct TxResult {
     pub info: TxInfo,
-    pub selected_link: usize, // 0 = from, 1 = to, 2 = block, 3 = contract created, then transfers, then logs
+    pub selected_link: usize, // 0 = from, 1 = to, 2 = block, 3 = contract created, then transfers, logs, state changes
     pub transfer_scroll: usize, // Scroll offset for token transfers
     pub log_scroll: usize,    // Scroll offset for logs
+    pub state_scroll: usize,  // Scroll offset for state changes
+    pub state_diff: StateDiffStatus,
+}
+
+/// Whether the tx page's state changes have been traced. They're only
+/// fetched when asked for: most endpoints don't offer debug_traceTransaction,
+/// and providers that do charge a lot for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum StateDiffStatus {
+    #[default]
+    NotLoaded,
+    Loading,
+    Loaded,      // In info.state_changes
+    Unsupported, // The endpoint has no debug_traceTransaction
+    Failed(String),
 }
 
 // Max visible items in scrollable sections
 pub const MAX_VISIBLE_TRANSFERS: usize = 4;
 pub const MAX_VISIBLE_LOGS: usize = 3;
+pub const MAX_VISIBLE_STATE_CHANGES: usize = 3;
+
+impl TxResult {
+    /// Link index where the token transfers start, after from/to/block/contract
+    fn transfer_start(&self) -> usize {
+        1 + usize::from(self.info.to.is_some())
+            + usize::from(self.info.block_number.is_some())
+            + usize::from(self.info.contract_created.is_some())
+    }
+
+    /// Each log has 1 contract address + N address params
+    fn log_links(log: &DecodedLog) -> usize {
+        1 + log.decoded_params.iter().filter(|p| p.is_address).count()
+    }
+
+    fn log_start(&self) -> usize {
+        // Each token transfer has 3 addresses (from, to, token contract)
+        self.transfer_start() + self.info.token_transfers.len() * 3
+    }
+
+    fn state_start(&self) -> usize {
+        self.log_start() + self.info.logs.iter().map(Self::log_links).sum::<usize>()
+    }
+
+    /// Total navigable links; each changed account is one link
+    fn link_count(&self) -> usize {
+        self.state_start() + self.info.state_changes.len()
+    }
+
+    /// Scroll whichever section holds the selected link so it stays visible
+    fn scroll_to_selected(&mut self) {
+        fn scroll_into_view(scroll: &mut usize, idx: usize, visible: usize) {
+            if idx < *scroll {
+                *scroll = idx;
+            } else if idx >= *scroll + visible {
+                *scroll = idx - visible + 1;
+            }
+        }
+
+        let (log_start, state_start) = (self.log_start(), self.state_start());
+        let selected = self.selected_link;
+
+        if selected >= state_start {
+            let state_idx = selected - state_start;
+            scroll_into_view(&mut self.state_scroll, state_idx, MAX_VISIBLE_STATE_CHANGES);
+        } else if selected >= log_start {
+            // Find which log the selected link is in
+            let mut link_offset = log_start;
+            for (log_idx, log) in self.info.logs.iter().enumerate() {
+                link_offset += Self::log_links(log);
+                if selected < link_offset {
+                    scroll_into_view(&mut self.log_scroll, log_idx, MAX_VISIBLE_LOGS);
+                    break;
+                }
+            }
+        } else if selected >= self.transfer_start() {
+            let transfer_idx = (selected - self.transfer_start()) / 3;
+            scroll_into_view(
+                &mut self.transfer_scroll,
+                transfer_idx,
+                MAX_VISIBLE_TRANSFERS,
+            );
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct AddressResult {
     pub info: AddressInfo,
-    pub selected_link: usize, // 0 = proxy impl
+    pub selected_link: usize, // Index into the proxy's related addresses
+    pub storage: Option<StorageInspector>, // Open while inspecting storage slots
+    pub call: Option<CallConsole>, // Open while calling view functions
+    pub activity: Option<BlockPrompt>, // Open while choosing blocks to scan for activity
+}
+
+impl AddressResult {
+    /// Navigable proxy-related addresses (implementation, beacon, admin, facets)
+    fn link_count(&self) -> usize {
+        self.info
+            .proxy
+            .as_ref()
+            .map_or(0, |proxy| proxy.links().len())
+    }
+}
+
+/// Two text inputs with Tab moving between them: what to read, call or
+/// scan from, then the block to do it at. The address page's storage
+/// inspector, call console and activity prompt and the token page's window
+/// prompt all take their input this way.
+#[derive(Debug, Clone, Default)]
+pub struct BlockPrompt {
+    pub input: Input,
+    pub block_input: Input, // Empty = the page's block, or latest
+    pub block_focused: bool,
+    pub error: Option<String>,
+}
+
+impl BlockPrompt {
+    pub fn focused_input_mut(&mut self) -> &mut Input {
+        if self.block_focused {
+            &mut self.block_input
+        } else {
+            &mut self.input
+        }
+    }
+
+    /// The block typed in, or the page's block (latest if the page isn't
+    /// pinned to one) when it is empty
+    fn block_label(&self, page_block: Option<u64>) -> String {
+        match (self.block_input.value().trim(), page_block) {
+            ("", Some(number)) => number.to_string(),
+            ("", None) => "latest".to_string(),
+            (block, _) => block.to_string(),
+        }
+    }
+
+    /// The range typed in, from `input` to `block_input`, with `default_to`
+    /// for an empty end block
+    fn range(&self, default_to: BlockId) -> anyhow::Result<(Option<BlockId>, BlockId)> {
+        let from = match self.input.value().trim() {
+            "" => None,
+            from => Some(parse_block_id(from)?),
+        };
+        let to = match self.block_input.value().trim() {
+            "" => default_to,
+            to => parse_block_id(to)?,
+        };
+        Ok((from, to))
+    }
+}
+
+/// Which block prompt is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    Storage,
+    Call,
+    Activity,
+    TokenWindow,
+}
+
+/// Storage slot reader on the address page
+#[derive(Debug, Clone, Default)]
+pub struct StorageInspector {
+    pub prompt: BlockPrompt,     // Slot expression, then block
+    pub reads: Vec<StorageRead>, // Newest first
+    pub pending: bool,
+}
+
+// Number of storage reads kept in the inspector
+pub const STORAGE_READS_CAPACITY: usize = 3;
+
+/// A storage read to perform for the inspector
+#[derive(Debug, Clone)]
+pub struct StorageRequest {
+    pub address: Address,
+    pub slot: B256,
+    pub block: BlockId,
+    pub expr: String,
+    pub block_label: String,
+}
+
+/// Read-only contract call console on the address page
+#[derive(Debug, Clone, Default)]
+pub struct CallConsole {
+    pub prompt: BlockPrompt,      // Call, then block
+    pub results: Vec<CallResult>, // Newest first
+    pub pending: bool,
+}
+
+// Number of call results kept in the console
+pub const CALL_RESULTS_CAPACITY: usize = 3;
+
+/// An eth_call to perform for the call console
+#[derive(Debug, Clone)]
+pub struct CallRequest {
+    pub address: Address,
+    pub call: ContractCall,
+    pub block: BlockId,
+    pub block_label: String,
+}
+
+/// An activity scan to run for the address page
+#[derive(Debug, Clone)]
+pub struct ActivityRequest {
+    pub address: Address,
+    pub from: Option<BlockId>,
+    pub to: BlockId,
+}
+
+#[derive(Debug, Clone)]
+pub struct ActivityResult {
+    pub scan: ActivityScan,
+    pub selected_index: usize,
+    pub list_mode: bool, // true = transfers, false = holdings
+}
+
+impl ActivityResult {
+    /// Rows in the selected list
+    fn len(&self) -> usize {
+        if self.list_mode {
+            self.scan.events.len()
+        } else {
+            self.scan.holdings.len()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenResult {
+    pub scan: TokenScan,
+    pub selected_index: usize,
+    pub list_mode: bool, // true = transfers, false = top senders then top receivers
+    pub window: Option<BlockPrompt>, // Open while choosing another block window
+    pub lookup: Option<TokenLookupForm>, // Open while looking up balances and allowances
+}
+
+impl TokenResult {
+    /// Top senders followed by top receivers, as the accounts list selects them
+    pub fn accounts(&self) -> impl Iterator<Item = &TokenFlow> {
+        self.scan.senders.iter().chain(&self.scan.receivers)
+    }
+
+    /// Rows in the selected list
+    fn len(&self) -> usize {
+        if self.list_mode {
+            self.scan.transfers.len()
+        } else {
+            self.scan.senders.len() + self.scan.receivers.len()
+        }
+    }
+}
+
+/// A token page scan to run
+#[derive(Debug, Clone)]
+pub struct TokenRequest {
+    pub token: Address,
+    pub from: Option<BlockId>, // None = DEFAULT_TOKEN_RANGE blocks before the end
+    pub to: BlockId,
+}
+
+// Labels of the token lookup fields, in focus order
+pub const TOKEN_LOOKUP_FIELDS: [&str; 3] = ["Holder", "Spender", "Block"];
+
+/// Balance and allowance lookup on the token page
+#[derive(Debug, Clone, Default)]
+pub struct TokenLookupForm {
+    pub inputs: [Input; 3], // One per TOKEN_LOOKUP_FIELDS entry; empty spender = balance only
+    pub focus: usize,
+    pub results: Vec<TokenLookup>, // Newest first
+    pub error: Option<String>,
+    pub pending: bool,
+}
+
+// Number of lookups kept in the form
+pub const TOKEN_LOOKUPS_CAPACITY: usize = 3;
+
+impl TokenLookupForm {
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % self.inputs.len();
+    }
+
+    pub fn focus_prev(&mut self) {
+        self.focus = (self.focus + self.inputs.len() - 1) % self.inputs.len();
+    }
+
+    pub fn focused_input_mut(&mut self) -> &mut Input {
+        &mut self.inputs[self.focus]
+    }
+}
+
+/// A balance (and allowance) lookup to perform for the token page
+#[derive(Debug, Clone)]
+pub struct TokenLookupRequest {
+    pub token: Address,
+    pub holder: Address,
+    pub spender: Option<Address>,
+    pub block: BlockId,
+    pub block_label: String,
+}
+
+// Labels of the log query form fields, in focus order
+pub const LOG_QUERY_FIELDS: [&str; 7] = [
+    "Address", "Event", "Topic 1", "Topic 2", "Topic 3", "From", "To",
+];
+
+/// Event log query form
+#[derive(Debug, Clone, Default)]
+pub struct LogQueryForm {
+    pub inputs: [Input; 7], // One per LOG_QUERY_FIELDS entry
+    pub focus: usize,
+    pub error: Option<String>,
+}
+
+impl LogQueryForm {
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % self.inputs.len();
+    }
+
+    pub fn focus_prev(&mut self) {
+        self.focus = (self.focus + self.inputs.len() - 1) % self.inputs.len();
+    }
+
+    pub fn focused_input_mut(&mut self) -> &mut Input {
+        &mut self.inputs[self.focus]
+    }
+}
+
+/// A page of logs to fetch
+#[derive(Debug, Clone)]
+pub struct LogQueryRequest {
+    pub query: LogQuery,
+    pub from: Option<BlockId>, // None = DEFAULT_LOG_RANGE blocks before the end
+    pub to: BlockId,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogsResult {
+    pub query: LogQuery,
+    pub from_block: u64, // Lowest block the query covers
+    pub page: LogPage,   // Grows as older pages are loaded
+    pub selected_index: usize,
+    pub pending: bool, // An older page is being fetched
+    pub error: Option<String>,
+}
+
+impl LogsResult {
+    /// Whether blocks below the loaded pages are left to query
+    pub fn has_more(&self) -> bool {
+        self.page.scanned_from > self.from_block
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveBlocksResult {
+    pub blocks: Vec<BlockInfo>, // Newest first
+    pub selected_index: usize,  // 0 = follow the chain head
+}
+
+// Number of recent blocks kept on the live blocks screen
+pub const LIVE_BLOCKS_CAPACITY: usize = 50;
+
+/// Where the mempool screen's transactions come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MempoolSource {
+    #[default]
+    Loading,
+    TxPool,       // Polled txpool_content snapshots, by sender and nonce
+    Subscription, // newPendingTransactions, newest first
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MempoolResult {
+    pub source: MempoolSource,
+    pub pool: Mempool, // Counts are only known from txpool_status
+    pub selected_index: usize,
+    pub error: Option<String>,
+}
+
+// Number of announced transactions kept when following newPendingTransactions
+pub const MEMPOOL_FEED_CAPACITY: usize = 200;
+
+#[derive(Debug, Clone)]
+pub struct TraceResult {
+    pub tx_hash: String,
+    pub root: CallTrace,
+    pub collapsed: HashSet<Vec<usize>>, // Paths (child indices from the root) of folded frames
+    pub selected_index: usize,          // Index into visible_calls()
+    pub from_selected: bool,            // Link to the caller instead of the target
+}
+
+/// A call frame currently shown in the trace tree
+#[derive(Debug, Clone)]
+pub struct TraceRow<'a> {
+    pub path: Vec<usize>, // Child indices from the root; depth = path.len()
+    pub call: &'a CallTrace,
+    pub collapsed: bool,
+}
+
+impl TraceResult {
+    pub fn new(tx_hash: String, root: CallTrace) -> Self {
+        Self {
+            tx_hash,
+            root,
+            collapsed: HashSet::new(),
+            selected_index: 0,
+            from_selected: false,
+        }
+    }
+
+    /// Frames in depth-first order, skipping children of collapsed frames
+    pub fn visible_calls(&self) -> Vec<TraceRow<'_>> {
+        fn walk<'a>(
+            call: &'a CallTrace,
+            path: Vec<usize>,
+            collapsed: &HashSet<Vec<usize>>,
+            rows: &mut Vec<TraceRow<'a>>,
+        ) {
+            let is_collapsed = collapsed.contains(&path);
+            rows.push(TraceRow {
+                path: path.clone(),
+                call,
+                collapsed: is_collapsed,
+            });
+            if !is_collapsed {
+                for (i, child) in call.calls.iter().enumerate() {
+                    let mut child_path = path.clone();
+                    child_path.push(i);
+                    walk(child, child_path, collapsed, rows);
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        walk(&self.root, Vec::new(), &self.collapsed, &mut rows);
+        rows
+    }
+
+    /// Fold or unfold the selected frame
+    pub fn toggle_selected(&mut self) {
+        let Some(row) = self.visible_calls().into_iter().nth(self.selected_index) else {
+            return;
+        };
+        if row.call.calls.is_empty() {
+            return;
+        }
+        let path = row.path;
+        if !self.collapsed.remove(&path) {
+            self.collapsed.insert(path);
+        }
+    }
 }
 
 /// Navigable links from a screen
@@ -56,16 +517,23 @@ pub struct App {
     pub selected_history_index: Option<usize>,
     pub should_quit: bool,
     pub rpc_url: Option<String>,
-    pub rpc_client: Option<RpcClient>,
+    pub rpc_transport: Option<RpcTransport>,
+    /// Connection to `rpc_url` shared by every fetch
+    pub rpc_client: Option<SharedClient>,
     pub network_info: Option<NetworkInfo>,
+    pub abis: Arc<AbiRegistry>,
+    /// The fetch behind the loading screen, aborted if the user leaves it
+    fetch: Option<AbortHandle>,
 }
 
 impl App {
     pub fn new(config: Config) -> Self {
-        let rpc_url = config.rpc_url.clone();
-        let rpc_client = rpc_url.as_ref().and_then(|url| RpcClient::new(url).ok());
+        let rpc_url = config.rpc_url().map(String::from);
+        let rpc_transport = rpc_url
+            .as_ref()
+            .and_then(|url| RpcTransport::parse(url).ok());
 
-        Self {
+        let mut app = Self {
             config,
             screen: Screen::Home,
             history: Vec::new(),
@@ -74,9 +542,30 @@ impl App {
             selected_history_index: None,
             should_quit: false,
             rpc_url,
-            rpc_client,
+            rpc_transport,
+            rpc_client: None,
             network_info: None,
-        }
+            abis: Arc::default(),
+            fetch: None,
+        };
+        app.reset_rpc_client();
+        app
+    }
+
+    /// Decode with these ABIs from now on
+    pub fn set_abis(&mut self, abis: Arc<AbiRegistry>) {
+        self.abis = abis;
+        self.reset_rpc_client();
+    }
+
+    /// Start over with a client for the current `rpc_url`, abis and tokens.
+    /// Tasks still holding the old one finish with it.
+    fn reset_rpc_client(&mut self) {
+        self.rpc_client = self
+            .rpc_url
+            .as_deref()
+            .filter(|_| self.rpc_transport.is_some())
+            .map(|url| SharedClient::new(url, self.abis.clone(), self.config.tokens()));
     }
 
     pub fn submit_rpc(&mut self) -> Result<(), String> {
@@ -85,12 +574,13 @@ impl App {
             return Err("RPC URL cannot be empty".to_string());
         }
 
-        // Try to create a client to validate the URL
-        match RpcClient::new(&url) {
-            Ok(client) => {
-                self.rpc_client = Some(client);
+        // Validate the URL (connections are opened per request)
+        match RpcTransport::parse(&url) {
+            Ok(transport) => {
+                self.rpc_transport = Some(transport);
                 self.rpc_url = Some(url.clone());
                 let _ = self.config.set_rpc(url);
+                self.reset_rpc_client();
                 self.rpc_input.reset();
                 Ok(())
             }
@@ -99,15 +589,86 @@ impl App {
     }
 
     pub fn needs_rpc_setup(&self) -> bool {
-        self.rpc_client.is_none()
+        self.rpc_transport.is_none()
     }
 
     pub fn get_recent_searches(&self) -> &[String] {
-        &self.config.recent_searches
+        self.config.recent_searches()
+    }
+
+    /// Display name of the active RPC profile, if profiles are configured
+    pub fn profile_label(&self) -> Option<String> {
+        let (name, profile) = self.config.profile()?;
+        let label = profile.display_name.as_deref().unwrap_or(name);
+        Some(match profile.chain_id {
+            Some(chain_id) => format!("{label} (chain {chain_id})"),
+            None => label.to_string(),
+        })
+    }
+
+    /// Registry entry of the connected chain (from eth_chainId, or the
+    /// profile's `chain_id` until network info arrives)
+    pub fn chain(&self) -> Option<&'static ChainInfo> {
+        let chain_id = self
+            .network_info
+            .as_ref()
+            .and_then(|info| info.chain_id)
+            .or_else(|| self.config.profile().and_then(|(_, p)| p.chain_id))?;
+        chain_info(chain_id)
+    }
+
+    /// Chain id reported by the node when it differs from the profile's `chain_id`
+    pub fn chain_mismatch(&self) -> Option<u64> {
+        let expected = self.config.profile()?.1.chain_id?;
+        let actual = self.network_info.as_ref()?.chain_id?;
+        (actual != expected).then_some(actual)
+    }
+
+    pub fn native_symbol(&self) -> &str {
+        self.config
+            .native_symbol()
+            .or_else(|| self.chain().map(|chain| chain.native_symbol))
+            .unwrap_or("ETH")
+    }
+
+    /// How often the home screen refreshes network info: about once a block,
+    /// but no more than every 2s on fast chains
+    pub fn network_refresh_interval(&self) -> Duration {
+        self.block_time().max(Duration::from_secs(2))
+    }
+
+    /// How often the live blocks screen polls for new heads: a few times a block
+    pub fn live_poll_interval(&self) -> Duration {
+        (self.block_time() / 4).clamp(Duration::from_secs(1), Duration::from_secs(3))
+    }
+
+    fn block_time(&self) -> Duration {
+        self.chain()
+            .map_or(DEFAULT_BLOCK_TIME, |chain| chain.block_time)
+    }
+
+    /// Switch to the next RPC profile (in name order) and make it the default.
+    /// Returns false if there are no profiles to switch between.
+    pub fn switch_to_next_profile(&mut self) -> bool {
+        let Some(next) = self.config.next_profile_name() else {
+            return false;
+        };
+
+        // The switch applies even if the config file can't be written
+        let _ = self.config.switch_profile(&next);
+        self.rpc_url = self.config.rpc_url().map(String::from);
+        self.rpc_transport = self
+            .rpc_url
+            .as_ref()
+            .and_then(|url| RpcTransport::parse(url).ok());
+        self.reset_rpc_client();
+        self.network_info = None;
+        self.selected_history_index = None;
+        true
     }
 
     pub fn select_history_prev(&mut self) {
-        let len = self.config.recent_searches.len();
+        let len = self.config.recent_searches().len();
         if len == 0 {
             return;
         }
@@ -120,7 +681,7 @@ impl App {
     }
 
     pub fn select_history_next(&mut self) {
-        let len = self.config.recent_searches.len();
+        let len = self.config.recent_searches().len();
         if len == 0 {
             return;
         }
@@ -134,7 +695,7 @@ impl App {
 
     pub fn get_selected_history_query(&self) -> Option<String> {
         self.selected_history_index
-            .and_then(|i| self.config.recent_searches.get(i).cloned())
+            .and_then(|i| self.config.recent_searches().get(i).cloned())
     }
 
     pub fn clear_history_selection(&mut self) {
@@ -143,15 +704,15 @@ impl App {
 
     pub fn delete_selected_history(&mut self) {
         if let Some(idx) = self.selected_history_index {
-            if idx < self.config.recent_searches.len() {
-                self.config.recent_searches.remove(idx);
-                let _ = self.config.save();
+            if idx < self.config.recent_searches().len() {
+                let _ = self.config.remove_recent_search(idx);
 
                 // Adjust selection
-                if self.config.recent_searches.is_empty() {
+                let len = self.config.recent_searches().len();
+                if len == 0 {
                     self.selected_history_index = None;
-                } else if idx >= self.config.recent_searches.len() {
-                    self.selected_history_index = Some(self.config.recent_searches.len() - 1);
+                } else if idx >= len {
+                    self.selected_history_index = Some(len - 1);
                 }
             }
         }
@@ -177,6 +738,7 @@ impl App {
     }
 
     pub fn go_back(&mut self) -> bool {
+        self.cancel_fetch();
         if let Some(prev) = self.history.pop() {
             self.screen = prev;
             true
@@ -187,11 +749,14 @@ impl App {
     }
 
     pub fn go_home(&mut self) {
+        self.cancel_fetch();
         self.history.clear();
         self.screen = Screen::Home;
     }
 
     pub fn set_loading(&mut self, msg: &str) {
+        // A new fetch supersedes any still running
+        self.cancel_fetch();
         // Save current screen to history before showing loading (if it's a navigable screen)
         if !matches!(
             self.screen,
@@ -202,6 +767,18 @@ impl App {
         self.screen = Screen::Loading(msg.to_string());
     }
 
+    /// Remember the task fetching for the loading screen, so leaving the
+    /// screen (or starting another fetch) can abort it
+    pub fn track_fetch(&mut self, fetch: AbortHandle) {
+        self.fetch = Some(fetch);
+    }
+
+    fn cancel_fetch(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.abort();
+        }
+    }
+
     pub fn set_error(&mut self, msg: String) {
         // Save current screen to history before showing error (if it's a navigable screen)
         if !matches!(
@@ -234,22 +811,672 @@ impl App {
             selected_link: 0,
             transfer_scroll: 0,
             log_scroll: 0,
+            state_scroll: 0,
+            state_diff: StateDiffStatus::NotLoaded,
         }));
     }
 
+    /// Hash of the mined transaction on screen, if its state changes can be
+    /// traced and aren't already loaded or loading; marks them as loading
+    pub fn state_diff_request(&mut self) -> Option<String> {
+        let Screen::TxResult(result) = &mut self.screen else {
+            return None;
+        };
+        if result.info.block_number.is_none()
+            || matches!(
+                result.state_diff,
+                StateDiffStatus::Loaded | StateDiffStatus::Loading
+            )
+        {
+            return None;
+        }
+        result.state_diff = StateDiffStatus::Loading;
+        Some(result.info.hash.clone())
+    }
+
+    /// Show traced state changes, if the transaction is still on screen
+    pub fn set_state_diff(&mut self, hash: &str, result: anyhow::Result<Vec<AccountStateDiff>>) {
+        let Screen::TxResult(tx) = &mut self.screen else {
+            return;
+        };
+        if tx.info.hash != hash {
+            return;
+        }
+        tx.state_diff = match result {
+            Ok(changes) => {
+                tx.info.state_changes = changes;
+                StateDiffStatus::Loaded
+            }
+            Err(e) => match RpcError::of(&e) {
+                Some(RpcError::Unsupported(_)) => StateDiffStatus::Unsupported,
+                _ => StateDiffStatus::Failed(format!("{e:#}")),
+            },
+        };
+    }
+
     pub fn set_address_result(&mut self, info: AddressInfo) {
         self.navigate_to(Screen::AddressResult(AddressResult {
             info,
             selected_link: 0,
+            storage: None,
+            call: None,
+            activity: None,
         }));
     }
 
+    /// The open block prompt, which takes text input until it is closed
+    pub fn block_prompt_mut(&mut self) -> Option<(PromptKind, &mut BlockPrompt)> {
+        match &mut self.screen {
+            Screen::AddressResult(result) => {
+                if let Some(inspector) = &mut result.storage {
+                    Some((PromptKind::Storage, &mut inspector.prompt))
+                } else if let Some(console) = &mut result.call {
+                    Some((PromptKind::Call, &mut console.prompt))
+                } else {
+                    let prompt = result.activity.as_mut();
+                    prompt.map(|prompt| (PromptKind::Activity, prompt))
+                }
+            }
+            Screen::Token(result) => {
+                let prompt = result.window.as_mut();
+                prompt.map(|prompt| (PromptKind::TokenWindow, prompt))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn close_block_prompt(&mut self) {
+        match &mut self.screen {
+            Screen::AddressResult(result) => {
+                result.storage = None;
+                result.call = None;
+                result.activity = None;
+            }
+            Screen::Token(result) => result.window = None,
+            _ => {}
+        }
+    }
+
+    /// Open the storage inspector on the address page
+    pub fn open_storage_inspector(&mut self) {
+        if let Screen::AddressResult(result) = &mut self.screen {
+            result.call = None;
+            result.activity = None;
+            result.storage.get_or_insert_with(StorageInspector::default);
+        }
+    }
+
+    pub fn storage_inspector_mut(&mut self) -> Option<&mut StorageInspector> {
+        match &mut self.screen {
+            Screen::AddressResult(result) => result.storage.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Parse the inspector's inputs into a storage read. Parse errors are
+    /// shown in the inspector and nothing is returned.
+    pub fn submit_storage_query(&mut self) -> Option<StorageRequest> {
+        let Screen::AddressResult(result) = &mut self.screen else {
+            return None;
+        };
+        let address = result.info.address;
+        let page_block = result.info.block;
+        let inspector = result.storage.as_mut()?;
+        if inspector.pending {
+            return None;
+        }
+
+        let prompt = &mut inspector.prompt;
+        let expr = prompt.input.value().trim().to_string();
+        let block_label = prompt.block_label(page_block);
+        let parsed = SlotExpr::parse(&expr).and_then(|slot| {
+            let block = parse_block_id(&block_label)?;
+            Ok((slot.slot(), block))
+        });
+        match parsed {
+            Ok((slot, block)) => {
+                prompt.error = None;
+                inspector.pending = true;
+                Some(StorageRequest {
+                    address,
+                    slot,
+                    block,
+                    expr,
+                    block_label,
+                })
+            }
+            Err(e) => {
+                prompt.error = Some(format!("{e:#}"));
+                None
+            }
+        }
+    }
+
+    /// Show a finished storage read, if the inspector for that address is still open
+    pub fn set_storage_read(&mut self, address: Address, read: Result<StorageRead, String>) {
+        let Screen::AddressResult(result) = &mut self.screen else {
+            return;
+        };
+        if result.info.address != address {
+            return;
+        }
+        let Some(inspector) = result.storage.as_mut() else {
+            return;
+        };
+        inspector.pending = false;
+        match read {
+            Ok(read) => {
+                inspector.prompt.error = None;
+                inspector.reads.insert(0, read);
+                inspector.reads.truncate(STORAGE_READS_CAPACITY);
+            }
+            Err(e) => inspector.prompt.error = Some(e),
+        }
+    }
+
+    /// Open the call console on the address page
+    pub fn open_call_console(&mut self) {
+        if let Screen::AddressResult(result) = &mut self.screen {
+            result.storage = None;
+            result.activity = None;
+            result.call.get_or_insert_with(CallConsole::default);
+        }
+    }
+
+    pub fn call_console_mut(&mut self) -> Option<&mut CallConsole> {
+        match &mut self.screen {
+            Screen::AddressResult(result) => result.call.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Parse the console's inputs into a call. Parse errors are shown in the
+    /// console and nothing is returned.
+    pub fn submit_call(&mut self) -> Option<CallRequest> {
+        let Screen::AddressResult(result) = &mut self.screen else {
+            return None;
+        };
+        let address = result.info.address;
+        let page_block = result.info.block;
+        let console = result.call.as_mut()?;
+        if console.pending {
+            return None;
+        }
+
+        let prompt = &mut console.prompt;
+        let block_label = prompt.block_label(page_block);
+        let parsed = ContractCall::parse(prompt.input.value()).and_then(|call| {
+            let block = parse_block_id(&block_label)?;
+            Ok((call, block))
+        });
+        match parsed {
+            Ok((call, block)) => {
+                prompt.error = None;
+                console.pending = true;
+                Some(CallRequest {
+                    address,
+                    call,
+                    block,
+                    block_label,
+                })
+            }
+            Err(e) => {
+                prompt.error = Some(format!("{e:#}"));
+                None
+            }
+        }
+    }
+
+    /// Show a finished call, if the console for that address is still open
+    pub fn set_call_result(&mut self, address: Address, call: Result<CallResult, String>) {
+        let Screen::AddressResult(result) = &mut self.screen else {
+            return;
+        };
+        if result.info.address != address {
+            return;
+        }
+        let Some(console) = result.call.as_mut() else {
+            return;
+        };
+        console.pending = false;
+        match call {
+            Ok(call) => {
+                console.prompt.error = None;
+                console.results.insert(0, call);
+                console.results.truncate(CALL_RESULTS_CAPACITY);
+            }
+            Err(e) => console.prompt.error = Some(e),
+        }
+    }
+
+    /// Open the activity scan prompt on the address page
+    pub fn open_activity_prompt(&mut self) {
+        if let Screen::AddressResult(result) = &mut self.screen {
+            result.storage = None;
+            result.call = None;
+            result.activity.get_or_insert_with(BlockPrompt::default);
+        }
+    }
+
+    /// Parse the prompt's block range into a scan and close the prompt.
+    /// Parse errors are shown in the prompt and nothing is returned.
+    pub fn submit_activity_scan(&mut self) -> Option<ActivityRequest> {
+        let Screen::AddressResult(result) = &mut self.screen else {
+            return None;
+        };
+        let address = result.info.address;
+        let page_block = result.info.block;
+        let prompt = result.activity.as_mut()?;
+
+        match prompt.range(page_block.map_or(BlockId::latest(), BlockId::number)) {
+            Ok((from, to)) => {
+                result.activity = None;
+                Some(ActivityRequest { address, from, to })
+            }
+            Err(e) => {
+                prompt.error = Some(format!("{e:#}"));
+                None
+            }
+        }
+    }
+
+    pub fn set_activity_result(&mut self, scan: ActivityScan) {
+        self.navigate_to(Screen::Activity(ActivityResult {
+            scan,
+            selected_index: 0,
+            list_mode: true,
+        }));
+    }
+
+    /// The token page scan for the ERC-20 contract on the address page,
+    /// over the DEFAULT_TOKEN_RANGE blocks up to the page's block
+    pub fn token_page_request(&self) -> Option<TokenRequest> {
+        let Screen::AddressResult(result) = &self.screen else {
+            return None;
+        };
+        result.info.token_info.as_ref()?;
+        Some(TokenRequest {
+            token: result.info.address,
+            from: None,
+            to: result.info.block.map_or(BlockId::latest(), BlockId::number),
+        })
+    }
+
+    pub fn set_token_result(&mut self, scan: TokenScan) {
+        self.navigate_to(Screen::Token(TokenResult {
+            scan,
+            selected_index: 0,
+            list_mode: true,
+            window: None,
+            lookup: None,
+        }));
+    }
+
+    /// Open the block window prompt on the token page, filled in with the
+    /// current window
+    pub fn open_token_window(&mut self) {
+        if let Screen::Token(result) = &mut self.screen {
+            result.lookup = None;
+            if result.window.is_none() {
+                result.window = Some(BlockPrompt {
+                    input: result.scan.from_block.to_string().into(),
+                    block_input: result.scan.to_block.to_string().into(),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    /// Parse the window prompt into a new scan of the token and close the
+    /// prompt. Parse errors are shown in the prompt and nothing is returned.
+    pub fn submit_token_window(&mut self) -> Option<TokenRequest> {
+        let Screen::Token(result) = &mut self.screen else {
+            return None;
+        };
+        let token = result.scan.token;
+        let prompt = result.window.as_mut()?;
+
+        match prompt.range(BlockId::latest()) {
+            Ok((from, to)) => {
+                result.window = None;
+                Some(TokenRequest { token, from, to })
+            }
+            Err(e) => {
+                prompt.error = Some(format!("{e:#}"));
+                None
+            }
+        }
+    }
+
+    /// Open the balance and allowance lookup on the token page
+    pub fn open_token_lookup(&mut self) {
+        if let Screen::Token(result) = &mut self.screen {
+            result.window = None;
+            result.lookup.get_or_insert_with(TokenLookupForm::default);
+        }
+    }
+
+    pub fn close_token_lookup(&mut self) {
+        if let Screen::Token(result) = &mut self.screen {
+            result.lookup = None;
+        }
+    }
+
+    pub fn token_lookup_mut(&mut self) -> Option<&mut TokenLookupForm> {
+        match &mut self.screen {
+            Screen::Token(result) => result.lookup.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Parse the lookup form's inputs. Parse errors are shown in the form and
+    /// nothing is returned.
+    pub fn submit_token_lookup(&mut self) -> Option<TokenLookupRequest> {
+        let Screen::Token(result) = &mut self.screen else {
+            return None;
+        };
+        let token = result.scan.token;
+        let form = result.lookup.as_mut()?;
+        if form.pending {
+            return None;
+        }
+
+        let [holder, spender, block] = form.inputs.each_ref().map(|input| input.value().trim());
+        let block_label = match block {
+            "" => "latest".to_string(),
+            block => block.to_string(),
+        };
+        let parsed = (|| {
+            let holder: Address = holder
+                .parse()
+                .with_context(|| format!("Invalid holder address: {holder}"))?;
+            let spender: Option<Address> = match spender {
+                "" => None,
+                spender => Some(
+                    spender
+                        .parse()
+                        .with_context(|| format!("Invalid spender address: {spender}"))?,
+                ),
+            };
+            let block = parse_block_id(&block_label)?;
+            anyhow::Ok((holder, spender, block))
+        })();
+        match parsed {
+            Ok((holder, spender, block)) => {
+                form.error = None;
+                form.pending = true;
+                Some(TokenLookupRequest {
+                    token,
+                    holder,
+                    spender,
+                    block,
+                    block_label,
+                })
+            }
+            Err(e) => {
+                form.error = Some(format!("{e:#}"));
+                None
+            }
+        }
+    }
+
+    /// Show a finished lookup, if the form for that token is still open
+    pub fn set_token_lookup(&mut self, token: Address, lookup: Result<TokenLookup, String>) {
+        let Screen::Token(result) = &mut self.screen else {
+            return;
+        };
+        if result.scan.token != token {
+            return;
+        }
+        let Some(form) = result.lookup.as_mut() else {
+            return;
+        };
+        form.pending = false;
+        match lookup {
+            Ok(lookup) => {
+                form.error = None;
+                form.results.insert(0, lookup);
+                form.results.truncate(TOKEN_LOOKUPS_CAPACITY);
+            }
+            Err(e) => form.error = Some(e),
+        }
+    }
+
+    pub fn open_log_query(&mut self) {
+        self.navigate_to(Screen::LogQuery(LogQueryForm::default()));
+    }
+
+    /// Open the log query form for the contract on the address page
+    pub fn open_address_log_query(&mut self) {
+        let Screen::AddressResult(result) = &self.screen else {
+            return;
+        };
+        let mut form = LogQueryForm::default();
+        form.inputs[0] = format!("{:?}", result.info.address).into();
+        form.focus = 1;
+        self.navigate_to(Screen::LogQuery(form));
+    }
+
+    pub fn log_query_form_mut(&mut self) -> Option<&mut LogQueryForm> {
+        match &mut self.screen {
+            Screen::LogQuery(form) => Some(form),
+            _ => None,
+        }
+    }
+
+    /// Parse the log query form. Errors are shown in the form and nothing is
+    /// returned.
+    pub fn submit_log_query(&mut self) -> Option<LogQueryRequest> {
+        let form = self.log_query_form_mut()?;
+        let [address, event, topic1, topic2, topic3, from, to] =
+            form.inputs.each_ref().map(|input| input.value().trim());
+
+        let parsed = (|| {
+            let query = LogQuery::parse(address, event, [topic1, topic2, topic3])?;
+            let from = match from {
+                "" => None,
+                from => Some(parse_block_id(from)?),
+            };
+            let to = match to {
+                "" => BlockId::latest(),
+                to => parse_block_id(to)?,
+            };
+            anyhow::Ok(LogQueryRequest { query, from, to })
+        })();
+        match parsed {
+            Ok(request) => {
+                form.error = None;
+                Some(request)
+            }
+            Err(e) => {
+                form.error = Some(format!("{e:#}"));
+                None
+            }
+        }
+    }
+
+    pub fn set_logs_result(&mut self, query: LogQuery, from_block: u64, page: LogPage) {
+        self.navigate_to(Screen::Logs(LogsResult {
+            query,
+            from_block,
+            page,
+            selected_index: 0,
+            pending: false,
+            error: None,
+        }));
+    }
+
+    /// The next older page of the logs on screen, unless one is already
+    /// being fetched or the whole range is loaded
+    pub fn next_logs_page(&mut self) -> Option<LogQueryRequest> {
+        let Screen::Logs(result) = &mut self.screen else {
+            return None;
+        };
+        if result.pending || !result.has_more() {
+            return None;
+        }
+        result.pending = true;
+        result.error = None;
+        Some(LogQueryRequest {
+            query: result.query.clone(),
+            from: Some(BlockId::number(result.from_block)),
+            to: BlockId::number(result.page.scanned_from - 1),
+        })
+    }
+
+    /// Append an older page to the logs it was fetched for, which may have
+    /// been left for a transaction in the meantime
+    pub fn append_logs_page(&mut self, page: Result<LogPage, String>) {
+        let Some(result) = std::iter::once(&mut self.screen)
+            .chain(self.history.iter_mut().rev())
+            .find_map(|screen| match screen {
+                Screen::Logs(result) if result.pending => Some(result),
+                _ => None,
+            })
+        else {
+            return;
+        };
+        result.pending = false;
+        match page {
+            Ok(page) if page.to_block + 1 == result.page.scanned_from => {
+                result.page.entries.extend(page.entries);
+                result.page.scanned_from = page.scanned_from;
+            }
+            Ok(_) => {}
+            Err(e) => result.error = Some(e),
+        }
+    }
+
+    /// Replace the loading message, if still loading
+    pub fn update_loading(&mut self, msg: String) {
+        if let Screen::Loading(current) = &mut self.screen {
+            *current = msg;
+        }
+    }
+
+    pub fn set_signature_result(&mut self, lookup: SignatureLookup) {
+        self.navigate_to(Screen::Signature(lookup));
+    }
+
+    pub fn set_trace_result(&mut self, tx_hash: String, root: CallTrace) {
+        self.navigate_to(Screen::Trace(TraceResult::new(tx_hash, root)));
+    }
+
+    /// Hash of the transaction on screen, if any
+    pub fn current_tx_hash(&self) -> Option<String> {
+        match &self.screen {
+            Screen::TxResult(result) => Some(result.info.hash.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn open_live_blocks(&mut self) {
+        self.navigate_to(Screen::LiveBlocks(LiveBlocksResult::default()));
+    }
+
+    /// Add newly seen blocks (newest first) to the live blocks screen
+    pub fn push_live_blocks(&mut self, new_blocks: Vec<BlockInfo>) {
+        let Screen::LiveBlocks(result) = &mut self.screen else {
+            return;
+        };
+
+        // Ignore blocks we already have (e.g. from a poll that overlapped the last one)
+        let head = result.blocks.first().map(|b| b.number);
+        let fresh: Vec<BlockInfo> = new_blocks
+            .into_iter()
+            .filter(|b| head.is_none_or(|h| b.number > h))
+            .collect();
+
+        // Keep the selection on the same block unless we're following the head
+        if result.selected_index > 0 {
+            result.selected_index =
+                (result.selected_index + fresh.len()).min(LIVE_BLOCKS_CAPACITY - 1);
+        }
+
+        result.blocks.splice(0..0, fresh);
+        result.blocks.truncate(LIVE_BLOCKS_CAPACITY);
+    }
+
+    /// Newest block number shown on the live blocks screen
+    pub fn live_head(&self) -> Option<u64> {
+        match &self.screen {
+            Screen::LiveBlocks(result) => result.blocks.first().map(|b| b.number),
+            _ => None,
+        }
+    }
+
+    pub fn is_on_live_blocks(&self) -> bool {
+        matches!(self.screen, Screen::LiveBlocks(_))
+    }
+
+    pub fn open_mempool(&mut self) {
+        self.navigate_to(Screen::Mempool(MempoolResult::default()));
+    }
+
+    /// Source of the mempool screen, if it is open
+    pub fn mempool_source(&self) -> Option<MempoolSource> {
+        match &self.screen {
+            Screen::Mempool(result) => Some(result.source),
+            _ => None,
+        }
+    }
+
+    /// Replace the mempool screen with a new txpool snapshot, keeping the
+    /// selection on the same transaction while it is still pending
+    pub fn set_mempool(&mut self, pool: Mempool) {
+        let Screen::Mempool(result) = &mut self.screen else {
+            return;
+        };
+        let selected = result.pool.txs.get(result.selected_index).map(|tx| tx.hash);
+        result.selected_index = selected
+            .and_then(|hash| pool.txs.iter().position(|tx| tx.hash == hash))
+            .unwrap_or(result.selected_index.min(pool.txs.len().saturating_sub(1)));
+        result.source = MempoolSource::TxPool;
+        result.pool = pool;
+        result.error = None;
+    }
+
+    /// Show an error on the mempool screen; a txpool snapshot already shown
+    /// stays up
+    pub fn set_mempool_error(&mut self, error: String) {
+        if let Screen::Mempool(result) = &mut self.screen {
+            result.error = Some(error);
+        }
+    }
+
+    /// Switch the mempool screen to following newPendingTransactions
+    pub fn follow_pending_transactions(&mut self) {
+        if let Screen::Mempool(result) = &mut self.screen {
+            result.source = MempoolSource::Subscription;
+            result.error = None;
+        }
+    }
+
+    /// Add a newly announced transaction to the mempool screen
+    pub fn push_pending_tx(&mut self, tx: PoolTx) {
+        let Screen::Mempool(result) = &mut self.screen else {
+            return;
+        };
+        if result.source != MempoolSource::Subscription
+            || result.pool.txs.iter().any(|t| t.hash == tx.hash)
+        {
+            return;
+        }
+
+        // Keep the selection on the same transaction unless we're following the newest
+        if result.selected_index > 0 {
+            result.selected_index = (result.selected_index + 1).min(MEMPOOL_FEED_CAPACITY - 1);
+        }
+        result.pool.txs.insert(0, tx);
+        result.pool.txs.truncate(MEMPOOL_FEED_CAPACITY);
+    }
+
     pub fn set_network_info(&mut self, info: NetworkInfo) {
         self.network_info = Some(info);
     }
 
     pub fn has_rpc(&self) -> bool {
-        self.rpc_client.is_some()
+        self.rpc_transport.is_some()
     }
 
     pub fn is_on_home(&self) -> bool {
@@ -263,75 +1490,37 @@ impl App {
     /// Move selection up
     pub fn select_prev(&mut self) {
         match &mut self.screen {
-            Screen::BlockResult(result) => {
-                if result.list_mode
-                    && result.selected_index > 0 {
-                        result.selected_index -= 1;
-                    }
+            Screen::BlockResult(result) if result.list_mode && result.selected_index > 0 => {
+                result.selected_index -= 1;
             }
             Screen::TxResult(result) => {
-                // Calculate total navigable links
-                let mut max = 1; // from
-                if result.info.to.is_some() {
-                    max += 1; // to
-                }
-                if result.info.block_number.is_some() {
-                    max += 1;
-                }
-                if result.info.contract_created.is_some() {
-                    max += 1;
-                }
-                let transfer_start = max;
-                // Each token transfer has 3 addresses (from, to, token contract)
-                max += result.info.token_transfers.len() * 3;
-                let log_start = max;
-                // Each log has 1 contract address + N address params
-                for log in &result.info.logs {
-                    max += 1; // contract address
-                    max += log.decoded_params.iter().filter(|p| p.is_address).count();
-                }
-
                 if result.selected_link > 0 {
                     result.selected_link -= 1;
                 } else {
-                    result.selected_link = max - 1;
-                }
-
-                // Auto-scroll transfers
-                if result.selected_link >= transfer_start && result.selected_link < log_start {
-                    let transfer_idx = (result.selected_link - transfer_start) / 3;
-                    if transfer_idx < result.transfer_scroll {
-                        result.transfer_scroll = transfer_idx;
-                    } else if transfer_idx >= result.transfer_scroll + MAX_VISIBLE_TRANSFERS {
-                        result.transfer_scroll = transfer_idx - MAX_VISIBLE_TRANSFERS + 1;
-                    }
-                }
-
-                // Auto-scroll logs - find which log the selected link is in
-                if result.selected_link >= log_start {
-                    let mut link_offset = log_start;
-                    for (log_idx, log) in result.info.logs.iter().enumerate() {
-                        let links_in_log =
-                            1 + log.decoded_params.iter().filter(|p| p.is_address).count();
-                        if result.selected_link < link_offset + links_in_log {
-                            // Found the log
-                            if log_idx < result.log_scroll {
-                                result.log_scroll = log_idx;
-                            } else if log_idx >= result.log_scroll + MAX_VISIBLE_LOGS {
-                                result.log_scroll = log_idx - MAX_VISIBLE_LOGS + 1;
-                            }
-                            break;
-                        }
-                        link_offset += links_in_log;
-                    }
+                    result.selected_link = result.link_count() - 1;
                 }
+                result.scroll_to_selected();
+            }
+            Screen::LiveBlocks(result) if result.selected_index > 0 => {
+                result.selected_index -= 1;
+            }
+            Screen::Mempool(result) if result.selected_index > 0 => {
+                result.selected_index -= 1;
+            }
+            Screen::Trace(result) if result.selected_index > 0 => {
+                result.selected_index -= 1;
+            }
+            Screen::Activity(result) if result.selected_index > 0 => {
+                result.selected_index -= 1;
+            }
+            Screen::Token(result) if result.selected_index > 0 => {
+                result.selected_index -= 1;
+            }
+            Screen::Logs(result) if result.selected_index > 0 => {
+                result.selected_index -= 1;
             }
             Screen::AddressResult(result) => {
-                let max = if result.info.proxy_impl.is_some() {
-                    1
-                } else {
-                    0
-                };
+                let max = result.link_count();
                 if max > 0 {
                     if result.selected_link > 0 {
                         result.selected_link -= 1;
@@ -347,71 +1536,35 @@ impl App {
     /// Move selection down
     pub fn select_next(&mut self) {
         match &mut self.screen {
-            Screen::BlockResult(result) => {
-                if result.list_mode && !result.transactions.is_empty()
-                    && result.selected_index < result.transactions.len() - 1 {
-                        result.selected_index += 1;
-                    }
+            Screen::BlockResult(result)
+                if result.list_mode && result.selected_index + 1 < result.transactions.len() =>
+            {
+                result.selected_index += 1;
             }
             Screen::TxResult(result) => {
-                // Calculate total navigable links
-                let mut max = 1; // from
-                if result.info.to.is_some() {
-                    max += 1; // to
-                }
-                if result.info.block_number.is_some() {
-                    max += 1;
-                }
-                if result.info.contract_created.is_some() {
-                    max += 1;
-                }
-                let transfer_start = max;
-                // Each token transfer has 3 addresses (from, to, token contract)
-                max += result.info.token_transfers.len() * 3;
-                let log_start = max;
-                // Each log has 1 contract address + N address params
-                for log in &result.info.logs {
-                    max += 1; // contract address
-                    max += log.decoded_params.iter().filter(|p| p.is_address).count();
-                }
-
-                result.selected_link = (result.selected_link + 1) % max;
-
-                // Auto-scroll transfers
-                if result.selected_link >= transfer_start && result.selected_link < log_start {
-                    let transfer_idx = (result.selected_link - transfer_start) / 3;
-                    if transfer_idx < result.transfer_scroll {
-                        result.transfer_scroll = transfer_idx;
-                    } else if transfer_idx >= result.transfer_scroll + MAX_VISIBLE_TRANSFERS {
-                        result.transfer_scroll = transfer_idx - MAX_VISIBLE_TRANSFERS + 1;
-                    }
-                }
-
-                // Auto-scroll logs - find which log the selected link is in
-                if result.selected_link >= log_start {
-                    let mut link_offset = log_start;
-                    for (log_idx, log) in result.info.logs.iter().enumerate() {
-                        let links_in_log =
-                            1 + log.decoded_params.iter().filter(|p| p.is_address).count();
-                        if result.selected_link < link_offset + links_in_log {
-                            // Found the log
-                            if log_idx < result.log_scroll {
-                                result.log_scroll = log_idx;
-                            } else if log_idx >= result.log_scroll + MAX_VISIBLE_LOGS {
-                                result.log_scroll = log_idx - MAX_VISIBLE_LOGS + 1;
-                            }
-                            break;
-                        }
-                        link_offset += links_in_log;
-                    }
-                }
+                result.selected_link = (result.selected_link + 1) % result.link_count();
+                result.scroll_to_selected();
+            }
+            Screen::LiveBlocks(result) if result.selected_index + 1 < result.blocks.len() => {
+                result.selected_index += 1;
+            }
+            Screen::Mempool(result) if result.selected_index + 1 < result.pool.txs.len() => {
+                result.selected_index += 1;
+            }
+            Screen::Trace(result) if result.selected_index + 1 < result.visible_calls().len() => {
+                result.selected_index += 1;
+            }
+            Screen::Activity(result) if result.selected_index + 1 < result.len() => {
+                result.selected_index += 1;
+            }
+            Screen::Token(result) if result.selected_index + 1 < result.len() => {
+                result.selected_index += 1;
+            }
+            Screen::Logs(result) if result.selected_index + 1 < result.page.entries.len() => {
+                result.selected_index += 1;
             }
             Screen::AddressResult(result) => {
-                let max = if result.info.proxy_impl.is_some() {
-                    1
-                } else {
-                    0
-                };
+                let max = result.link_count();
                 if max > 0 {
                     result.selected_link = (result.selected_link + 1) % max;
                 }
@@ -420,11 +1573,40 @@ impl App {
         }
     }
 
-    /// Toggle between list mode and link mode (for blocks)
+    /// Toggle between list mode and link mode (for blocks), or fold the
+    /// selected call (for traces)
     pub fn toggle_mode(&mut self) {
-        if let Screen::BlockResult(result) = &mut self.screen {
-            result.list_mode = !result.list_mode;
-            result.selected_index = 0;
+        match &mut self.screen {
+            Screen::BlockResult(result) => {
+                result.list_mode = !result.list_mode;
+                result.selected_index = 0;
+            }
+            Screen::Trace(result) => result.toggle_selected(),
+            Screen::Activity(result) => {
+                result.list_mode = !result.list_mode;
+                result.selected_index = 0;
+            }
+            Screen::Token(result) => {
+                result.list_mode = !result.list_mode;
+                result.selected_index = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Select the link left of the current one in the selected row (the
+    /// caller of a trace frame)
+    pub fn select_left(&mut self) {
+        if let Screen::Trace(result) = &mut self.screen {
+            result.from_selected = true;
+        }
+    }
+
+    /// Select the link right of the current one in the selected row (the
+    /// target of a trace frame)
+    pub fn select_right(&mut self) {
+        if let Screen::Trace(result) = &mut self.screen {
+            result.from_selected = false;
         }
     }
 
@@ -478,18 +1660,65 @@ impl App {
                     }
                 }
 
+                // Add accounts with state changes
+                for account in &result.info.state_changes {
+                    links.push(NavLink::Address(account.address.clone()));
+                }
+
                 links.get(result.selected_link).cloned()
             }
             Screen::AddressResult(result) => {
-                if result.info.proxy_impl.is_some() && result.selected_link == 0 {
-                    result
-                        .info
-                        .proxy_impl
-                        .map(|a| NavLink::Address(format!("{a:?}")))
-                } else {
-                    None
-                }
+                let proxy = result.info.proxy.as_ref()?;
+                proxy
+                    .links()
+                    .get(result.selected_link)
+                    .map(|(_, a)| NavLink::Address(format!("{a:?}")))
             }
+            Screen::LiveBlocks(result) => result
+                .blocks
+                .get(result.selected_index)
+                .map(|b| NavLink::Block(b.number)),
+            Screen::Mempool(result) => result
+                .pool
+                .txs
+                .get(result.selected_index)
+                .map(|tx| NavLink::Transaction(format!("{:?}", tx.hash))),
+            Screen::Trace(result) => {
+                let row = result
+                    .visible_calls()
+                    .into_iter()
+                    .nth(result.selected_index)?;
+                // The target of CREATE frames is the created contract
+                let address = match result.from_selected {
+                    true => Some(row.call.from.clone()),
+                    false => row.call.to.clone(),
+                };
+                address.map(NavLink::Address)
+            }
+            Screen::Activity(result) if result.list_mode => result
+                .scan
+                .events
+                .get(result.selected_index)
+                .map(|event| NavLink::Transaction(format!("{:?}", event.tx_hash))),
+            Screen::Activity(result) => result
+                .scan
+                .holdings
+                .get(result.selected_index)
+                .map(|holding| NavLink::Address(format!("{:?}", holding.token))),
+            Screen::Token(result) if result.list_mode => result
+                .scan
+                .transfers
+                .get(result.selected_index)
+                .map(|transfer| NavLink::Transaction(format!("{:?}", transfer.tx_hash))),
+            Screen::Token(result) => result
+                .accounts()
+                .nth(result.selected_index)
+                .map(|flow| NavLink::Address(format!("{:?}", flow.account))),
+            Screen::Logs(result) => result
+                .page
+                .entries
+                .get(result.selected_index)
+                .map(|entry| NavLink::Transaction(format!("{:?}", entry.tx_hash))),
             _ => None,
         }
     }
@@ -507,6 +1736,7 @@ mod tests {
         Config {
             rpc_url: Some("http://localhost:8545".to_string()),
             recent_searches: vec![],
+            ..Default::default()
         }
     }
 
@@ -538,8 +1768,11 @@ mod tests {
             to_ens: None,
             actual_fee: None,
             decoded_method: None,
+            decoded_inputs: Vec::new(),
+            revert_reason: None,
             logs: vec![],
             token_transfers: vec![],
+            state_changes: vec![],
         }
     }
 
@@ -568,16 +1801,19 @@ mod tests {
                         name: "from".to_string(),
                         value: format!("0x{:040x}", i * 3),
                         is_address: true,
+                        ..Default::default()
                     },
                     DecodedParam {
                         name: "to".to_string(),
                         value: format!("0x{:040x}", i * 3 + 1),
                         is_address: true,
+                        ..Default::default()
                     },
                     DecodedParam {
                         name: "value".to_string(),
                         value: "1000".to_string(),
                         is_address: false,
+                        ..Default::default()
                     },
                 ],
             });
@@ -628,6 +1864,17 @@ mod tests {
         let app = App::new(config);
         assert!(app.has_rpc());
         assert!(app.is_on_home());
+        let client = app.rpc_client.as_ref().unwrap();
+        assert_eq!(client.rpc_url(), "http://localhost:8545");
+    }
+
+    #[test]
+    fn test_app_new_with_ws_rpc() {
+        let mut config = mock_config();
+        config.rpc_url = Some("ws://localhost:8546".to_string());
+        let app = App::new(config);
+        assert!(app.has_rpc());
+        assert!(!app.needs_rpc_setup());
     }
 
     #[test]
@@ -636,6 +1883,7 @@ mod tests {
         config.rpc_url = None;
         let app = App::new(config);
         assert!(!app.has_rpc());
+        assert!(app.rpc_client.is_none());
     }
 
     // ==================== Navigation tests ====================
@@ -653,6 +1901,31 @@ mod tests {
         assert!(matches!(app.screen, Screen::Home));
     }
 
+    #[tokio::test]
+    async fn test_leaving_loading_cancels_fetch() {
+        let mut app = App::new(mock_config());
+        let pending = || tokio::spawn(std::future::pending::<()>());
+
+        app.set_loading("Loading...");
+        let fetch = pending();
+        app.track_fetch(fetch.abort_handle());
+        app.go_back();
+        assert!(fetch.await.unwrap_err().is_cancelled());
+
+        // A new search supersedes the one still loading
+        app.set_loading("Loading...");
+        let first = pending();
+        app.track_fetch(first.abort_handle());
+        app.set_loading("Loading...");
+        let second = pending();
+        app.track_fetch(second.abort_handle());
+        assert!(first.await.unwrap_err().is_cancelled());
+        assert!(!second.is_finished());
+
+        app.go_home();
+        assert!(second.await.unwrap_err().is_cancelled());
+    }
+
     #[test]
     fn test_go_back_at_home() {
         let config = mock_config();
@@ -790,6 +2063,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tx_result_state_change_links() {
+        let config = mock_config();
+        let mut app = App::new(config);
+
+        let mut info = mock_tx_info_with_transfers(0, 0);
+        info.state_changes = (0..5)
+            .map(|i| crate::rpc::AccountStateDiff {
+                address: format!("0x{i:040x}"),
+                ..Default::default()
+            })
+            .collect();
+        app.set_tx_result(info);
+
+        // from, to, block, then the state change accounts
+        for _ in 0..7 {
+            app.select_next();
+        }
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Address(addr)) if addr == format!("0x{:040x}", 4)
+        ));
+        if let Screen::TxResult(result) = &app.screen {
+            assert_eq!(result.state_scroll, 5 - MAX_VISIBLE_STATE_CHANGES);
+        }
+
+        // Wraps around to from
+        app.select_next();
+        if let Screen::TxResult(result) = &app.screen {
+            assert_eq!(result.selected_link, 0);
+        }
+    }
+
+    #[test]
+    fn test_state_diff_on_demand() {
+        let mut app = App::new(mock_config());
+        let info = mock_tx_info();
+        let hash = info.hash.clone();
+        app.set_tx_result(info);
+
+        assert_eq!(app.state_diff_request(), Some(hash.clone()));
+        let state_diff = |app: &App| match &app.screen {
+            Screen::TxResult(result) => result.state_diff.clone(),
+            _ => panic!("Expected TxResult screen"),
+        };
+        assert_eq!(state_diff(&app), StateDiffStatus::Loading);
+        // Not requested again while the trace is in flight
+        assert_eq!(app.state_diff_request(), None);
+
+        // A node without the debug API is told apart from other failures
+        app.set_state_diff(
+            &hash,
+            Err(
+                anyhow::Error::new(RpcError::Unsupported("debug_traceTransaction".into()))
+                    .context("RPC call debug_traceTransaction failed"),
+            ),
+        );
+        assert_eq!(state_diff(&app), StateDiffStatus::Unsupported);
+        app.set_state_diff(&hash, Err(anyhow::anyhow!("connection reset")));
+        assert_eq!(
+            state_diff(&app),
+            StateDiffStatus::Failed("connection reset".to_string())
+        );
+
+        // Results for another transaction are dropped
+        app.set_state_diff("0xother", Ok(vec![AccountStateDiff::default()]));
+        assert!(matches!(state_diff(&app), StateDiffStatus::Failed(_)));
+
+        app.state_diff_request();
+        app.set_state_diff(&hash, Ok(vec![AccountStateDiff::default()]));
+        assert_eq!(state_diff(&app), StateDiffStatus::Loaded);
+        // Traced once
+        assert_eq!(app.state_diff_request(), None);
+    }
+
     // ==================== get_selected_link tests ====================
 
     #[test]
@@ -875,6 +2223,138 @@ mod tests {
         assert_eq!(app.get_selected_history_query(), Some("query1".to_string()));
     }
 
+    // ==================== LiveBlocks tests ====================
+
+    fn mock_block(number: u64) -> BlockInfo {
+        BlockInfo {
+            number,
+            ..mock_block_info()
+        }
+    }
+
+    #[test]
+    fn test_live_blocks_push_newest_first() {
+        let mut app = App::new(mock_config());
+        app.open_live_blocks();
+
+        app.push_live_blocks(vec![mock_block(102), mock_block(101), mock_block(100)]);
+        assert_eq!(app.live_head(), Some(102));
+
+        // Duplicates of already-seen blocks are ignored
+        app.push_live_blocks(vec![mock_block(103), mock_block(102)]);
+        if let Screen::LiveBlocks(result) = &app.screen {
+            let numbers: Vec<u64> = result.blocks.iter().map(|b| b.number).collect();
+            assert_eq!(numbers, vec![103, 102, 101, 100]);
+        } else {
+            panic!("Expected LiveBlocks screen");
+        }
+    }
+
+    #[test]
+    fn test_live_blocks_selection_sticks_to_block() {
+        let mut app = App::new(mock_config());
+        app.open_live_blocks();
+        app.push_live_blocks(vec![mock_block(101), mock_block(100)]);
+
+        app.select_next();
+        assert!(matches!(app.get_selected_link(), Some(NavLink::Block(100))));
+
+        app.push_live_blocks(vec![mock_block(102)]);
+        assert!(matches!(app.get_selected_link(), Some(NavLink::Block(100))));
+    }
+
+    #[test]
+    fn test_live_blocks_capacity() {
+        let mut app = App::new(mock_config());
+        app.open_live_blocks();
+        app.push_live_blocks((0..80).rev().map(mock_block).collect());
+
+        if let Screen::LiveBlocks(result) = &app.screen {
+            assert_eq!(result.blocks.len(), LIVE_BLOCKS_CAPACITY);
+            assert_eq!(result.blocks[0].number, 79);
+        }
+    }
+
+    // ==================== Trace tests ====================
+
+    fn mock_call(to: &str, calls: Vec<CallTrace>) -> CallTrace {
+        CallTrace {
+            call_type: "CALL".to_string(),
+            to: Some(to.to_string()),
+            calls,
+            ..Default::default()
+        }
+    }
+
+    fn mock_trace() -> CallTrace {
+        // root -> [a -> [b], c]
+        mock_call(
+            "0xroot",
+            vec![
+                mock_call("0xa", vec![mock_call("0xb", vec![])]),
+                mock_call("0xc", vec![]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_trace_navigation_links() {
+        let mut app = App::new(mock_config());
+        app.set_trace_result("0xhash".to_string(), mock_trace());
+
+        let mut targets = Vec::new();
+        for _ in 0..5 {
+            if let Some(NavLink::Address(addr)) = app.get_selected_link() {
+                targets.push(addr);
+            }
+            app.select_next();
+        }
+        // Depth-first order, and selection stops at the last row
+        assert_eq!(targets, ["0xroot", "0xa", "0xb", "0xc", "0xc"]);
+    }
+
+    #[test]
+    fn test_trace_collapse_and_expand() {
+        let mut app = App::new(mock_config());
+        app.set_trace_result("0xhash".to_string(), mock_trace());
+        app.select_next(); // 0xa
+
+        app.toggle_mode();
+        if let Screen::Trace(result) = &app.screen {
+            let rows = result.visible_calls();
+            assert_eq!(rows.len(), 3);
+            assert!(rows[1].collapsed);
+            assert_eq!(rows[2].call.to.as_deref(), Some("0xc"));
+        } else {
+            panic!("Expected Trace screen");
+        }
+
+        app.toggle_mode();
+        if let Screen::Trace(result) = &app.screen {
+            assert_eq!(result.visible_calls().len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_trace_leaf_does_not_collapse() {
+        let mut app = App::new(mock_config());
+        app.set_trace_result("0xhash".to_string(), mock_trace());
+        app.select_next();
+        app.select_next(); // 0xb (leaf)
+        app.toggle_mode();
+        if let Screen::Trace(result) = &app.screen {
+            assert!(result.collapsed.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_current_tx_hash() {
+        let mut app = App::new(mock_config());
+        assert!(app.current_tx_hash().is_none());
+        app.set_tx_result(mock_tx_info());
+        assert_eq!(app.current_tx_hash(), Some(mock_tx_info().hash));
+    }
+
     // ==================== BlockResult tests ====================
 
     #[test]
@@ -894,4 +2374,759 @@ mod tests {
             assert!(!result.list_mode); // now in info mode
         }
     }
+
+    // ==================== Chain tests ====================
+
+    fn network_info(chain_id: Option<u64>) -> NetworkInfo {
+        NetworkInfo {
+            chain_id,
+            latest_block: 1,
+            gas_price: 0,
+            client_version: String::new(),
+            base_fee_trend: None,
+            priority_fee_percentiles: None,
+        }
+    }
+
+    #[test]
+    fn test_chain_adapts_intervals() {
+        let mut app = App::new(mock_config());
+        // Unknown until network info arrives
+        assert!(app.chain().is_none());
+        assert_eq!(app.native_symbol(), "ETH");
+        assert_eq!(app.network_refresh_interval(), Duration::from_secs(12));
+        assert_eq!(app.live_poll_interval(), Duration::from_secs(3));
+
+        // Arbitrum: sub-second blocks, clamped to the minimum cadence
+        app.network_info = Some(network_info(Some(42161)));
+        assert_eq!(app.chain().unwrap().name, "Arbitrum One");
+        assert_eq!(app.network_refresh_interval(), Duration::from_secs(2));
+        assert_eq!(app.live_poll_interval(), Duration::from_secs(1));
+
+        app.network_info = Some(network_info(Some(137)));
+        assert_eq!(app.native_symbol(), "POL");
+
+        // Unregistered chains fall back to the defaults
+        app.network_info = Some(network_info(Some(999_999)));
+        assert!(app.chain().is_none());
+        assert_eq!(app.native_symbol(), "ETH");
+        assert_eq!(app.network_refresh_interval(), DEFAULT_BLOCK_TIME);
+    }
+
+    #[test]
+    fn test_chain_mismatch() {
+        let mut config = mock_config();
+        config.profiles.insert(
+            "base".to_string(),
+            crate::config::Profile {
+                rpc_url: "https://base.example.com".to_string(),
+                chain_id: Some(8453),
+                ..Default::default()
+            },
+        );
+        config.active_profile = Some("base".to_string());
+        let mut app = App::new(config);
+        // Profile chain id is used until the node reports its own
+        assert_eq!(app.chain().unwrap().name, "Base");
+        assert_eq!(app.chain_mismatch(), None);
+
+        app.network_info = Some(network_info(Some(8453)));
+        assert_eq!(app.chain_mismatch(), None);
+
+        app.network_info = Some(network_info(Some(1)));
+        assert_eq!(app.chain_mismatch(), Some(1));
+        assert_eq!(app.chain().unwrap().name, "Ethereum");
+    }
+
+    // ==================== Storage inspector tests ====================
+
+    fn mock_address_info() -> AddressInfo {
+        AddressInfo {
+            address: Address::repeat_byte(0xaa),
+            balance: U256::ZERO,
+            nonce: 1,
+            is_contract: true,
+            code_size: Some(100),
+            proxy: None,
+            token_info: None,
+            ens_name: None,
+            owner: None,
+            token_balances: vec![],
+            block: None,
+        }
+    }
+
+    fn storage_read(expr: &str) -> StorageRead {
+        StorageRead {
+            expr: expr.to_string(),
+            block: "latest".to_string(),
+            slot: B256::ZERO,
+            value: B256::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_storage_query() {
+        let mut app = App::new(mock_config());
+        app.set_address_result(mock_address_info());
+        // Nothing to submit until the inspector is open
+        assert!(app.submit_storage_query().is_none());
+
+        app.open_storage_inspector();
+        let inspector = app.storage_inspector_mut().unwrap();
+        inspector.prompt.input = "balances[1]".into();
+        assert!(app.submit_storage_query().is_none());
+        assert!(app.storage_inspector_mut().unwrap().prompt.error.is_some());
+
+        let inspector = app.storage_inspector_mut().unwrap();
+        inspector.prompt.input = "balances[1] @ 3".into();
+        inspector.prompt.block_input = "19000000".into();
+        let request = app.submit_storage_query().unwrap();
+        assert_eq!(request.address, Address::repeat_byte(0xaa));
+        assert_eq!(request.slot, SlotExpr::parse("3[1]").unwrap().slot());
+        assert_eq!(request.block, BlockId::number(19_000_000));
+        assert_eq!(request.block_label, "19000000");
+        let inspector = app.storage_inspector_mut().unwrap();
+        assert!(inspector.pending && inspector.prompt.error.is_none());
+
+        // One read at a time
+        assert!(app.submit_storage_query().is_none());
+
+        // On a historical address page an empty block is the page's block
+        let mut info = mock_address_info();
+        info.block = Some(19_000_000);
+        app.set_address_result(info);
+        app.open_storage_inspector();
+        app.storage_inspector_mut().unwrap().prompt.input = "0".into();
+        let request = app.submit_storage_query().unwrap();
+        assert_eq!(request.block, BlockId::number(19_000_000));
+        assert_eq!(request.block_label, "19000000");
+    }
+
+    #[test]
+    fn test_set_storage_read() {
+        let mut app = App::new(mock_config());
+        app.set_address_result(mock_address_info());
+        app.open_storage_inspector();
+
+        // Reads for another address are dropped
+        app.set_storage_read(Address::ZERO, Ok(storage_read("0")));
+        assert!(app.storage_inspector_mut().unwrap().reads.is_empty());
+
+        for i in 0..=STORAGE_READS_CAPACITY {
+            app.set_storage_read(Address::repeat_byte(0xaa), Ok(storage_read(&i.to_string())));
+        }
+        let inspector = app.storage_inspector_mut().unwrap();
+        assert_eq!(inspector.reads.len(), STORAGE_READS_CAPACITY);
+        assert_eq!(inspector.reads[0].expr, STORAGE_READS_CAPACITY.to_string());
+
+        app.set_storage_read(Address::repeat_byte(0xaa), Err("reverted".to_string()));
+        let inspector = app.storage_inspector_mut().unwrap();
+        assert_eq!(inspector.prompt.error.as_deref(), Some("reverted"));
+        assert!(!inspector.pending);
+
+        app.close_block_prompt();
+        assert!(app.storage_inspector_mut().is_none());
+    }
+
+    // ==================== Call console tests ====================
+
+    #[test]
+    fn test_call_submit() {
+        let mut app = App::new(mock_config());
+        app.set_address_result(mock_address_info());
+        app.open_storage_inspector();
+        // Opening the console replaces the storage inspector
+        app.open_call_console();
+        assert!(app.storage_inspector_mut().is_none());
+
+        let console = app.call_console_mut().unwrap();
+        console.prompt.input = "balanceOf(address)(uint256)".into();
+        assert!(app.submit_call().is_none());
+        assert!(app.call_console_mut().unwrap().prompt.error.is_some());
+
+        let console = app.call_console_mut().unwrap();
+        console.prompt.input =
+            "balanceOf(address)(uint256) 0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".into();
+        console.prompt.block_input = "safe".into();
+        let request = app.submit_call().unwrap();
+        assert_eq!(request.address, Address::repeat_byte(0xaa));
+        assert_eq!(request.call.signature(), "balanceOf(address)(uint256)");
+        assert_eq!(request.block, BlockId::safe());
+        let console = app.call_console_mut().unwrap();
+        assert!(console.pending && console.prompt.error.is_none());
+        assert!(app.submit_call().is_none());
+
+        // On a historical address page an empty block is the page's block
+        let mut info = mock_address_info();
+        info.block = Some(19_000_000);
+        app.set_address_result(info);
+        app.open_call_console();
+        app.call_console_mut().unwrap().prompt.input = "totalSupply()".into();
+        let request = app.submit_call().unwrap();
+        assert_eq!(request.block, BlockId::number(19_000_000));
+        assert_eq!(request.block_label, "19000000");
+    }
+
+    #[test]
+    fn test_set_call_result() {
+        let mut app = App::new(mock_config());
+        app.set_address_result(mock_address_info());
+        app.open_call_console();
+        let call_result = |call: &str| CallResult {
+            call: call.to_string(),
+            block: "latest".to_string(),
+            outputs: vec![],
+        };
+
+        app.set_call_result(Address::ZERO, Ok(call_result("a()")));
+        assert!(app.call_console_mut().unwrap().results.is_empty());
+
+        for i in 0..=CALL_RESULTS_CAPACITY {
+            app.set_call_result(
+                Address::repeat_byte(0xaa),
+                Ok(call_result(&format!("f{i}()"))),
+            );
+        }
+        let console = app.call_console_mut().unwrap();
+        assert_eq!(console.results.len(), CALL_RESULTS_CAPACITY);
+        assert_eq!(
+            console.results[0].call,
+            format!("f{CALL_RESULTS_CAPACITY}()")
+        );
+
+        app.set_call_result(
+            Address::repeat_byte(0xaa),
+            Err("Reverted: Paused".to_string()),
+        );
+        assert_eq!(
+            app.call_console_mut().unwrap().prompt.error.as_deref(),
+            Some("Reverted: Paused")
+        );
+
+        app.close_block_prompt();
+        assert!(app.call_console_mut().is_none());
+    }
+
+    // ==================== Activity scan tests ====================
+
+    fn mock_activity_scan() -> ActivityScan {
+        use crate::rpc::{ActivityEvent, ActivityKind, TokenHolding};
+
+        let event = |block: u64| ActivityEvent {
+            block,
+            tx_hash: B256::repeat_byte(block as u8),
+            log_index: 0,
+            token: Address::repeat_byte(0x70),
+            kind: ActivityKind::Transfer,
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0xaa),
+            amount: Some(U256::from(5)),
+            token_id: None,
+        };
+        ActivityScan {
+            address: Address::repeat_byte(0xaa),
+            from_block: 1,
+            to_block: 100,
+            scanned_from: 1,
+            events: vec![event(90), event(40)],
+            holdings: vec![TokenHolding {
+                token: Address::repeat_byte(0x70),
+                received: U256::from(10),
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_activity_submit() {
+        let mut app = App::new(mock_config());
+        app.set_address_result(mock_address_info());
+        app.open_call_console();
+        // Opening the prompt replaces the call console
+        app.open_activity_prompt();
+        assert!(app.call_console_mut().is_none());
+        let (kind, prompt) = app.block_prompt_mut().unwrap();
+        assert_eq!(kind, PromptKind::Activity);
+
+        prompt.input = "soon".into();
+        assert!(app.submit_activity_scan().is_none());
+        let (_, prompt) = app.block_prompt_mut().unwrap();
+        assert!(prompt.error.is_some());
+
+        // Empty fields scan the default range up to the latest block
+        prompt.input = "".into();
+        let request = app.submit_activity_scan().unwrap();
+        assert_eq!(request.address, Address::repeat_byte(0xaa));
+        assert_eq!(request.from, None);
+        assert_eq!(request.to, BlockId::latest());
+        assert!(app.block_prompt_mut().is_none());
+
+        // On a historical address page the scan ends at the page's block
+        let mut info = mock_address_info();
+        info.block = Some(19_000_000);
+        app.set_address_result(info);
+        app.open_activity_prompt();
+        app.block_prompt_mut().unwrap().1.input = "18000000".into();
+        let request = app.submit_activity_scan().unwrap();
+        assert_eq!(request.from, Some(BlockId::number(18_000_000)));
+        assert_eq!(request.to, BlockId::number(19_000_000));
+    }
+
+    #[test]
+    fn test_activity_result_navigation() {
+        let mut app = App::new(mock_config());
+        app.set_loading("Scanning token activity...");
+        app.update_loading("Scanning token activity... 50%".to_string());
+        assert!(matches!(&app.screen, Screen::Loading(msg) if msg.ends_with("50%")));
+
+        app.set_activity_result(mock_activity_scan());
+        // A late progress message doesn't replace the result
+        app.update_loading("Scanning token activity... 100%".to_string());
+        assert!(matches!(app.screen, Screen::Activity(_)));
+
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", B256::repeat_byte(90))
+        ));
+        app.select_next();
+        app.select_next();
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", B256::repeat_byte(40))
+        ));
+
+        // Tab switches to the holdings, which link to the token
+        app.toggle_mode();
+        app.select_next();
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Address(addr)) if addr == format!("{:?}", Address::repeat_byte(0x70))
+        ));
+    }
+
+    // ==================== Token page tests ====================
+
+    fn mock_token_scan() -> TokenScan {
+        use crate::rpc::{ActivityEvent, ActivityKind, TokenInfo};
+
+        let token = Address::repeat_byte(0xaa);
+        let transfer = |block: u64| ActivityEvent {
+            block,
+            tx_hash: B256::repeat_byte(block as u8),
+            log_index: 0,
+            token,
+            kind: ActivityKind::Transfer,
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0x22),
+            amount: Some(U256::from(5)),
+            token_id: None,
+        };
+        let flow = |byte: u8| TokenFlow {
+            account: Address::repeat_byte(byte),
+            amount: U256::from(10),
+            transfers: 2,
+        };
+        TokenScan {
+            token,
+            info: TokenInfo {
+                name: Some("Token".to_string()),
+                symbol: Some("TKN".to_string()),
+                decimals: Some(18),
+                total_supply: Some(U256::from(1_000)),
+            },
+            from_block: 1_001,
+            to_block: 2_000,
+            scanned_from: 1_001,
+            transfers: vec![transfer(90), transfer(40)],
+            senders: vec![flow(0x11)],
+            receivers: vec![flow(0x22), flow(0x33)],
+            volume: U256::from(10),
+            minted: U256::ZERO,
+            burned: U256::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_token_page_request() {
+        use crate::rpc::TokenInfo;
+
+        let mut app = App::new(mock_config());
+        // Only ERC-20 contracts have a token page
+        app.set_address_result(mock_address_info());
+        assert!(app.token_page_request().is_none());
+
+        let mut info = mock_address_info();
+        info.token_info = Some(TokenInfo {
+            name: None,
+            symbol: Some("TKN".to_string()),
+            decimals: Some(18),
+            total_supply: None,
+        });
+        info.block = Some(19_000_000);
+        app.set_address_result(info);
+        let request = app.token_page_request().unwrap();
+        assert_eq!(request.token, Address::repeat_byte(0xaa));
+        assert_eq!(request.from, None);
+        assert_eq!(request.to, BlockId::number(19_000_000));
+    }
+
+    #[test]
+    fn test_token_window_submit() {
+        let mut app = App::new(mock_config());
+        app.set_token_result(mock_token_scan());
+        app.open_token_lookup();
+        // Opening the window prompt replaces the lookup, and starts from the current window
+        app.open_token_window();
+        assert!(app.token_lookup_mut().is_none());
+        let (kind, prompt) = app.block_prompt_mut().unwrap();
+        assert_eq!(kind, PromptKind::TokenWindow);
+        assert_eq!(prompt.input.value(), "1001");
+        assert_eq!(prompt.block_input.value(), "2000");
+
+        prompt.block_input = "later".into();
+        assert!(app.submit_token_window().is_none());
+        let (_, prompt) = app.block_prompt_mut().unwrap();
+        assert!(prompt.error.is_some());
+
+        // Empty fields scan the default window up to the latest block
+        prompt.input = "".into();
+        prompt.block_input = "".into();
+        let request = app.submit_token_window().unwrap();
+        assert_eq!(request.token, Address::repeat_byte(0xaa));
+        assert_eq!(request.from, None);
+        assert_eq!(request.to, BlockId::latest());
+        assert!(app.block_prompt_mut().is_none());
+    }
+
+    #[test]
+    fn test_token_lookup_submit_and_result() {
+        let mut app = App::new(mock_config());
+        app.set_token_result(mock_token_scan());
+        app.open_token_lookup();
+
+        let holder = Address::repeat_byte(0x11);
+        let spender = Address::repeat_byte(0x22);
+        app.token_lookup_mut().unwrap().inputs[0] = "0x1234".into();
+        assert!(app.submit_token_lookup().is_none());
+        let error = app.token_lookup_mut().unwrap().error.clone().unwrap();
+        assert!(error.contains("Invalid holder address"));
+
+        // Without a spender only the balance is looked up
+        app.token_lookup_mut().unwrap().inputs[0] = format!("{holder:?}").into();
+        let request = app.submit_token_lookup().unwrap();
+        assert_eq!(request.holder, holder);
+        assert_eq!(request.spender, None);
+        assert_eq!(request.block, BlockId::latest());
+        assert_eq!(request.block_label, "latest");
+        // No second lookup while one is pending
+        assert!(app.submit_token_lookup().is_none());
+
+        let lookup = |allowance: Option<U256>| TokenLookup {
+            holder,
+            spender: allowance.map(|_| spender),
+            block: "latest".to_string(),
+            balance: U256::from(7),
+            allowance,
+        };
+        // Lookups for another token are ignored
+        app.set_token_lookup(Address::ZERO, Ok(lookup(None)));
+        assert!(app.token_lookup_mut().unwrap().pending);
+        app.set_token_lookup(Address::repeat_byte(0xaa), Ok(lookup(None)));
+
+        let form = app.token_lookup_mut().unwrap();
+        form.inputs[1] = format!("{spender:?}").into();
+        form.inputs[2] = "19000000".into();
+        let request = app.submit_token_lookup().unwrap();
+        assert_eq!(request.spender, Some(spender));
+        assert_eq!(request.block, BlockId::number(19_000_000));
+        app.set_token_lookup(Address::repeat_byte(0xaa), Ok(lookup(Some(U256::MAX))));
+
+        let form = app.token_lookup_mut().unwrap();
+        assert!(!form.pending);
+        assert_eq!(form.results.len(), 2);
+        assert_eq!(form.results[0].allowance, Some(U256::MAX));
+    }
+
+    #[test]
+    fn test_token_result_navigation() {
+        let mut app = App::new(mock_config());
+        app.set_loading("Scanning token transfers...");
+        app.set_token_result(mock_token_scan());
+        assert!(matches!(app.screen, Screen::Token(_)));
+
+        // Transfers open their transaction
+        app.select_next();
+        app.select_next();
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", B256::repeat_byte(40))
+        ));
+
+        // Tab switches to the top accounts: senders, then receivers
+        app.toggle_mode();
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Address(addr)) if addr == format!("{:?}", Address::repeat_byte(0x11))
+        ));
+        for _ in 0..5 {
+            app.select_next();
+        }
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Address(addr)) if addr == format!("{:?}", Address::repeat_byte(0x33))
+        ));
+    }
+
+    fn mock_log_page(to_block: u64, scanned_from: u64) -> LogPage {
+        use crate::rpc::LogEntry;
+
+        let entries = (scanned_from..=to_block)
+            .rev()
+            .step_by(100)
+            .map(|block| LogEntry {
+                block,
+                tx_hash: B256::from(U256::from(block)),
+                log_index: 0,
+                log: DecodedLog {
+                    address: format!("{:?}", Address::repeat_byte(0xaa)),
+                    topics: vec![],
+                    data: "0x".to_string(),
+                    event_name: None,
+                    decoded_params: vec![],
+                },
+            })
+            .collect();
+        LogPage {
+            to_block,
+            scanned_from,
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_log_query_submit() {
+        let mut app = App::new(mock_config());
+        app.set_address_result(mock_address_info());
+        // The address page fills in its contract
+        app.open_address_log_query();
+        let form = app.log_query_form_mut().unwrap();
+        assert_eq!(
+            form.inputs[0].value(),
+            format!("{:?}", Address::repeat_byte(0xaa))
+        );
+        assert_eq!(LOG_QUERY_FIELDS[form.focus], "Event");
+
+        form.focused_input_mut()
+            .handle(tui_input::InputRequest::InsertChar('x'));
+        assert!(app.submit_log_query().is_none());
+        assert!(app.log_query_form_mut().unwrap().error.is_some());
+
+        let form = app.log_query_form_mut().unwrap();
+        form.inputs[1] = "Transfer(address,address,uint256)".into();
+        form.focus_prev();
+        form.focus_prev();
+        assert_eq!(LOG_QUERY_FIELDS[form.focus], "To");
+        form.focused_input_mut()
+            .handle(tui_input::InputRequest::InsertChar('9'));
+        let request = app.submit_log_query().unwrap();
+        assert_eq!(request.query.addresses, vec![Address::repeat_byte(0xaa)]);
+        assert_eq!(request.from, None);
+        assert_eq!(request.to, BlockId::number(9));
+        assert!(app.log_query_form_mut().unwrap().error.is_none());
+
+        // Back from the form returns to the address page
+        app.go_back();
+        assert!(matches!(app.screen, Screen::AddressResult(_)));
+    }
+
+    #[test]
+    fn test_logs_paging() {
+        let query = LogQuery::parse("", "", ["", "", ""]).unwrap();
+        let mut app = App::new(mock_config());
+        app.set_logs_result(query, 1_000, mock_log_page(10_000, 5_001));
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", B256::from(U256::from(10_000)))
+        ));
+
+        let request = app.next_logs_page().unwrap();
+        assert_eq!(request.from, Some(BlockId::number(1_000)));
+        assert_eq!(request.to, BlockId::number(5_000));
+        // Only one page is fetched at a time
+        assert!(app.next_logs_page().is_none());
+
+        // Open a transaction while the page loads; it lands on the logs behind it
+        app.set_tx_result(mock_tx_info());
+        app.append_logs_page(Ok(mock_log_page(5_000, 1_000)));
+        app.go_back();
+        let Screen::Logs(result) = &app.screen else {
+            panic!("expected the logs screen");
+        };
+        assert!(!result.pending);
+        assert!(!result.has_more());
+        assert_eq!(result.page.entries.len(), 91);
+        assert!(app.next_logs_page().is_none());
+    }
+
+    #[test]
+    fn test_logs_page_error() {
+        let query = LogQuery::parse("", "", ["", "", ""]).unwrap();
+        let mut app = App::new(mock_config());
+        app.set_logs_result(query, 1_000, mock_log_page(10_000, 5_001));
+        app.next_logs_page().unwrap();
+        app.append_logs_page(Err("rate limited".to_string()));
+        let Screen::Logs(result) = &app.screen else {
+            panic!("expected the logs screen");
+        };
+        assert_eq!(result.error.as_deref(), Some("rate limited"));
+        // The page can be retried
+        assert!(app.next_logs_page().is_some());
+    }
+
+    fn mock_pool_tx(from: u8, nonce: u64) -> PoolTx {
+        use crate::rpc::TxType;
+
+        PoolTx {
+            hash: B256::from(U256::from(from as u64 * 1_000 + nonce)),
+            from: Address::repeat_byte(from),
+            to: Some(Address::repeat_byte(0xee)),
+            nonce,
+            value: U256::ZERO,
+            gas_limit: 21_000,
+            max_fee: 30_000_000_000,
+            priority_fee: Some(1_000_000_000),
+            tx_type: TxType::EIP1559,
+            method: None,
+            queued: false,
+        }
+    }
+
+    #[test]
+    fn test_mempool_snapshot_keeps_selection() {
+        let mut app = App::new(mock_config());
+        app.open_mempool();
+        assert_eq!(app.mempool_source(), Some(MempoolSource::Loading));
+
+        app.set_mempool(Mempool {
+            pending: 3,
+            queued: 0,
+            txs: vec![mock_pool_tx(1, 5), mock_pool_tx(2, 8), mock_pool_tx(2, 9)],
+        });
+        assert_eq!(app.mempool_source(), Some(MempoolSource::TxPool));
+        app.select_next();
+        app.select_next();
+
+        // 1/5 was mined; the selection follows 2/9
+        app.set_mempool(Mempool {
+            pending: 3,
+            queued: 0,
+            txs: vec![mock_pool_tx(2, 8), mock_pool_tx(2, 9), mock_pool_tx(3, 0)],
+        });
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", mock_pool_tx(2, 9).hash)
+        ));
+
+        // A failed refresh keeps the snapshot up
+        app.set_mempool_error("timeout".to_string());
+        let Screen::Mempool(result) = &app.screen else {
+            panic!("expected the mempool screen");
+        };
+        assert_eq!(result.pool.txs.len(), 3);
+        assert_eq!(result.error.as_deref(), Some("timeout"));
+
+        // Snapshots aren't mixed with announced transactions
+        app.push_pending_tx(mock_pool_tx(4, 0));
+        let Screen::Mempool(result) = &app.screen else {
+            panic!("expected the mempool screen");
+        };
+        assert_eq!(result.pool.txs.len(), 3);
+    }
+
+    #[test]
+    fn test_mempool_follows_pending_transactions() {
+        let mut app = App::new(mock_config());
+        app.open_mempool();
+        app.follow_pending_transactions();
+        assert_eq!(app.mempool_source(), Some(MempoolSource::Subscription));
+
+        app.push_pending_tx(mock_pool_tx(1, 0));
+        app.push_pending_tx(mock_pool_tx(2, 0));
+        app.push_pending_tx(mock_pool_tx(2, 0)); // announced twice
+                                                 // Newest first, following the newest
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", mock_pool_tx(2, 0).hash)
+        ));
+
+        // Once scrolled away, the selection stays on its transaction
+        app.select_next();
+        app.push_pending_tx(mock_pool_tx(3, 0));
+        assert!(matches!(
+            app.get_selected_link(),
+            Some(NavLink::Transaction(hash)) if hash == format!("{:?}", mock_pool_tx(1, 0).hash)
+        ));
+
+        for nonce in 0..MEMPOOL_FEED_CAPACITY as u64 {
+            app.push_pending_tx(mock_pool_tx(9, nonce));
+        }
+        let Screen::Mempool(result) = &app.screen else {
+            panic!("expected the mempool screen");
+        };
+        assert_eq!(result.pool.txs.len(), MEMPOOL_FEED_CAPACITY);
+        assert_eq!(result.selected_index, MEMPOOL_FEED_CAPACITY - 1);
+    }
+
+    #[test]
+    fn test_address_proxy_links() {
+        use crate::rpc::{Facet, ProxyInfo, ProxyKind};
+
+        let mut info = mock_address_info();
+        info.proxy = Some(ProxyInfo {
+            beacon: Some(Address::repeat_byte(0xbb)),
+            admin: Some(Address::repeat_byte(0xcc)),
+            ..ProxyInfo::new(ProxyKind::Eip1967Beacon, Some(Address::repeat_byte(0x11)))
+        });
+        let mut app = App::new(mock_config());
+        app.set_address_result(info);
+
+        let selected = |app: &App| match app.get_selected_link() {
+            Some(NavLink::Address(addr)) => addr,
+            other => panic!("expected an address link, got {other:?}"),
+        };
+        assert_eq!(selected(&app), format!("{:?}", Address::repeat_byte(0x11)));
+        app.select_next();
+        assert_eq!(selected(&app), format!("{:?}", Address::repeat_byte(0xbb)));
+        app.select_next();
+        assert_eq!(selected(&app), format!("{:?}", Address::repeat_byte(0xcc)));
+        app.select_next(); // wraps
+        assert_eq!(selected(&app), format!("{:?}", Address::repeat_byte(0x11)));
+        app.select_prev(); // wraps back
+        assert_eq!(selected(&app), format!("{:?}", Address::repeat_byte(0xcc)));
+
+        // Diamonds link to each facet
+        let mut info = mock_address_info();
+        info.proxy = Some(ProxyInfo {
+            facets: vec![
+                Facet {
+                    address: Address::repeat_byte(0x01),
+                    selectors: vec![],
+                },
+                Facet {
+                    address: Address::repeat_byte(0x02),
+                    selectors: vec![],
+                },
+            ],
+            ..ProxyInfo::new(ProxyKind::Diamond, None)
+        });
+        app.set_address_result(info);
+        app.select_next();
+        assert_eq!(selected(&app), format!("{:?}", Address::repeat_byte(0x02)));
+
+        // Plain contracts have no links
+        app.set_address_result(mock_address_info());
+        app.select_next();
+        assert!(app.get_selected_link().is_none());
+    }
 }
@@ -0,0 +1,776 @@
+//! Non-interactive command line interface
+//!
+//! Every subcommand reuses `RpcClient` and prints its result to stdout, so tbex
+//! can be used from scripts and CI jobs without starting the terminal UI.
+//! `--json` prints a single JSON document and `--ndjson` prints list results
+//! (such as a block's transactions) as one JSON object per line.
+
+use crate::config::{Config, Profile};
+use crate::rpc::{
+    chain_info, AbiRegistry, AddressInfo, BlockInfo, BlockStats, DecodedParam, NetworkInfo,
+    RpcClient, RpcTransport, SignatureDb, TxInfo, TxSummary,
+};
+use crate::search::SearchQuery;
+use crate::ui::helper::{
+    format_address_with_ens, format_gas, format_gwei, format_native, format_storage_word,
+    format_token_amount,
+};
+
+use alloy::eips::BlockId;
+use alloy::primitives::{Address, TxHash};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub const USAGE: &str = "\
+Usage: tbex [COMMAND] [--profile <name>] [--json | --ndjson] [--state]
+
+Commands:
+  (none)              Start the terminal UI
+  block <number>      Print a block and its transactions
+  tx <hash>           Print a transaction
+  address <addr|ens>[@block]
+                      Print an address, optionally as of a block
+  set-rpc <url>       Save the RPC URL to the config file (of the active profile, if any)
+  profiles            List the RPC profiles in the config file
+  import-signatures <file>
+                      Import a 4byte-style signature dump into the local database
+  network             Print the current network status
+  help                Print this message
+
+Options:
+  --profile <name>    Use a named RPC profile from the config file
+  --json              Print results as JSON
+  --ndjson            Print list results as newline-delimited JSON
+  --state             With 'tx': also trace the state changes (needs the debug API)";
+
+/// How command results are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Ndjson,
+}
+
+/// A full command line: the command plus global options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cli {
+    pub command: Command,
+    pub output: OutputFormat,
+    pub profile: Option<String>,
+    /// Trace the state changes of `tx`, which needs debug_traceTransaction
+    pub state_changes: bool,
+}
+
+impl Cli {
+    /// Parse command line arguments (without the program name)
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut output = OutputFormat::Text;
+        let mut profile = None;
+        let mut state_changes = false;
+        let mut rest = Vec::new();
+
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => output = OutputFormat::Json,
+                "--ndjson" => output = OutputFormat::Ndjson,
+                "--state" => state_changes = true,
+                "--profile" => {
+                    let name = args.next().ok_or_else(|| {
+                        anyhow!("Missing profile name for '--profile'\n\n{USAGE}")
+                    })?;
+                    profile = Some(name);
+                }
+                _ => match arg.strip_prefix("--profile=") {
+                    Some(name) => profile = Some(name.to_string()),
+                    None => rest.push(arg),
+                },
+            }
+        }
+
+        let command = Command::parse(rest)?;
+        if command == Command::Tui && output != OutputFormat::Text {
+            bail!("--json and --ndjson require a command\n\n{USAGE}");
+        }
+        if state_changes && !matches!(command, Command::Tx(_)) {
+            bail!("--state only applies to 'tx'\n\n{USAGE}");
+        }
+
+        Ok(Self {
+            command,
+            output,
+            profile,
+            state_changes,
+        })
+    }
+}
+
+/// A parsed command line invocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// No subcommand: run the interactive TUI
+    Tui,
+    Block(u64),
+    Tx(String),
+    /// Address or ENS name, and the block to read its state at (None = latest)
+    Address(String, Option<BlockId>),
+    SetRpc(String),
+    Profiles,
+    ImportSignatures(PathBuf),
+    Network,
+    Help,
+}
+
+impl Command {
+    /// Parse command line arguments (without the program name)
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let args: Vec<String> = args.into_iter().map(Into::into).collect();
+
+        let Some(name) = args.first() else {
+            return Ok(Self::Tui);
+        };
+
+        let arg = |what: &str| -> Result<String> {
+            match args.len() {
+                2 => Ok(args[1].clone()),
+                1 => Err(anyhow!("Missing {what} for '{name}'\n\n{USAGE}")),
+                _ => Err(anyhow!("Too many arguments for '{name}'\n\n{USAGE}")),
+            }
+        };
+        let no_arg = || -> Result<()> {
+            if args.len() > 1 {
+                bail!("'{name}' takes no arguments\n\n{USAGE}");
+            }
+            Ok(())
+        };
+
+        match name.as_str() {
+            "block" => match SearchQuery::parse(&arg("block number")?) {
+                SearchQuery::BlockNumber(num) => Ok(Self::Block(num)),
+                _ => Err(anyhow!("Invalid block number: {}", args[1])),
+            },
+            "tx" => match SearchQuery::parse(&arg("transaction hash")?) {
+                SearchQuery::Hash32(hash) => Ok(Self::Tx(hash)),
+                _ => Err(anyhow!("Invalid transaction hash: {}", args[1])),
+            },
+            "address" => match SearchQuery::parse(&arg("address")?) {
+                SearchQuery::Address(addr) | SearchQuery::EnsName(addr) => {
+                    Ok(Self::Address(addr, None))
+                }
+                SearchQuery::AtBlock(query, block) => match *query {
+                    SearchQuery::Address(addr) | SearchQuery::EnsName(addr) => {
+                        Ok(Self::Address(addr, Some(block)))
+                    }
+                    _ => Err(anyhow!("Invalid address or ENS name: {}", args[1])),
+                },
+                SearchQuery::Invalid(reason) if args[1].contains('@') => Err(anyhow!(reason)),
+                _ => Err(anyhow!("Invalid address or ENS name: {}", args[1])),
+            },
+            "set-rpc" => Ok(Self::SetRpc(arg("RPC URL")?)),
+            "profiles" => no_arg().map(|_| Self::Profiles),
+            "import-signatures" => Ok(Self::ImportSignatures(arg("dump file")?.into())),
+            "network" => no_arg().map(|_| Self::Network),
+            "help" | "-h" | "--help" => Ok(Self::Help),
+            other => Err(anyhow!("Unknown command: {other}\n\n{USAGE}")),
+        }
+    }
+}
+
+/// JSON shape of `tbex block`
+#[derive(Serialize)]
+pub struct BlockOutput<'a> {
+    pub block: &'a BlockInfo,
+    pub stats: &'a BlockStats,
+    pub transactions: &'a [TxSummary],
+}
+
+/// JSON shape of one entry of `tbex profiles`
+#[derive(Serialize)]
+pub struct ProfileOutput<'a> {
+    pub name: &'a str,
+    pub active: bool,
+    #[serde(flatten)]
+    pub profile: &'a Profile,
+}
+
+/// Run a non-interactive command
+pub async fn run(cli: Cli, config: &mut Config) -> Result<()> {
+    let output = cli.output;
+
+    match cli.command {
+        Command::Tui => bail!("The TUI has no non-interactive form"),
+        Command::Help => println!("{USAGE}"),
+        Command::SetRpc(url) => {
+            RpcTransport::parse(&url).with_context(|| format!("Invalid RPC URL: {url}"))?;
+            config.set_rpc(url.clone())?;
+            let profile = config.profile().map(|(name, _)| name);
+            match (output, profile) {
+                (OutputFormat::Text, Some(name)) => {
+                    println!("RPC of profile '{name}' set to {url}")
+                }
+                (OutputFormat::Text, None) => println!("RPC set to {url}"),
+                _ => print_json(
+                    &serde_json::json!({ "rpc_url": url, "profile": profile }),
+                    output,
+                )?,
+            }
+        }
+        Command::Profiles => {
+            let active = config.profile().map(|(name, _)| name);
+            match output {
+                OutputFormat::Text => print!("{}", format_profiles(config)),
+                _ => {
+                    let profiles: Vec<_> = config
+                        .profiles
+                        .iter()
+                        .map(|(name, profile)| ProfileOutput {
+                            name,
+                            active: active == Some(name.as_str()),
+                            profile,
+                        })
+                        .collect();
+                    print_json(&profiles, output)?;
+                }
+            }
+        }
+        Command::ImportSignatures(file) => {
+            let dump = std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read {file:?}"))?;
+            let db = SignatureDb::open(Config::signature_db_path()?);
+            let stats = db.import(&dump)?;
+            match output {
+                OutputFormat::Text => println!(
+                    "Imported {} new signatures ({} total) into {}",
+                    stats.added,
+                    stats.total,
+                    db.path().display()
+                ),
+                _ => print_json(
+                    &serde_json::json!({ "added": stats.added, "total": stats.total }),
+                    output,
+                )?,
+            }
+        }
+        Command::Block(num) => {
+            let client = client(config).await?;
+            let (info, transactions, stats) = client.get_block_page(num).await?;
+            match output {
+                OutputFormat::Text => print!(
+                    "{}",
+                    format_block(&info, &transactions, &stats, symbol(config, &client).await)
+                ),
+                OutputFormat::Json => print_json(
+                    &BlockOutput {
+                        block: &info,
+                        stats: &stats,
+                        transactions: &transactions,
+                    },
+                    output,
+                )?,
+                OutputFormat::Ndjson => {
+                    for tx in &transactions {
+                        print_json(tx, output)?;
+                    }
+                }
+            }
+        }
+        Command::Tx(hash) => {
+            let abis = AbiRegistry::load(&config.abi_search_paths());
+            let client = client(config).await?.with_abis(Arc::new(abis));
+            let hash: TxHash = hash.parse()?;
+            let mut info = client.get_transaction(hash).await?;
+            if cli.state_changes && info.block_number.is_some() {
+                info.state_changes = client
+                    .get_state_diff(hash)
+                    .await
+                    .with_context(|| format!("Failed to trace the state changes of {hash:?}"))?;
+            }
+            match output {
+                OutputFormat::Text => print!("{}", format_tx(&info, symbol(config, &client).await)),
+                _ => print_json(&info, output)?,
+            }
+        }
+        Command::Address(query, block) => {
+            let client = client(config).await?;
+            let addr = match query.parse::<Address>() {
+                Ok(addr) => addr,
+                Err(_) => client.resolve_ens_to_address(&query).await?,
+            };
+            let info = client
+                .get_address_at(addr, block.unwrap_or_default())
+                .await?;
+            match output {
+                OutputFormat::Text => {
+                    print!("{}", format_address(&info, symbol(config, &client).await))
+                }
+                _ => print_json(&info, output)?,
+            }
+        }
+        Command::Network => {
+            let info = client(config).await?.get_network_info().await?;
+            match output {
+                OutputFormat::Text => print!("{}", format_network(&info)),
+                _ => print_json(&info, output)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a value as pretty JSON (`--json`) or a single compact line (`--ndjson`)
+fn print_json<T: Serialize + ?Sized>(value: &T, output: OutputFormat) -> Result<()> {
+    let json = match output {
+        OutputFormat::Ndjson => serde_json::to_string(value),
+        _ => serde_json::to_string_pretty(value),
+    }
+    .context("Failed to serialize output")?;
+    println!("{json}");
+    Ok(())
+}
+
+async fn client(config: &Config) -> Result<RpcClient> {
+    let url = config
+        .rpc_url()
+        .context("No RPC configured. Use 'tbex set-rpc <url>' first.")?;
+    Ok(RpcClient::connect(url).await?.with_tokens(config.tokens()))
+}
+
+/// Native currency symbol: the profile's, else the detected chain's, else ETH
+async fn symbol<'a>(config: &'a Config, client: &RpcClient) -> &'a str {
+    match config.native_symbol() {
+        Some(symbol) => symbol,
+        None => client
+            .chain()
+            .await
+            .map_or("ETH", |chain| chain.native_symbol),
+    }
+}
+
+fn push_kv(out: &mut String, key: &str, value: &str) {
+    out.push_str(&format!("{:<20}{value}\n", format!("{key}:")));
+}
+
+pub fn format_block(
+    info: &BlockInfo,
+    transactions: &[TxSummary],
+    stats: &BlockStats,
+    symbol: &str,
+) -> String {
+    let mut out = format!("Block #{}\n", info.number);
+    push_kv(&mut out, "Hash", &info.hash);
+    push_kv(&mut out, "Parent Hash", &info.parent_hash);
+    push_kv(&mut out, "Timestamp", &info.timestamp.to_string());
+    let miner = format_address_with_ens(&info.miner, info.miner_ens.as_deref());
+    match &info.builder_tag {
+        Some(tag) => push_kv(&mut out, "Miner/Builder", &format!("{miner} ({tag})")),
+        None => push_kv(&mut out, "Miner/Builder", &miner),
+    }
+    push_kv(&mut out, "Transactions", &info.tx_count.to_string());
+    push_kv(
+        &mut out,
+        "Gas Used",
+        &format!(
+            "{} / {}",
+            format_gas(info.gas_used),
+            format_gas(info.gas_limit)
+        ),
+    );
+    if let Some(base_fee) = info.base_fee {
+        push_kv(&mut out, "Base Fee", &format_gwei(base_fee as u128));
+    }
+    push_kv(
+        &mut out,
+        "Value Transferred",
+        &format_native(stats.total_value_transferred, symbol),
+    );
+    push_kv(
+        &mut out,
+        "Total Fees",
+        &format_native(stats.total_fees, symbol),
+    );
+    push_kv(
+        &mut out,
+        "Burnt Fees",
+        &format_native(stats.burnt_fees, symbol),
+    );
+    if stats.blob_count > 0 {
+        push_kv(&mut out, "Blobs", &stats.blob_count.to_string());
+    }
+
+    if !transactions.is_empty() {
+        out.push('\n');
+        for (i, tx) in transactions.iter().enumerate() {
+            let to = tx.to.as_deref().unwrap_or("[Contract Create]");
+            let method = tx.decoded_method.as_deref().unwrap_or("");
+            out.push_str(&format!(
+                "{i:>4}  {}  {} -> {to}  {}  {method}\n",
+                tx.hash,
+                tx.from,
+                format_native(tx.value, symbol)
+            ));
+        }
+    }
+
+    out
+}
+
+pub fn format_tx(info: &TxInfo, symbol: &str) -> String {
+    let status = match info.status {
+        Some(true) => "Success",
+        Some(false) => "Failed",
+        None => "Pending",
+    };
+
+    let mut out = format!("Transaction {}\n", info.hash);
+    push_kv(&mut out, "Status", status);
+    if let Some(reason) = &info.revert_reason {
+        push_kv(&mut out, "Revert Reason", reason);
+    }
+    push_kv(&mut out, "Type", info.tx_type.as_str());
+    if let Some(method) = &info.decoded_method {
+        push_kv(&mut out, "Method", method);
+    }
+    push_kv(
+        &mut out,
+        "From",
+        &format_address_with_ens(&info.from, info.from_ens.as_deref()),
+    );
+    match &info.to {
+        Some(to) => push_kv(
+            &mut out,
+            "To",
+            &format_address_with_ens(to, info.to_ens.as_deref()),
+        ),
+        None => push_kv(&mut out, "To", "Contract Creation"),
+    }
+    if let Some(contract) = &info.contract_created {
+        push_kv(&mut out, "Contract Created", contract);
+    }
+    push_kv(&mut out, "Value", &format_native(info.value, symbol));
+    if let Some(fee) = info.actual_fee {
+        push_kv(&mut out, "Fee Paid", &format_native(fee, symbol));
+    }
+    if let Some(gp) = info.gas_price {
+        push_kv(&mut out, "Gas Price", &format_gwei(gp));
+    }
+    push_kv(&mut out, "Gas Limit", &info.gas_limit.to_string());
+    if let Some(used) = info.gas_used {
+        push_kv(&mut out, "Gas Used", &used.to_string());
+    }
+    push_kv(&mut out, "Nonce", &info.nonce.to_string());
+    if let Some(block) = info.block_number {
+        push_kv(&mut out, "Block", &block.to_string());
+    }
+    if let Some(idx) = info.tx_index {
+        push_kv(&mut out, "Tx Index", &idx.to_string());
+    }
+    push_kv(&mut out, "Input", &format!("{} bytes", info.input_size));
+    if !info.decoded_inputs.is_empty() {
+        out.push_str("\nArguments:\n");
+        push_params(&mut out, &info.decoded_inputs, "  ");
+    }
+
+    if !info.token_transfers.is_empty() {
+        out.push_str("\nToken Transfers:\n");
+        for transfer in &info.token_transfers {
+            let amount = format_token_amount(transfer.amount, transfer.decimals.unwrap_or(18));
+            let symbol = transfer.token_symbol.as_deref().unwrap_or("");
+            out.push_str(&format!(
+                "  {} -> {}  {amount} {symbol} ({})\n",
+                transfer.from, transfer.to, transfer.token_address
+            ));
+        }
+    }
+
+    if !info.logs.is_empty() {
+        out.push_str("\nLogs:\n");
+        for (i, log) in info.logs.iter().enumerate() {
+            let event = log.event_name.as_deref().unwrap_or("Unknown Event");
+            out.push_str(&format!("  {i:>3}. {} {event}\n", log.address));
+            push_params(&mut out, &log.decoded_params, "         ");
+        }
+    }
+
+    if !info.state_changes.is_empty() {
+        out.push_str("\nState Changes:\n");
+        for account in &info.state_changes {
+            out.push_str(&format!("  {}\n", account.address));
+            if let (Some(before), Some(after)) = (account.balance_before, account.balance_after) {
+                out.push_str(&format!(
+                    "    Balance: {} -> {}\n",
+                    format_native(before, symbol),
+                    format_native(after, symbol)
+                ));
+            }
+            if let (Some(before), Some(after)) = (account.nonce_before, account.nonce_after) {
+                out.push_str(&format!("    Nonce: {before} -> {after}\n"));
+            }
+            if let (Some(before), Some(after)) = (account.code_size_before, account.code_size_after)
+            {
+                out.push_str(&format!("    Code: {before} bytes -> {after} bytes\n"));
+            }
+            for change in &account.storage {
+                out.push_str(&format!(
+                    "    Slot {}: {} -> {}\n",
+                    format_storage_word(change.slot),
+                    format_storage_word(change.before),
+                    format_storage_word(change.after)
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+/// Append decoded params one per line, nesting tuple fields and array elements
+fn push_params(out: &mut String, params: &[DecodedParam], indent: &str) {
+    for param in params {
+        match &param.ty {
+            Some(ty) => out.push_str(&format!("{indent}{} ({ty}): {}\n", param.name, param.value)),
+            None => out.push_str(&format!("{indent}{}: {}\n", param.name, param.value)),
+        }
+        push_params(out, &param.children, &format!("{indent}  "));
+    }
+}
+
+pub fn format_address(info: &AddressInfo, symbol: &str) -> String {
+    let mut out = format!("Address {:?}\n", info.address);
+    if let Some(number) = info.block {
+        push_kv(&mut out, "Block", &number.to_string());
+    }
+    if let Some(ens) = &info.ens_name {
+        push_kv(&mut out, "ENS", ens);
+    }
+    push_kv(
+        &mut out,
+        &format!("{symbol} Balance"),
+        &format_native(info.balance, symbol),
+    );
+    push_kv(&mut out, "Nonce", &info.nonce.to_string());
+    if let Some(size) = info.code_size {
+        push_kv(&mut out, "Code Size", &format!("{size} bytes"));
+    }
+    if let Some(owner) = &info.owner {
+        push_kv(&mut out, "Owner", owner);
+    }
+    if let Some(proxy) = &info.proxy {
+        push_kv(&mut out, "Proxy", proxy.kind.name());
+        for (label, address) in proxy.links() {
+            push_kv(&mut out, label, &format!("{address:?}"));
+        }
+    }
+
+    if let Some(token) = &info.token_info {
+        if let Some(name) = &token.name {
+            push_kv(&mut out, "Token Name", name);
+        }
+        if let Some(symbol) = &token.symbol {
+            push_kv(&mut out, "Token Symbol", symbol);
+        }
+        if let Some(decimals) = token.decimals {
+            push_kv(&mut out, "Decimals", &decimals.to_string());
+        }
+        if let Some(supply) = token.total_supply {
+            push_kv(
+                &mut out,
+                "Total Supply",
+                &format_token_amount(supply, token.decimals.unwrap_or(18)),
+            );
+        }
+    }
+
+    if !info.token_balances.is_empty() {
+        out.push_str("\nToken Balances:\n");
+        for balance in &info.token_balances {
+            out.push_str(&format!(
+                "  {:>16} {} ({:?})\n",
+                format_token_amount(balance.balance, balance.decimals),
+                balance.symbol,
+                balance.address
+            ));
+        }
+    }
+
+    out
+}
+
+pub fn format_profiles(config: &Config) -> String {
+    if config.profiles.is_empty() {
+        return "No profiles configured. Add [profiles.<name>] sections to the config file.\n"
+            .to_string();
+    }
+
+    let active = config.profile().map(|(name, _)| name);
+    let mut out = String::new();
+    for (name, profile) in &config.profiles {
+        let marker = if active == Some(name.as_str()) {
+            "*"
+        } else {
+            " "
+        };
+        out.push_str(&format!("{marker} {name:<16}{}", profile.rpc_url));
+        if let Some(display_name) = &profile.display_name {
+            out.push_str(&format!("  {display_name}"));
+        }
+        if let Some(chain_id) = profile.chain_id {
+            out.push_str(&format!("  (chain {chain_id})"));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub fn format_network(info: &NetworkInfo) -> String {
+    let mut out = String::new();
+    if let Some(chain_id) = info.chain_id {
+        let chain = match chain_info(chain_id) {
+            Some(chain) => format!("{} ({chain_id})", chain.name),
+            None => chain_id.to_string(),
+        };
+        push_kv(&mut out, "Chain", &chain);
+    }
+    push_kv(&mut out, "Latest Block", &info.latest_block.to_string());
+    push_kv(&mut out, "Gas Price", &format_gwei(info.gas_price));
+    if let Some(base_fee) = info.base_fee_trend.as_ref().and_then(|t| t.last()) {
+        push_kv(&mut out, "Base Fee", &format_gwei(*base_fee as u128));
+    }
+    push_kv(&mut out, "Client", &info.client_version);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_no_args_is_tui() {
+        assert_eq!(Command::parse(Vec::<String>::new()).unwrap(), Command::Tui);
+    }
+
+    #[test]
+    fn test_parse_block() {
+        assert_eq!(
+            Command::parse(["block", "12345678"]).unwrap(),
+            Command::Block(12345678)
+        );
+        assert_eq!(
+            Command::parse(["block", "0xBC614E"]).unwrap(),
+            Command::Block(12345678)
+        );
+        assert!(Command::parse(["block", "vitalik.eth"]).is_err());
+        assert!(Command::parse(["block"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_tx() {
+        let hash = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+        assert_eq!(
+            Command::parse(["tx", hash]).unwrap(),
+            Command::Tx(hash.to_string())
+        );
+        assert!(Command::parse(["tx", "123"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_address_and_ens() {
+        assert_eq!(
+            Command::parse(["address", "0x742d35Cc6634C0532925a3b844Bc9e7595f8fE31"]).unwrap(),
+            Command::Address(
+                "0x742d35cc6634c0532925a3b844bc9e7595f8fe31".to_string(),
+                None
+            )
+        );
+        assert_eq!(
+            Command::parse(["address", "Vitalik.eth"]).unwrap(),
+            Command::Address("vitalik.eth".to_string(), None)
+        );
+        assert_eq!(
+            Command::parse(["address", "vitalik.eth@19000000"]).unwrap(),
+            Command::Address("vitalik.eth".to_string(), Some(BlockId::number(19_000_000)))
+        );
+        assert!(Command::parse(["address", "vitalik.eth@soon"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_set_rpc_and_network() {
+        assert_eq!(
+            Command::parse(["set-rpc", "http://localhost:8545"]).unwrap(),
+            Command::SetRpc("http://localhost:8545".to_string())
+        );
+        assert_eq!(Command::parse(["network"]).unwrap(), Command::Network);
+        assert!(Command::parse(["network", "extra"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_import_signatures() {
+        assert_eq!(
+            Command::parse(["import-signatures", "4bytes.csv"]).unwrap(),
+            Command::ImportSignatures(PathBuf::from("4bytes.csv"))
+        );
+        assert!(Command::parse(["import-signatures"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_output_flags() {
+        let cli = Cli::parse(["block", "--json", "100"]).unwrap();
+        assert_eq!(cli.command, Command::Block(100));
+        assert_eq!(cli.output, OutputFormat::Json);
+
+        let cli = Cli::parse(["--ndjson", "block", "100"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Ndjson);
+
+        assert_eq!(Cli::parse(["network"]).unwrap().output, OutputFormat::Text);
+        assert!(Cli::parse(["--json"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_state_flag() {
+        let hash = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+        let cli = Cli::parse(["tx", hash, "--state"]).unwrap();
+        assert_eq!(cli.command, Command::Tx(hash.to_string()));
+        assert!(cli.state_changes);
+
+        assert!(!Cli::parse(["tx", hash]).unwrap().state_changes);
+        assert!(Cli::parse(["block", "100", "--state"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_profile_flag() {
+        let cli = Cli::parse(["--profile", "sepolia", "block", "100"]).unwrap();
+        assert_eq!(cli.command, Command::Block(100));
+        assert_eq!(cli.profile.as_deref(), Some("sepolia"));
+
+        let cli = Cli::parse(["--profile=base"]).unwrap();
+        assert_eq!(cli.command, Command::Tui);
+        assert_eq!(cli.profile.as_deref(), Some("base"));
+
+        assert_eq!(Cli::parse(["network"]).unwrap().profile, None);
+        assert!(Cli::parse(["network", "--profile"]).is_err());
+        assert_eq!(Command::parse(["profiles"]).unwrap(), Command::Profiles);
+    }
+
+    #[test]
+    fn test_parse_unknown_command() {
+        assert!(Command::parse(["frobnicate"]).is_err());
+        assert_eq!(Command::parse(["--help"]).unwrap(), Command::Help);
+    }
+
+    #[tokio::test]
+    async fn test_run_tui_is_an_error() {
+        let cli = Cli::parse(Vec::<String>::new()).unwrap();
+        assert!(run(cli, &mut Config::default()).await.is_err());
+    }
+}
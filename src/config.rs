@@ -1,12 +1,74 @@
-use anyhow::{Context, Result};
+use crate::rpc::{KnownToken, ResponseCache};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Config {
+    /// RPC URL used when no profile is active
     pub rpc_url: Option<String>,
     #[serde(default)]
     pub recent_searches: Vec<String>,
+    /// Extra directories to scan for contract ABIs (e.g. a Foundry project's `out/`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub abi_dirs: Vec<PathBuf>,
+    /// Profile selected by default (set by the in-TUI profile switcher)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+    /// Named RPC profiles, e.g. `[profiles.sepolia]`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    /// Response cache for finalized blocks and transactions (`[cache]`)
+    #[serde(default, skip_serializing_if = "CacheConfig::is_default")]
+    pub cache: CacheConfig,
+    /// Profile chosen with `--profile` for this run only (never saved)
+    #[serde(skip)]
+    pub profile_override: Option<String>,
+}
+
+/// A named RPC endpoint with its chain settings and search history
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Profile {
+    pub rpc_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Native currency symbol (ETH if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_symbol: Option<String>,
+    /// Tokens whose balances are shown on address pages instead of the built-in list
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tokens: Vec<KnownToken>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_searches: Vec<String>,
+}
+
+/// Size caps of the response cache, and whether it persists between runs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct CacheConfig {
+    pub memory_mb: u64,
+    /// Keep responses in ~/.cache/tbex/responses as well
+    pub disk: bool,
+    pub disk_mb: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            memory_mb: 64,
+            disk: false,
+            disk_mb: 512,
+        }
+    }
+}
+
+impl CacheConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 impl Config {
@@ -22,6 +84,36 @@ impl Config {
         Ok(Self::config_dir()?.join("config.toml"))
     }
 
+    /// Directories scanned for contract ABIs: ~/.config/tbex/abis plus any `abi_dirs`
+    pub fn abi_search_paths(&self) -> Vec<PathBuf> {
+        Self::config_dir()
+            .map(|dir| dir.join("abis"))
+            .into_iter()
+            .chain(self.abi_dirs.iter().cloned())
+            .collect()
+    }
+
+    /// Local 4byte-style signature database (~/.config/tbex/signatures.txt)
+    pub fn signature_db_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("signatures.txt"))
+    }
+
+    /// On-disk response cache (~/.cache/tbex/responses on Linux)
+    pub fn response_cache_dir() -> Result<PathBuf> {
+        dirs::cache_dir()
+            .map(|p| p.join("tbex").join("responses"))
+            .context("Could not determine cache directory")
+    }
+
+    /// The response cache configured by `[cache]`
+    pub fn response_cache(&self) -> ResponseCache {
+        let cache = ResponseCache::new((self.cache.memory_mb << 20) as usize);
+        match Self::response_cache_dir() {
+            Ok(dir) if self.cache.disk => cache.with_disk(dir, self.cache.disk_mb << 20),
+            _ => cache,
+        }
+    }
+
     /// Load config from disk, or return default if not found
     pub fn load() -> Result<Self> {
         let path = Self::config_path()?;
@@ -51,20 +143,232 @@ impl Config {
         Ok(())
     }
 
-    /// Set the RPC URL and persist
+    /// Name and settings of the active profile (`--profile`, then `active_profile`)
+    pub fn profile(&self) -> Option<(&str, &Profile)> {
+        let name = self
+            .profile_override
+            .as_ref()
+            .or(self.active_profile.as_ref())?;
+        self.profiles
+            .get_key_value(name)
+            .map(|(name, profile)| (name.as_str(), profile))
+    }
+
+    fn profile_mut(&mut self) -> Option<&mut Profile> {
+        let name = self
+            .profile_override
+            .as_ref()
+            .or(self.active_profile.as_ref())?;
+        self.profiles.get_mut(name)
+    }
+
+    /// Use a profile for this run without changing the saved default
+    pub fn use_profile(&mut self, name: &str) -> Result<()> {
+        self.check_profile(name)?;
+        self.profile_override = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Make a profile the active one and persist the choice
+    pub fn switch_profile(&mut self, name: &str) -> Result<()> {
+        self.check_profile(name)?;
+        self.profile_override = None;
+        self.active_profile = Some(name.to_string());
+        self.save()
+    }
+
+    /// The profile after the active one in name order (wrapping), if that's a change
+    pub fn next_profile_name(&self) -> Option<String> {
+        let names: Vec<&String> = self.profiles.keys().collect();
+        let current = self.profile().map(|(name, _)| name);
+        let next = match current.and_then(|c| names.iter().position(|n| *n == c)) {
+            Some(i) => names[(i + 1) % names.len()],
+            None => names.first()?,
+        };
+        (current != Some(next.as_str())).then(|| next.clone())
+    }
+
+    fn check_profile(&self, name: &str) -> Result<()> {
+        if !self.profiles.contains_key(name) {
+            let known: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            if known.is_empty() {
+                bail!("Unknown profile '{name}': no profiles are configured");
+            }
+            bail!("Unknown profile '{name}' (available: {})", known.join(", "));
+        }
+        Ok(())
+    }
+
+    /// RPC URL of the active profile, or the top-level `rpc_url`
+    pub fn rpc_url(&self) -> Option<&str> {
+        match self.profile() {
+            Some((_, profile)) => Some(profile.rpc_url.as_str()),
+            None => self.rpc_url.as_deref(),
+        }
+    }
+
+    /// Native currency symbol set on the active profile
+    pub fn native_symbol(&self) -> Option<&str> {
+        self.profile().and_then(|(_, p)| p.native_symbol.as_deref())
+    }
+
+    /// Token list of the active profile (empty means use the built-in list)
+    pub fn tokens(&self) -> &[KnownToken] {
+        self.profile().map_or(&[], |(_, p)| p.tokens.as_slice())
+    }
+
+    /// Set the RPC URL (of the active profile, if any) and persist
     pub fn set_rpc(&mut self, url: String) -> Result<()> {
-        self.rpc_url = Some(url);
+        match self.profile_mut() {
+            Some(profile) => profile.rpc_url = url,
+            None => self.rpc_url = Some(url),
+        }
         self.save()
     }
 
+    /// Recent searches of the active profile
+    pub fn recent_searches(&self) -> &[String] {
+        match self.profile() {
+            Some((_, profile)) => &profile.recent_searches,
+            None => &self.recent_searches,
+        }
+    }
+
+    fn recent_searches_mut(&mut self) -> &mut Vec<String> {
+        if self.profile().is_some() {
+            return &mut self.profile_mut().unwrap().recent_searches;
+        }
+        &mut self.recent_searches
+    }
+
     /// Add a search to recent history (keeps last 10)
     pub fn add_recent_search(&mut self, query: String) -> Result<()> {
+        let recent = self.recent_searches_mut();
         // Remove if already exists to avoid duplicates
-        self.recent_searches.retain(|s| s != &query);
+        recent.retain(|s| s != &query);
         // Add to front
-        self.recent_searches.insert(0, query);
+        recent.insert(0, query);
         // Keep only last 10
-        self.recent_searches.truncate(10);
+        recent.truncate(10);
+        self.save()
+    }
+
+    /// Remove one entry from recent history
+    pub fn remove_recent_search(&mut self, index: usize) -> Result<()> {
+        let recent = self.recent_searches_mut();
+        if index < recent.len() {
+            recent.remove(index);
+        }
         self.save()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_profiles() -> Config {
+        toml::from_str(
+            r#"
+            rpc_url = "http://localhost:8545"
+            recent_searches = ["1"]
+            active_profile = "mainnet"
+
+            [profiles.mainnet]
+            rpc_url = "https://eth.example.com"
+            chain_id = 1
+            recent_searches = ["vitalik.eth"]
+
+            [profiles.base]
+            rpc_url = "wss://base.example.com"
+            chain_id = 8453
+            display_name = "Base"
+            native_symbol = "ETH"
+
+            [[profiles.base.tokens]]
+            symbol = "USDC"
+            address = "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"
+            decimals = 6
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_active_profile() {
+        let config = config_with_profiles();
+        let (name, profile) = config.profile().unwrap();
+        assert_eq!(name, "mainnet");
+        assert_eq!(profile.chain_id, Some(1));
+        assert_eq!(config.rpc_url(), Some("https://eth.example.com"));
+        assert_eq!(config.recent_searches(), ["vitalik.eth"]);
+        assert!(config.tokens().is_empty());
+    }
+
+    #[test]
+    fn test_use_profile_override() {
+        let mut config = config_with_profiles();
+        config.use_profile("base").unwrap();
+        assert_eq!(config.rpc_url(), Some("wss://base.example.com"));
+        assert!(config.recent_searches().is_empty());
+        assert_eq!(config.tokens()[0].symbol, "USDC");
+        assert_eq!(config.tokens()[0].decimals, 6);
+        // The saved default is untouched
+        assert_eq!(config.active_profile.as_deref(), Some("mainnet"));
+
+        let err = config.use_profile("nope").unwrap_err();
+        assert!(err.to_string().contains("available: base, mainnet"));
+    }
+
+    #[test]
+    fn test_next_profile_name() {
+        let mut config = config_with_profiles();
+        // Profiles cycle in name order
+        assert_eq!(config.next_profile_name().as_deref(), Some("base"));
+        config.use_profile("base").unwrap();
+        assert_eq!(config.next_profile_name().as_deref(), Some("mainnet"));
+
+        config.profiles.remove("mainnet");
+        assert_eq!(config.next_profile_name(), None);
+
+        // Without an active profile the first one is picked
+        config.profile_override = None;
+        config.active_profile = None;
+        assert_eq!(config.next_profile_name().as_deref(), Some("base"));
+    }
+
+    #[test]
+    fn test_no_profiles_uses_top_level() {
+        let config = Config {
+            rpc_url: Some("http://localhost:8545".to_string()),
+            recent_searches: vec!["1".to_string()],
+            ..Default::default()
+        };
+        assert!(config.profile().is_none());
+        assert_eq!(config.rpc_url(), Some("http://localhost:8545"));
+        assert_eq!(config.recent_searches(), ["1"]);
+        assert_eq!(config.native_symbol(), None);
+    }
+
+    #[test]
+    fn test_cache_config() {
+        let config = config_with_profiles();
+        assert_eq!(config.cache, CacheConfig::default());
+        // Defaults aren't written out
+        assert!(!toml::to_string_pretty(&config).unwrap().contains("[cache]"));
+
+        let config: Config = toml::from_str("[cache]\ndisk = true").unwrap();
+        assert!(config.cache.disk);
+        assert_eq!(config.cache.disk_mb, 512);
+        assert_eq!(config.cache.memory_mb, 64);
+    }
+
+    #[test]
+    fn test_profiles_round_trip() {
+        let config = config_with_profiles();
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(parsed.profiles, config.profiles);
+        assert_eq!(parsed.active_profile, config.active_profile);
+    }
+}
@@ -3,6 +3,7 @@
 //! A terminal-based blockchain explorer for EVM chains.
 
 pub mod app;
+pub mod cli;
 pub mod config;
 pub mod rpc;
 pub mod search;
@@ -1,7 +1,17 @@
-use tbex::app::{App, NavLink};
+use tbex::app::{
+    ActivityRequest, App, CallRequest, LogQueryRequest, MempoolSource, NavLink, PromptKind,
+    StorageRequest, TokenLookupRequest, TokenRequest, LIVE_BLOCKS_CAPACITY,
+};
+use tbex::cli::{self, Cli, Command};
 use tbex::config::Config;
-use tbex::rpc::{AddressInfo, BlockInfo, BlockStats, NetworkInfo, RpcClient, TxInfo, TxSummary};
-use tbex::search::SearchQuery;
+use tbex::rpc::{
+    block_label, AbiRegistry, AccountStateDiff, ActivityScan, AddressInfo, BlockInfo, BlockStats,
+    CallResult, CallTrace, LogPage, LogQuery, Mempool, NetworkInfo, PoolTx, ResponseCache,
+    RpcClient, RpcError, SignatureDb, SignatureLookup, StorageRead, TokenLookup, TokenScan, TxInfo,
+    TxSummary, DEFAULT_ACTIVITY_RANGE, DEFAULT_LOG_RANGE, DEFAULT_TOKEN_RANGE,
+    MAX_CONCURRENT_REQUESTS,
+};
+use tbex::search::{format_utc_timestamp, SearchQuery};
 use tbex::ui;
 
 use alloy::primitives::{Address, TxHash};
@@ -15,14 +25,26 @@ use ratatui::{
     prelude::*,
 };
 use std::io::stdout;
-use tokio::sync::mpsc;
+use std::sync::Arc;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
 use tui_input::backend::crossterm::EventHandler;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = Config::load()?;
+    let mut config = Config::load()?;
+    if let Ok(path) = Config::signature_db_path() {
+        SignatureDb::install(SignatureDb::open(path));
+    }
+    ResponseCache::install(config.response_cache());
 
-    run_tui(config).await?;
+    let cli = Cli::parse(std::env::args().skip(1))?;
+    if let Some(name) = &cli.profile {
+        config.use_profile(name)?;
+    }
+    match cli.command {
+        Command::Tui => run_tui(config).await?,
+        _ => cli::run(cli, &mut config).await?,
+    }
 
     Ok(())
 }
@@ -33,6 +55,19 @@ enum AsyncMessage {
     TxResult(Result<TxInfo>),
     AddressResult(Result<AddressInfo>),
     NetworkInfo(Result<NetworkInfo>),
+    LiveBlocks(Result<Vec<BlockInfo>>),
+    Mempool(Result<Mempool>),
+    PendingTx(PoolTx),
+    Trace(Result<(String, CallTrace)>),
+    StateDiff(String, Result<Vec<AccountStateDiff>>),
+    Storage(Address, Result<StorageRead>),
+    Call(Address, Result<CallResult>),
+    Progress(String),
+    Activity(Result<ActivityScan>),
+    Token(Result<TokenScan>),
+    TokenLookup(Address, Result<TokenLookup>),
+    Logs(Result<(LogQuery, u64, LogPage)>),
+    LogsPage(Result<LogPage>),
 }
 
 async fn run_tui(config: Config) -> Result<()> {
@@ -41,21 +76,14 @@ async fn run_tui(config: Config) -> Result<()> {
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
     let mut app = App::new(config);
+    let abis = AbiRegistry::load(&app.config.abi_search_paths());
+    app.set_abis(Arc::new(abis));
 
     let (tx, mut rx) = mpsc::channel::<AsyncMessage>(10);
 
     // Fetch network info on startup (only if RPC is configured)
     if app.has_rpc() {
-        if let Some(ref url) = app.rpc_url {
-            let tx_clone = tx.clone();
-            let url_clone = url.clone();
-            tokio::spawn(async move {
-                if let Ok(client) = RpcClient::new(&url_clone) {
-                    let result = client.get_network_info().await;
-                    let _ = tx_clone.send(AsyncMessage::NetworkInfo(result)).await;
-                }
-            });
-        }
+        fetch_network_info(&app, tx.clone());
     }
 
     let result = run_event_loop(&mut terminal, &mut app, tx, &mut rx).await;
@@ -73,46 +101,174 @@ async fn run_event_loop(
     rx: &mut mpsc::Receiver<AsyncMessage>,
 ) -> Result<()> {
     let mut last_network_refresh = std::time::Instant::now();
+    let mut last_live_poll: Option<std::time::Instant> = None;
+    let mut live_poll_pending = false;
+    let mut live_subscription: Option<tokio::task::JoinHandle<()>> = None;
+    let mut last_mempool_poll: Option<std::time::Instant> = None;
+    let mut mempool_poll_pending = false;
+    let mut pending_subscription: Option<tokio::task::JoinHandle<()>> = None;
 
     loop {
         terminal.draw(|frame| ui::draw(frame, app))?;
 
-        // Periodically refresh network info (every 12 seconds ~ 1 block)
+        // Periodically refresh network info (about once a block on the detected chain)
         if app.is_on_home()
             && app.has_rpc()
-            && last_network_refresh.elapsed() > std::time::Duration::from_secs(12)
+            && last_network_refresh.elapsed() > app.network_refresh_interval()
         {
             last_network_refresh = std::time::Instant::now();
+            fetch_network_info(app, tx.clone());
+        }
+
+        // On WebSocket/IPC endpoints, new heads are pushed by a subscription
+        // for as long as the live blocks screen is open
+        if !app.is_on_live_blocks() {
+            if let Some(handle) = live_subscription.take() {
+                handle.abort();
+            }
+        } else if live_subscription.is_none()
+            && app.rpc_transport.as_ref().is_some_and(|t| t.supports_subscriptions())
+        {
             if let Some(ref url) = app.rpc_url {
+                live_subscription = Some(spawn_new_heads_subscription(url.clone(), tx.clone()));
+            }
+        }
+        let subscribed = live_subscription.as_ref().is_some_and(|h| !h.is_finished());
+
+        // Otherwise poll for new heads (always poll once to fill in recent blocks)
+        if app.is_on_live_blocks()
+            && !live_poll_pending
+            && last_live_poll.is_none_or(|t| !subscribed && t.elapsed() > app.live_poll_interval())
+        {
+            last_live_poll = Some(std::time::Instant::now());
+            live_poll_pending = true;
+            if let Some(client) = app.rpc_client.clone() {
                 let tx_clone = tx.clone();
-                let url_clone = url.clone();
+                let after = app.live_head();
                 tokio::spawn(async move {
-                    if let Ok(client) = RpcClient::new(&url_clone) {
-                        let result = client.get_network_info().await;
-                        let _ = tx_clone.send(AsyncMessage::NetworkInfo(result)).await;
-                    }
+                    let result = match client.get().await {
+                        Ok(client) => client
+                            .get_new_blocks(after, LIVE_BLOCKS_CAPACITY)
+                            .await
+                            .map_err(anyhow::Error::from),
+                        Err(e) => Err(e),
+                    };
+                    let _ = tx_clone.send(AsyncMessage::LiveBlocks(result)).await;
                 });
             }
         }
 
+        // The mempool screen polls the txpool namespace, or follows
+        // newPendingTransactions where the node doesn't expose it
+        let mempool_source = app.mempool_source();
+        if mempool_source != Some(MempoolSource::Subscription) {
+            if let Some(handle) = pending_subscription.take() {
+                handle.abort();
+            }
+        }
+        match mempool_source {
+            Some(MempoolSource::Subscription) if pending_subscription.is_none() => {
+                if let Some(ref url) = app.rpc_url {
+                    pending_subscription =
+                        Some(spawn_pending_tx_subscription(url.clone(), tx.clone()));
+                }
+            }
+            Some(MempoolSource::Loading | MempoolSource::TxPool)
+                if !mempool_poll_pending
+                    && last_mempool_poll
+                        .is_none_or(|t| t.elapsed() > app.network_refresh_interval()) =>
+            {
+                last_mempool_poll = Some(std::time::Instant::now());
+                mempool_poll_pending = true;
+                if let Some(client) = app.rpc_client.clone() {
+                    let tx_clone = tx.clone();
+                    tokio::spawn(async move {
+                        let result = match client.get().await {
+                            Ok(client) => client.get_mempool().await.map_err(anyhow::Error::from),
+                            Err(e) => Err(e),
+                        };
+                        let _ = tx_clone.send(AsyncMessage::Mempool(result)).await;
+                    });
+                }
+            }
+            _ => {}
+        }
+
         // Check for async results
         while let Ok(msg) = rx.try_recv() {
             match msg {
+                // Sent just before the user left the loading screen, which aborted the fetch
+                AsyncMessage::BlockResult(_)
+                | AsyncMessage::TxResult(_)
+                | AsyncMessage::AddressResult(_)
+                | AsyncMessage::Trace(_)
+                | AsyncMessage::Activity(_)
+                | AsyncMessage::Token(_)
+                | AsyncMessage::Logs(_)
+                    if !app.is_loading() => {}
                 AsyncMessage::BlockResult(Ok((info, transactions, stats))) => {
                     app.set_block_result(info, transactions, stats);
                 }
                 AsyncMessage::TxResult(Ok(info)) => app.set_tx_result(info),
                 AsyncMessage::AddressResult(Ok(info)) => app.set_address_result(info),
                 AsyncMessage::NetworkInfo(Ok(info)) => app.set_network_info(info),
+                AsyncMessage::Trace(Ok((hash, root))) => app.set_trace_result(hash, root),
+                AsyncMessage::Activity(Ok(scan)) => app.set_activity_result(scan),
+                AsyncMessage::Token(Ok(scan)) => app.set_token_result(scan),
+                AsyncMessage::Logs(Ok((query, from, page))) => {
+                    app.set_logs_result(query, from, page);
+                }
                 AsyncMessage::BlockResult(Err(e))
                 | AsyncMessage::TxResult(Err(e))
-                | AsyncMessage::AddressResult(Err(e)) => {
-                    // Use {:#} to get full error chain from anyhow
-                    app.set_error(format!("{e:#}"));
+                | AsyncMessage::AddressResult(Err(e))
+                | AsyncMessage::Trace(Err(e))
+                | AsyncMessage::Activity(Err(e))
+                | AsyncMessage::Token(Err(e))
+                | AsyncMessage::Logs(Err(e)) => {
+                    app.set_error(error_message(&e));
                 }
                 AsyncMessage::NetworkInfo(Err(_)) => {
                     // Silently ignore network info errors
                 }
+                AsyncMessage::Storage(address, result) => {
+                    app.set_storage_read(address, result.map_err(|e| format!("{e:#}")));
+                }
+                AsyncMessage::Call(address, result) => {
+                    app.set_call_result(address, result.map_err(|e| format!("{e:#}")));
+                }
+                AsyncMessage::StateDiff(hash, result) => app.set_state_diff(&hash, result),
+                AsyncMessage::TokenLookup(token, result) => {
+                    app.set_token_lookup(token, result.map_err(|e| format!("{e:#}")));
+                }
+                AsyncMessage::LogsPage(result) => {
+                    app.append_logs_page(result.map_err(|e| format!("{e:#}")));
+                }
+                AsyncMessage::Progress(msg) => app.update_loading(msg),
+                AsyncMessage::Mempool(Ok(pool)) => {
+                    mempool_poll_pending = false;
+                    app.set_mempool(pool);
+                }
+                AsyncMessage::Mempool(Err(e)) => {
+                    mempool_poll_pending = false;
+                    if app.mempool_source() == Some(MempoolSource::Loading)
+                        && app
+                            .rpc_transport
+                            .as_ref()
+                            .is_some_and(|t| t.supports_subscriptions())
+                    {
+                        app.follow_pending_transactions();
+                    } else {
+                        app.set_mempool_error(format!("{e:#}"));
+                    }
+                }
+                AsyncMessage::PendingTx(pool_tx) => app.push_pending_tx(pool_tx),
+                AsyncMessage::LiveBlocks(result) => {
+                    live_poll_pending = false;
+                    // A failed poll is retried on the next interval
+                    if let Ok(blocks) = result {
+                        app.push_live_blocks(blocks);
+                    }
+                }
             }
         }
 
@@ -125,6 +281,97 @@ async fn run_event_loop(
                     continue;
                 }
 
+                // Block prompts (storage inspector, call console, activity
+                // and token window prompts) take text input while open
+                if let Some((kind, prompt)) = app.block_prompt_mut() {
+                    match key.code {
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            app.should_quit = true;
+                        }
+                        KeyCode::Esc => app.close_block_prompt(),
+                        KeyCode::Tab => prompt.block_focused = !prompt.block_focused,
+                        KeyCode::Enter => match kind {
+                            PromptKind::Storage => {
+                                if let Some(request) = app.submit_storage_query() {
+                                    read_storage(app, request, tx.clone());
+                                }
+                            }
+                            PromptKind::Call => {
+                                if let Some(request) = app.submit_call() {
+                                    call_contract(app, request, tx.clone());
+                                }
+                            }
+                            PromptKind::Activity => {
+                                if let Some(request) = app.submit_activity_scan() {
+                                    scan_activity(app, request, tx.clone());
+                                }
+                            }
+                            PromptKind::TokenWindow => {
+                                if let Some(request) = app.submit_token_window() {
+                                    scan_token(app, request, tx.clone());
+                                }
+                            }
+                        },
+                        _ => {
+                            prompt.focused_input_mut().handle_event(&ev);
+                        }
+                    }
+                    if app.should_quit {
+                        break;
+                    }
+                    continue;
+                }
+
+                // The token page's lookup form too
+                if let Some(form) = app.token_lookup_mut() {
+                    match key.code {
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            app.should_quit = true;
+                        }
+                        KeyCode::Esc => app.close_token_lookup(),
+                        KeyCode::Tab | KeyCode::Down => form.focus_next(),
+                        KeyCode::BackTab | KeyCode::Up => form.focus_prev(),
+                        KeyCode::Enter => {
+                            if let Some(request) = app.submit_token_lookup() {
+                                lookup_token(app, request, tx.clone());
+                            }
+                        }
+                        _ => {
+                            form.focused_input_mut().handle_event(&ev);
+                        }
+                    }
+                    if app.should_quit {
+                        break;
+                    }
+                    continue;
+                }
+
+                // The log query form is a screen of text inputs
+                if let Some(form) = app.log_query_form_mut() {
+                    match key.code {
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            app.should_quit = true;
+                        }
+                        KeyCode::Esc => {
+                            app.go_back();
+                        }
+                        KeyCode::Tab | KeyCode::Down => form.focus_next(),
+                        KeyCode::BackTab | KeyCode::Up => form.focus_prev(),
+                        KeyCode::Enter => {
+                            if let Some(request) = app.submit_log_query() {
+                                query_logs(app, request, tx.clone());
+                            }
+                        }
+                        _ => {
+                            form.focused_input_mut().handle_event(&ev);
+                        }
+                    }
+                    if app.should_quit {
+                        break;
+                    }
+                    continue;
+                }
+
                 // Global keys
                 match key.code {
                     KeyCode::Esc => {
@@ -145,18 +392,7 @@ async fn run_event_loop(
                                 match app.submit_rpc() {
                                     Ok(()) => {
                                         // RPC configured, fetch network info
-                                        if let Some(ref url) = app.rpc_url {
-                                            let tx_clone = tx.clone();
-                                            let url_clone = url.clone();
-                                            tokio::spawn(async move {
-                                                if let Ok(client) = RpcClient::new(&url_clone) {
-                                                    let result = client.get_network_info().await;
-                                                    let _ = tx_clone
-                                                        .send(AsyncMessage::NetworkInfo(result))
-                                                        .await;
-                                                }
-                                            });
-                                        }
+                                        fetch_network_info(app, tx.clone());
                                     }
                                     Err(e) => {
                                         app.set_error(e);
@@ -182,6 +418,26 @@ async fn run_event_loop(
                                     execute_search(app, &query, tx.clone());
                                 }
                             }
+                            KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.clear_history_selection();
+                                app.open_live_blocks();
+                                last_live_poll = None;
+                            }
+                            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.clear_history_selection();
+                                app.open_mempool();
+                                last_mempool_poll = None;
+                            }
+                            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.clear_history_selection();
+                                app.open_log_query();
+                            }
+                            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                if app.switch_to_next_profile() {
+                                    last_network_refresh = std::time::Instant::now();
+                                    fetch_network_info(app, tx.clone());
+                                }
+                            }
                             KeyCode::Up => {
                                 app.select_history_prev();
                             }
@@ -214,6 +470,12 @@ async fn run_event_loop(
                         KeyCode::Down | KeyCode::Char('j') => {
                             app.select_next();
                         }
+                        KeyCode::Left => {
+                            app.select_left();
+                        }
+                        KeyCode::Right => {
+                            app.select_right();
+                        }
                         KeyCode::Tab => {
                             app.toggle_mode();
                         }
@@ -222,6 +484,50 @@ async fn run_event_loop(
                                 navigate_to_link(app, link, tx.clone());
                             }
                         }
+                        KeyCode::Char('t') => {
+                            if let Some(hash) = app.current_tx_hash() {
+                                open_trace(app, hash, tx.clone());
+                            } else if let Some(request) = app.token_page_request() {
+                                scan_token(app, request, tx.clone());
+                            }
+                        }
+                        KeyCode::Char('d') => {
+                            if let Some(hash) = app.state_diff_request() {
+                                trace_state_changes(app, hash, tx.clone());
+                            }
+                        }
+                        KeyCode::Char('s') => {
+                            app.open_storage_inspector();
+                        }
+                        KeyCode::Char('c') => {
+                            app.open_call_console();
+                        }
+                        KeyCode::Char('a') => {
+                            app.open_activity_prompt();
+                        }
+                        KeyCode::Char('l') => {
+                            app.open_address_log_query();
+                            app.open_token_lookup();
+                        }
+                        KeyCode::Char('w') => {
+                            app.open_token_window();
+                        }
+                        KeyCode::Char('n') => {
+                            if let Some(request) = app.next_logs_page() {
+                                fetch_logs_page(app, request, tx.clone());
+                            }
+                        }
+                        KeyCode::Backspace | KeyCode::Char('b') => {
+                            app.go_back();
+                        }
+                        KeyCode::Char('h') => {
+                            app.go_home();
+                        }
+                        _ => {}
+                    }
+                } else {
+                    // Leaving the loading screen cancels its fetch
+                    match key.code {
                         KeyCode::Backspace | KeyCode::Char('b') => {
                             app.go_back();
                         }
@@ -242,6 +548,351 @@ async fn run_event_loop(
     Ok(())
 }
 
+/// Refresh the network status shown on the home screen
+fn fetch_network_info(app: &App, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+    tokio::spawn(async move {
+        if let Ok(client) = client.get().await {
+            let result = client.get_network_info().await.map_err(anyhow::Error::from);
+            let _ = tx.send(AsyncMessage::NetworkInfo(result)).await;
+        }
+    });
+}
+
+/// Stream new heads into the live blocks screen until the task is aborted.
+/// The task ends early if the endpoint rejects eth_subscribe, and polling takes over.
+/// Subscriptions get a connection of their own, which closes with the task,
+/// so the node stops pushing once the screen is left.
+fn spawn_new_heads_subscription(
+    rpc_url: String,
+    tx: mpsc::Sender<AsyncMessage>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let Ok(client) = RpcClient::connect(&rpc_url).await else {
+            return;
+        };
+        let Ok(mut heads) = client.subscribe_new_heads().await else {
+            return;
+        };
+        while let Ok(header) = heads.recv().await {
+            let result = client
+                .get_block_header(header.number)
+                .await
+                .map(|b| vec![b])
+                .map_err(anyhow::Error::from);
+            if tx.send(AsyncMessage::LiveBlocks(result)).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// Error screen text: for RPC failures, what kind it was in plain words,
+/// then the full error chain (`{:#}`)
+fn error_message(e: &anyhow::Error) -> String {
+    match RpcError::of(e) {
+        Some(error) => format!("{}\n\n{e:#}", error.hint()),
+        None => format!("{e:#}"),
+    }
+}
+
+/// Whether the node answered that the thing asked for doesn't exist
+fn is_not_found(e: &tbex::rpc::Error) -> bool {
+    matches!(e.kind(), Some(RpcError::NotFound(_)))
+}
+
+/// A failed fetch's error, with the endpoint it went to
+fn fetch_error(e: anyhow::Error, rpc_url: &str) -> anyhow::Error {
+    anyhow::anyhow!("{}\n\nRPC: {rpc_url}", error_message(&e))
+}
+
+/// Follow newPendingTransactions for the mempool screen on WebSocket/IPC
+/// endpoints, fetching each announced transaction
+fn spawn_pending_tx_subscription(
+    rpc_url: String,
+    tx: mpsc::Sender<AsyncMessage>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let result = async {
+            let client = RpcClient::connect(&rpc_url).await?;
+            let hashes = client.subscribe_pending_transactions().await?;
+            anyhow::Ok((client, hashes))
+        }
+        .await;
+        let (client, mut hashes) = match result {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                let _ = tx.send(AsyncMessage::Mempool(Err(e))).await;
+                return;
+            }
+        };
+        // Look up several announced transactions at once; while all slots are
+        // busy the channel may lag, which only drops hashes we couldn't show
+        let client = Arc::new(client);
+        let mut fetches = tokio::task::JoinSet::new();
+        loop {
+            tokio::select! {
+                hash = hashes.recv(), if fetches.len() < MAX_CONCURRENT_REQUESTS => match hash {
+                    Ok(hash) => {
+                        let client = client.clone();
+                        fetches.spawn(async move { client.get_pool_transaction(hash).await });
+                    }
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => {
+                        let error = anyhow::anyhow!("The node closed the pending transaction feed");
+                        let _ = tx.send(AsyncMessage::Mempool(Err(error))).await;
+                        break;
+                    }
+                },
+                Some(fetched) = fetches.join_next() => {
+                    // Skip transactions mined or dropped before we got to them
+                    let Ok(Ok(Some(pool_tx))) = fetched else {
+                        continue;
+                    };
+                    if tx.send(AsyncMessage::PendingTx(pool_tx)).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Fetch the call trace of a transaction and show it on the trace screen
+fn open_trace(app: &mut App, hash: String, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+
+    app.set_loading("Tracing transaction...");
+    let fetch = tokio::spawn(async move {
+        let rpc_url_for_error = client.rpc_url().to_string();
+        let result = async {
+            let client = client.get().await?;
+            let root = client.get_call_trace(hash.parse()?).await?;
+            Ok((hash, root))
+        }
+        .await
+        .map_err(|e| fetch_error(e, &rpc_url_for_error));
+        let _ = tx.send(AsyncMessage::Trace(result)).await;
+    });
+    app.track_fetch(fetch.abort_handle());
+}
+
+/// Trace the state changes of the transaction on the tx page
+fn trace_state_changes(app: &App, hash: String, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+    tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            anyhow::Ok(client.get_state_diff(hash.parse()?).await?)
+        }
+        .await;
+        let _ = tx.send(AsyncMessage::StateDiff(hash, result)).await;
+    });
+}
+
+/// Read a storage slot for the inspector on the address page
+fn read_storage(app: &App, request: StorageRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+    tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let value = client
+                .get_storage_at(request.address, request.slot, request.block)
+                .await?;
+            anyhow::Ok(value)
+        }
+        .await
+        .map(|value| StorageRead {
+            expr: request.expr,
+            block: request.block_label,
+            slot: request.slot,
+            value,
+        });
+        let _ = tx
+            .send(AsyncMessage::Storage(request.address, result))
+            .await;
+    });
+}
+
+/// Run a read-only call for the console on the address page
+fn call_contract(app: &App, request: CallRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+    tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let outputs = client
+                .call_function(request.address, &request.call, request.block)
+                .await?;
+            anyhow::Ok(outputs)
+        }
+        .await
+        .map(|outputs| CallResult {
+            call: request.call.signature(),
+            block: request.block_label,
+            outputs,
+        });
+        let _ = tx.send(AsyncMessage::Call(request.address, result)).await;
+    });
+}
+
+/// Scan an address's token activity, reporting progress on the loading screen
+fn scan_activity(app: &mut App, request: ActivityRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+
+    app.set_loading("Scanning token activity...");
+    let fetch = tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let to = client.resolve_block_number(request.to).await?;
+            let from = match request.from {
+                Some(from) => client.resolve_block_number(from).await?,
+                None => to.saturating_sub(DEFAULT_ACTIVITY_RANGE - 1),
+            };
+            let progress_tx = tx.clone();
+            let scan = client
+                .scan_activity(request.address, from, to, move |low| {
+                    let done = (to - low + 1) * 100 / (to - from + 1);
+                    let _ = progress_tx.try_send(AsyncMessage::Progress(format!(
+                        "Scanning token activity... {done}% (block {low})"
+                    )));
+                })
+                .await?;
+            anyhow::Ok(scan)
+        }
+        .await;
+        let _ = tx.send(AsyncMessage::Activity(result)).await;
+    });
+    app.track_fetch(fetch.abort_handle());
+}
+
+/// Scan a token's transfers for the token page, reporting progress on the
+/// loading screen
+fn scan_token(app: &mut App, request: TokenRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+
+    app.set_loading("Scanning token transfers...");
+    let fetch = tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let to = client.resolve_block_number(request.to).await?;
+            let from = match request.from {
+                Some(from) => client.resolve_block_number(from).await?,
+                None => to.saturating_sub(DEFAULT_TOKEN_RANGE - 1),
+            };
+            let progress_tx = tx.clone();
+            let scan = client
+                .scan_token(request.token, from, to, move |low| {
+                    let done = (to - low + 1) * 100 / (to - from + 1);
+                    let _ = progress_tx.try_send(AsyncMessage::Progress(format!(
+                        "Scanning token transfers... {done}% (block {low})"
+                    )));
+                })
+                .await?;
+            anyhow::Ok(scan)
+        }
+        .await;
+        let _ = tx.send(AsyncMessage::Token(result)).await;
+    });
+    app.track_fetch(fetch.abort_handle());
+}
+
+/// Look up a balance and allowance for the form on the token page
+fn lookup_token(app: &App, request: TokenLookupRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+    tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let lookup = client
+                .token_balance_and_allowance(
+                    request.token,
+                    request.holder,
+                    request.spender,
+                    request.block,
+                )
+                .await?;
+            anyhow::Ok(lookup)
+        }
+        .await
+        .map(|(balance, allowance)| TokenLookup {
+            holder: request.holder,
+            spender: request.spender,
+            block: request.block_label,
+            balance,
+            allowance,
+        });
+        let _ = tx
+            .send(AsyncMessage::TokenLookup(request.token, result))
+            .await;
+    });
+}
+
+/// Run a log query, reporting progress on the loading screen
+fn query_logs(app: &mut App, request: LogQueryRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+
+    app.set_loading("Querying logs...");
+    let fetch = tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let to = client.resolve_block_number(request.to).await?;
+            let from = match request.from {
+                Some(from) => client.resolve_block_number(from).await?,
+                None => to.saturating_sub(DEFAULT_LOG_RANGE - 1),
+            };
+            let progress_tx = tx.clone();
+            let page = client
+                .query_logs(&request.query, from, to, move |low| {
+                    let _ = progress_tx.try_send(AsyncMessage::Progress(format!(
+                        "Querying logs... (block {low})"
+                    )));
+                })
+                .await?;
+            Ok((request.query, from, page))
+        }
+        .await;
+        let _ = tx.send(AsyncMessage::Logs(result)).await;
+    });
+    app.track_fetch(fetch.abort_handle());
+}
+
+/// Fetch an older page for the logs on screen
+fn fetch_logs_page(app: &App, request: LogQueryRequest, tx: mpsc::Sender<AsyncMessage>) {
+    let Some(client) = app.rpc_client.clone() else {
+        return;
+    };
+    tokio::spawn(async move {
+        let result = async {
+            let client = client.get().await?;
+            let to = client.resolve_block_number(request.to).await?;
+            let from = match request.from {
+                Some(from) => client.resolve_block_number(from).await?,
+                None => to.saturating_sub(DEFAULT_LOG_RANGE - 1),
+            };
+            anyhow::Ok(client.query_logs(&request.query, from, to, |_| {}).await?)
+        }
+        .await;
+        let _ = tx.send(AsyncMessage::LogsPage(result)).await;
+    });
+}
+
 fn navigate_to_link(app: &mut App, link: NavLink, tx: mpsc::Sender<AsyncMessage>) {
     match link {
         NavLink::Address(addr) => {
@@ -264,81 +915,160 @@ fn execute_search(app: &mut App, query: &str, tx: mpsc::Sender<AsyncMessage>) {
         return;
     }
 
-    let Some(_) = &app.rpc_client else {
+    let Some(client) = app.rpc_client.clone() else {
         app.set_error("No RPC configured. Use 'tbex set-rpc <url>' first.".into());
         return;
     };
+    let rpc_url_for_error = client.rpc_url().to_string();
 
-    let rpc_url = app.rpc_url.clone().unwrap();
+    // `addr@block` reads the address state at that block
+    let (parsed, block) = match parsed {
+        SearchQuery::AtBlock(query, block) => (*query, Some(block)),
+        query => (query, None),
+    };
 
     match parsed {
-        SearchQuery::BlockNumber(num) => {
-            app.set_loading(&format!("Fetching block {num}..."));
+        SearchQuery::BlockNumber(_)
+        | SearchQuery::BlockTag(_)
+        | SearchQuery::RelativeBlock(..)
+        | SearchQuery::Timestamp(_) => {
+            let loading = match &parsed {
+                SearchQuery::BlockNumber(num) => format!("Fetching block {num}..."),
+                SearchQuery::BlockTag(tag) => format!("Fetching {tag} block..."),
+                SearchQuery::RelativeBlock(tag, offset) => {
+                    format!("Fetching block {tag}-{offset}...")
+                }
+                SearchQuery::Timestamp(timestamp) => format!(
+                    "Searching for the block at {}...",
+                    format_utc_timestamp(*timestamp)
+                ),
+                _ => unreachable!(),
+            };
+            app.set_loading(&loading);
             let tx = tx.clone();
-            let rpc_url_for_error = rpc_url.clone();
-            tokio::spawn(async move {
-                let client = RpcClient::new(&rpc_url).unwrap();
+            let fetch = tokio::spawn(async move {
                 let result = async {
-                    let info = client.get_block(num).await?;
-                    let (transactions, stats) = client.get_block_transactions(num).await?;
-                    Ok((info, transactions, stats))
+                    let client = client.get().await?;
+                    let num = match parsed {
+                        SearchQuery::BlockNumber(num) => num,
+                        SearchQuery::BlockTag(tag) => {
+                            client.resolve_block_number(tag.into()).await?
+                        }
+                        SearchQuery::RelativeBlock(tag, offset) => client
+                            .resolve_block_number(tag.into())
+                            .await?
+                            .checked_sub(offset)
+                            .ok_or_else(|| {
+                                anyhow::anyhow!("{tag}-{offset} is before the genesis block")
+                            })?,
+                        SearchQuery::Timestamp(timestamp) => {
+                            client.find_block_by_timestamp(timestamp).await?
+                        }
+                        _ => unreachable!(),
+                    };
+                    anyhow::Ok(client.get_block_page(num).await?)
                 }
                 .await
-                .map_err(|e: anyhow::Error| {
-                    anyhow::anyhow!("{e:#}\n\nRPC: {rpc_url_for_error}")
-                });
+                .map_err(|e| fetch_error(e, &rpc_url_for_error));
                 let _ = tx.send(AsyncMessage::BlockResult(result)).await;
             });
+            app.track_fetch(fetch.abort_handle());
         }
-        SearchQuery::TxHash(hash) => {
-            app.set_loading("Fetching transaction...");
+        SearchQuery::Hash32(hash) => {
+            app.set_loading("Fetching transaction or block...");
             let tx = tx.clone();
-            let rpc_url_for_error = rpc_url.clone();
-            tokio::spawn(async move {
-                let client = RpcClient::new(&rpc_url).unwrap();
-                let result = async {
+            let fetch = tokio::spawn(async move {
+                // The hash could be either, so look for both and show whichever exists
+                let message = async {
+                    let client = client.get().await?;
                     let hash: TxHash = hash.parse()?;
-                    client.get_transaction(hash).await
+                    let (tx_info, block_info) =
+                        tokio::join!(client.get_transaction(hash), client.get_block_by_hash(hash));
+                    match (tx_info, block_info) {
+                        (Ok(info), _) => Ok(AsyncMessage::TxResult(Ok(info))),
+                        (Err(_), Ok(info)) => {
+                            let page = client.get_block_page(info.number).await?;
+                            Ok(AsyncMessage::BlockResult(Ok(page)))
+                        }
+                        // Only a miss on both sides means the hash isn't on this
+                        // chain; anything else (rate limit, bad endpoint) is the error
+                        (Err(tx_err), Err(block_err)) => {
+                            match (is_not_found(&tx_err), is_not_found(&block_err)) {
+                                (true, true) => Err(tx_err.into_inner().context(format!(
+                                    "No transaction or block with hash {hash:?} \
+                                     (search topic:{hash:?} for an event topic)"
+                                ))),
+                                (false, _) => Err(tx_err.into()),
+                                (true, false) => Err(block_err.into()),
+                            }
+                        }
+                    }
                 }
                 .await
-                .map_err(|e: anyhow::Error| {
-                    anyhow::anyhow!("{e:#}\n\nRPC: {rpc_url_for_error}")
+                .unwrap_or_else(|e| {
+                    AsyncMessage::TxResult(Err(fetch_error(e, &rpc_url_for_error)))
                 });
-                let _ = tx.send(AsyncMessage::TxResult(result)).await;
+                let _ = tx.send(message).await;
             });
+            app.track_fetch(fetch.abort_handle());
         }
         SearchQuery::Address(addr) => {
-            app.set_loading("Fetching address...");
+            match block {
+                Some(block) => app.set_loading(&format!(
+                    "Fetching address at block {}...",
+                    block_label(block)
+                )),
+                None => app.set_loading("Fetching address..."),
+            }
             let tx = tx.clone();
-            let rpc_url_for_error = rpc_url.clone();
-            tokio::spawn(async move {
-                let client = RpcClient::new(&rpc_url).unwrap();
+            let fetch = tokio::spawn(async move {
                 let result = async {
+                    let client = client.get().await?;
                     let addr: Address = addr.parse()?;
-                    client.get_address(addr).await
+                    let info = client
+                        .get_address_at(addr, block.unwrap_or_default())
+                        .await?;
+                    anyhow::Ok(info)
                 }
                 .await
-                .map_err(|e: anyhow::Error| {
-                    anyhow::anyhow!("{e:#}\n\nRPC: {rpc_url_for_error}")
-                });
+                .map_err(|e| fetch_error(e, &rpc_url_for_error));
                 let _ = tx.send(AsyncMessage::AddressResult(result)).await;
             });
+            app.track_fetch(fetch.abort_handle());
         }
         SearchQuery::EnsName(name) => {
             app.set_loading(&format!("Resolving {name}..."));
             let tx = tx.clone();
-            tokio::spawn(async move {
-                let client = RpcClient::new(&rpc_url).unwrap();
+            let fetch = tokio::spawn(async move {
                 let result = async {
+                    let client = client.get().await?;
                     // First resolve ENS name to address
                     let addr = client.resolve_ens_to_address(&name).await?;
                     // Then fetch address info
-                    client.get_address(addr).await
+                    let info = client
+                        .get_address_at(addr, block.unwrap_or_default())
+                        .await?;
+                    anyhow::Ok(info)
                 }
                 .await;
                 let _ = tx.send(AsyncMessage::AddressResult(result)).await;
             });
+            app.track_fetch(fetch.abort_handle());
+        }
+        SearchQuery::Selector(selector) => {
+            let lookup = SignatureLookup::selector(selector, &app.abis, SignatureDb::global());
+            app.set_signature_result(lookup);
+        }
+        SearchQuery::Topic(topic) => {
+            let lookup = SignatureLookup::topic(topic, &app.abis, SignatureDb::global());
+            app.set_signature_result(lookup);
+        }
+        SearchQuery::Signature(text) => {
+            match SignatureLookup::signature(&text, &app.abis, SignatureDb::global()) {
+                Ok(lookup) => app.set_signature_result(lookup),
+                Err(e) => app.set_error(format!("{e:#}")),
+            }
         }
-        SearchQuery::Invalid(_) => unreachable!(),
+        SearchQuery::AtBlock(..) | SearchQuery::Invalid(_) => unreachable!(),
     }
 }
@@ -0,0 +1,468 @@
+use super::types::{DecodedParam, TxInfo};
+use alloy::{
+    dyn_abi::{DynSolValue, EventExt, JsonAbiExt},
+    json_abi::{Error, Event, Function, JsonAbi, Param},
+    primitives::{Address, Selector, B256},
+    rpc::types::Log,
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Directories never worth descending into when scanning for ABIs
+/// (Foundry's build-info holds multi-megabyte compiler outputs)
+const SKIPPED_DIRS: &[&str] = &["build-info", "node_modules", ".git", "cache"];
+
+/// ABIs loaded from user-supplied JSON files, used to decode calldata and logs
+/// of contracts tbex has no built-in knowledge of.
+///
+/// Files named `<address>.json` only apply to that address; any other file
+/// (e.g. Foundry `out/Foo.sol/Foo.json` artifacts) is matched by selector/topic
+/// for every contract.
+#[derive(Debug, Default)]
+pub struct AbiRegistry {
+    contracts: HashMap<Address, JsonAbi>,
+    functions: HashMap<Selector, Function>,
+    events: HashMap<B256, Event>,
+    errors: HashMap<Selector, Error>,
+    file_count: usize,
+}
+
+impl AbiRegistry {
+    /// Recursively load every ABI found in `dirs`. Missing directories and files
+    /// that aren't ABIs or compiler artifacts are skipped.
+    pub fn load(dirs: &[PathBuf]) -> Self {
+        let mut registry = Self::default();
+        for dir in dirs {
+            registry.load_dir(dir);
+        }
+        registry
+    }
+
+    fn load_dir(&mut self, dir: &Path) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let skipped = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| SKIPPED_DIRS.contains(&n));
+                if !skipped {
+                    self.load_dir(&path);
+                }
+            } else if path.extension().is_some_and(|ext| ext == "json") {
+                let Some(abi) = std::fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|json| parse_abi_json(&json))
+                else {
+                    continue;
+                };
+                let address = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .and_then(|s| s.parse::<Address>().ok());
+                self.insert(address, abi);
+            }
+        }
+    }
+
+    /// Register an ABI, optionally bound to a single contract address
+    pub fn insert(&mut self, address: Option<Address>, abi: JsonAbi) {
+        for function in abi.functions() {
+            self.functions
+                .entry(function.selector())
+                .or_insert_with(|| function.clone());
+        }
+        for event in abi.events().filter(|e| !e.anonymous) {
+            self.events
+                .entry(event.selector())
+                .or_insert_with(|| event.clone());
+        }
+        for error in abi.errors() {
+            self.errors
+                .entry(error.selector())
+                .or_insert_with(|| error.clone());
+        }
+        if let Some(address) = address {
+            self.contracts.insert(address, abi);
+        }
+        self.file_count += 1;
+    }
+
+    /// Number of ABI files loaded
+    pub fn len(&self) -> usize {
+        self.file_count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.file_count == 0
+    }
+
+    /// Find a function by selector, preferring the ABI registered for `contract`
+    pub fn function(&self, contract: Option<Address>, selector: Selector) -> Option<&Function> {
+        contract
+            .and_then(|addr| self.contracts.get(&addr))
+            .and_then(|abi| abi.functions().find(|f| f.selector() == selector))
+            .or_else(|| self.functions.get(&selector))
+    }
+
+    /// Find an event by topic0, preferring the ABI registered for `contract`
+    pub fn event(&self, contract: Option<Address>, topic0: B256) -> Option<&Event> {
+        contract
+            .and_then(|addr| self.contracts.get(&addr))
+            .and_then(|abi| {
+                abi.events()
+                    .find(|e| !e.anonymous && e.selector() == topic0)
+            })
+            .or_else(|| self.events.get(&topic0))
+    }
+
+    /// Find a custom error by selector, preferring the ABI registered for `contract`
+    pub fn error(&self, contract: Option<Address>, selector: Selector) -> Option<&Error> {
+        contract
+            .and_then(|addr| self.contracts.get(&addr))
+            .and_then(|abi| abi.errors().find(|e| e.selector() == selector))
+            .or_else(|| self.errors.get(&selector))
+    }
+
+    /// Decode revert data into the custom error's signature and its arguments
+    pub fn decode_error(
+        &self,
+        contract: Option<Address>,
+        data: &[u8],
+    ) -> Option<(String, Vec<DecodedParam>)> {
+        let selector = Selector::try_from(data.get(..4)?).ok()?;
+        let error = self.error(contract, selector)?;
+        let values = error.abi_decode_input(&data[4..]).ok()?;
+        Some((error.signature(), decode_params(&error.inputs, &values)))
+    }
+
+    /// Decode calldata into the function signature and its arguments
+    pub fn decode_call(
+        &self,
+        contract: Option<Address>,
+        input: &[u8],
+    ) -> Option<(String, Vec<DecodedParam>)> {
+        let selector = Selector::try_from(input.get(..4)?).ok()?;
+        let function = self.function(contract, selector)?;
+        let values = function.abi_decode_input(&input[4..]).ok()?;
+        Some((
+            function.signature(),
+            decode_params(&function.inputs, &values),
+        ))
+    }
+
+    /// Decode a log into the event signature and all of its parameters
+    pub fn decode_log(
+        &self,
+        contract: Address,
+        topics: &[B256],
+        data: &[u8],
+    ) -> Option<(String, Vec<DecodedParam>)> {
+        let event = self.event(Some(contract), *topics.first()?)?;
+        Some((event.signature(), decode_event(event, topics, data)?))
+    }
+
+    /// Replace the built-in method/event decoding of `info` wherever a loaded
+    /// ABI matches. `logs` must be the receipt logs `info.logs` was built from.
+    pub fn decode_transaction(&self, info: &mut TxInfo, to: Option<Address>, logs: &[Log]) {
+        if self.is_empty() {
+            return;
+        }
+
+        if let Some((signature, inputs)) = self.decode_call(to, &info.input_data) {
+            info.decoded_method = Some(signature);
+            info.decoded_inputs = inputs;
+        }
+
+        for (decoded, log) in info.logs.iter_mut().zip(logs) {
+            if let Some((signature, params)) =
+                self.decode_log(log.address(), log.topics(), &log.data().data)
+            {
+                decoded.event_name = Some(signature);
+                decoded.decoded_params = params;
+            }
+        }
+    }
+}
+
+/// Decode a log's topics and data with an event, parameters in declaration order
+pub(super) fn decode_event(
+    event: &Event,
+    topics: &[B256],
+    data: &[u8],
+) -> Option<Vec<DecodedParam>> {
+    let decoded = event.decode_log_parts(topics.iter().copied(), data).ok()?;
+
+    // Re-interleave indexed and body values back into declaration order
+    let mut indexed = decoded.indexed.into_iter();
+    let mut body = decoded.body.into_iter();
+    let values: Vec<DynSolValue> = event
+        .inputs
+        .iter()
+        .filter_map(|input| {
+            if input.indexed {
+                indexed.next()
+            } else {
+                body.next()
+            }
+        })
+        .collect();
+
+    let params: Vec<Param> = event
+        .inputs
+        .iter()
+        .map(|input| Param {
+            ty: input.ty.clone(),
+            name: input.name.clone(),
+            components: input.components.clone(),
+            internal_type: input.internal_type.clone(),
+        })
+        .collect();
+    Some(decode_params(&params, &values))
+}
+
+/// Decode arguments using only a text signature like `transfer(address,uint256)`
+/// (parameters get positional names)
+pub(super) fn decode_with_signature(signature: &str, args: &[u8]) -> Option<Vec<DecodedParam>> {
+    let function = Function::parse(signature).ok()?;
+    let values = function.abi_decode_input(args).ok()?;
+    Some(decode_params(&function.inputs, &values))
+}
+
+/// Parse either a bare ABI array or a compiler artifact with an `abi` field
+/// (Foundry, Hardhat, Truffle)
+fn parse_abi_json(json: &str) -> Option<JsonAbi> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let abi = match value {
+        serde_json::Value::Array(_) => value,
+        serde_json::Value::Object(mut obj) => obj.remove("abi")?,
+        _ => return None,
+    };
+    serde_json::from_value(abi).ok()
+}
+
+pub(super) fn decode_params(params: &[Param], values: &[DynSolValue]) -> Vec<DecodedParam> {
+    params
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(i, (param, value))| {
+            let name = if param.name.is_empty() {
+                format!("arg{i}")
+            } else {
+                param.name.clone()
+            };
+            to_decoded_param(name, &param.ty, &param.components, value)
+        })
+        .collect()
+}
+
+/// Convert a decoded value into a `DecodedParam`, recursing into tuples and arrays
+fn to_decoded_param(
+    name: String,
+    ty: &str,
+    components: &[Param],
+    value: &DynSolValue,
+) -> DecodedParam {
+    let mut param = DecodedParam {
+        name,
+        ty: Some(ty.to_string()),
+        ..Default::default()
+    };
+
+    match value {
+        DynSolValue::Address(addr) => {
+            param.value = format!("{addr:?}");
+            param.is_address = true;
+        }
+        DynSolValue::Bool(b) => param.value = b.to_string(),
+        DynSolValue::Uint(n, _) => param.value = n.to_string(),
+        DynSolValue::Int(n, _) => param.value = n.to_string(),
+        DynSolValue::FixedBytes(word, size) => {
+            param.value = format!("0x{}", alloy::hex::encode(&word[..*size]));
+        }
+        DynSolValue::Bytes(bytes) => param.value = format!("0x{}", alloy::hex::encode(bytes)),
+        DynSolValue::Function(f) => param.value = format!("0x{}", alloy::hex::encode(f)),
+        DynSolValue::String(s) => param.value = format!("{s:?}"),
+        DynSolValue::Tuple(values) => {
+            param.value = format!("({} fields)", values.len());
+            param.children = decode_params(components, values);
+        }
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+            // "uint256[2][]" -> element type "uint256[2]"
+            let elem_ty = ty.rfind('[').map_or(ty, |i| &ty[..i]);
+            param.value = format!("[{} items]", values.len());
+            param.children = values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| to_decoded_param(format!("[{i}]"), elem_ty, components, v))
+                .collect();
+        }
+        #[allow(unreachable_patterns)]
+        _ => param.value = format!("{value:?}"),
+    }
+
+    param
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, keccak256, U256};
+    use alloy::sol_types::SolValue;
+
+    const ERC20_ABI: &str = r#"[
+        {"type":"function","name":"transfer","stateMutability":"nonpayable",
+         "inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],
+         "outputs":[{"name":"","type":"bool"}]},
+        {"type":"event","name":"Transfer","anonymous":false,
+         "inputs":[{"name":"from","type":"address","indexed":true},
+                   {"name":"to","type":"address","indexed":true},
+                   {"name":"value","type":"uint256","indexed":false}]}
+    ]"#;
+
+    const ORDER_ABI: &str = r#"{"abi":[
+        {"type":"function","name":"fill","stateMutability":"nonpayable",
+         "inputs":[{"name":"order","type":"tuple","components":[
+             {"name":"maker","type":"address"},{"name":"amounts","type":"uint256[]"}]},
+             {"name":"","type":"bool"}],
+         "outputs":[]}
+    ],"bytecode":{"object":"0x"}}"#;
+
+    fn registry(json: &str, address: Option<Address>) -> AbiRegistry {
+        let mut registry = AbiRegistry::default();
+        registry.insert(address, parse_abi_json(json).unwrap());
+        registry
+    }
+
+    #[test]
+    fn test_parse_abi_json_formats() {
+        assert!(parse_abi_json(ERC20_ABI).is_some());
+        // Foundry artifact with an "abi" field
+        assert!(parse_abi_json(ORDER_ABI).is_some());
+        assert!(parse_abi_json(r#"{"bytecode":"0x"}"#).is_none());
+        assert!(parse_abi_json("not json").is_none());
+    }
+
+    #[test]
+    fn test_decode_call() {
+        let registry = registry(ERC20_ABI, None);
+        let to = address!("00000000000000000000000000000000000000aa");
+        let mut input = keccak256("transfer(address,uint256)")[..4].to_vec();
+        input.extend((to, U256::from(1000u64)).abi_encode_params());
+
+        let (signature, params) = registry.decode_call(None, &input).unwrap();
+        assert_eq!(signature, "transfer(address,uint256)");
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].name, "to");
+        assert!(params[0].is_address);
+        assert_eq!(params[0].value, format!("{to:?}"));
+        assert_eq!(params[1].value, "1000");
+        assert_eq!(params[1].ty.as_deref(), Some("uint256"));
+    }
+
+    #[test]
+    fn test_decode_call_nested() {
+        let registry = registry(ORDER_ABI, None);
+        let maker = address!("00000000000000000000000000000000000000bb");
+        let mut input = keccak256("fill((address,uint256[]),bool)")[..4].to_vec();
+        input.extend(((maker, vec![U256::from(1u64), U256::from(2u64)]), true).abi_encode_params());
+
+        let (_, params) = registry.decode_call(None, &input).unwrap();
+        assert_eq!(params[0].name, "order");
+        assert_eq!(params[0].children.len(), 2);
+        assert!(params[0].children[0].is_address);
+        let amounts = &params[0].children[1];
+        assert_eq!(amounts.value, "[2 items]");
+        assert_eq!(amounts.children[1].name, "[1]");
+        assert_eq!(amounts.children[1].value, "2");
+        assert_eq!(amounts.children[1].ty.as_deref(), Some("uint256"));
+        // Unnamed parameters get positional names
+        assert_eq!(params[1].name, "arg1");
+        assert_eq!(params[1].value, "true");
+    }
+
+    #[test]
+    fn test_decode_custom_error() {
+        let registry = registry(
+            r#"[{"type":"error","name":"InsufficientBalance",
+                "inputs":[{"name":"available","type":"uint256"},
+                          {"name":"required","type":"uint256"}]}]"#,
+            None,
+        );
+        let mut data = keccak256("InsufficientBalance(uint256,uint256)")[..4].to_vec();
+        data.extend((U256::from(1u64), U256::from(2u64)).abi_encode_params());
+
+        let (signature, params) = registry.decode_error(None, &data).unwrap();
+        assert_eq!(signature, "InsufficientBalance(uint256,uint256)");
+        assert_eq!(params[0].name, "available");
+        assert_eq!(params[1].value, "2");
+    }
+
+    #[test]
+    fn test_decode_call_unknown_selector() {
+        let registry = registry(ERC20_ABI, None);
+        assert!(registry
+            .decode_call(None, &[0xde, 0xad, 0xbe, 0xef])
+            .is_none());
+        assert!(registry.decode_call(None, &[0x01]).is_none());
+    }
+
+    #[test]
+    fn test_decode_log() {
+        let registry = registry(ERC20_ABI, None);
+        let from = address!("00000000000000000000000000000000000000aa");
+        let to = address!("00000000000000000000000000000000000000bb");
+        let topics = vec![
+            keccak256("Transfer(address,address,uint256)"),
+            from.into_word(),
+            to.into_word(),
+        ];
+        let data = U256::from(42u64).abi_encode();
+
+        let (signature, params) = registry.decode_log(Address::ZERO, &topics, &data).unwrap();
+        assert_eq!(signature, "Transfer(address,address,uint256)");
+        let names: Vec<&str> = params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["from", "to", "value"]);
+        assert_eq!(params[1].value, format!("{to:?}"));
+        assert_eq!(params[2].value, "42");
+    }
+
+    #[test]
+    fn test_address_specific_abi() {
+        let token = address!("00000000000000000000000000000000000000cc");
+        let registry = registry(ERC20_ABI, Some(token));
+        let selector = Selector::from_slice(&keccak256("transfer(address,uint256)")[..4]);
+        assert!(registry.function(Some(token), selector).is_some());
+        assert!(registry.function(None, selector).is_some());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = std::env::temp_dir().join(format!("tbex-abi-test-{}", std::process::id()));
+        let nested = dir.join("out/Order.sol");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir_all(dir.join("out/build-info")).unwrap();
+        std::fs::write(
+            dir.join("0x00000000000000000000000000000000000000cc.json"),
+            ERC20_ABI,
+        )
+        .unwrap();
+        std::fs::write(nested.join("Order.json"), ORDER_ABI).unwrap();
+        std::fs::write(dir.join("out/build-info/abc.json"), ERC20_ABI).unwrap();
+        std::fs::write(dir.join("notes.json"), "{}").unwrap();
+
+        let registry = AbiRegistry::load(&[dir.clone(), dir.join("missing")]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(registry.len(), 2);
+        assert!(registry
+            .contracts
+            .contains_key(&address!("00000000000000000000000000000000000000cc")));
+    }
+}
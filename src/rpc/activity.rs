@@ -0,0 +1,304 @@
+//! Token activity of an address, found by scanning `eth_getLogs` for ERC-20
+//! and ERC-721 Transfer/Approval events with the address in an indexed topic.
+
+use alloy::primitives::{b256, Address, TxHash, B256, U256};
+use alloy::rpc::types::Log;
+use std::collections::HashMap;
+
+/// keccak256("Transfer(address,address,uint256)"), shared by ERC-20 and ERC-721
+pub(super) const TRANSFER_TOPIC: B256 =
+    b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+/// keccak256("Approval(address,address,uint256)"), shared by ERC-20 and ERC-721
+pub(super) const APPROVAL_TOPIC: B256 =
+    b256!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+/// A scan stops once it has found this many events
+pub const MAX_ACTIVITY_EVENTS: usize = 5_000;
+/// Tokens whose symbol and decimals are looked up after a scan
+pub(super) const ACTIVITY_TOKEN_LOOKUPS: usize = 50;
+/// Blocks scanned when no start block is given
+pub const DEFAULT_ACTIVITY_RANGE: u64 = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    Transfer,
+    Approval,
+}
+
+/// One Transfer or Approval log involving the scanned address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityEvent {
+    pub block: u64,
+    pub tx_hash: TxHash,
+    pub log_index: u64,
+    pub token: Address,
+    pub kind: ActivityKind,
+    /// Sender, or owner for approvals
+    pub from: Address,
+    /// Recipient, or spender for approvals
+    pub to: Address,
+    /// ERC-20 amount
+    pub amount: Option<U256>,
+    /// ERC-721 token id (indexed as a fourth topic)
+    pub token_id: Option<U256>,
+}
+
+impl ActivityEvent {
+    /// Parse a Transfer or Approval log; other logs and logs the node
+    /// returned without a block or transaction are skipped
+    pub(super) fn from_log(log: &Log) -> Option<Self> {
+        let topics = log.topics();
+        let kind = match topics.first()? {
+            t if *t == TRANSFER_TOPIC => ActivityKind::Transfer,
+            t if *t == APPROVAL_TOPIC => ActivityKind::Approval,
+            _ => return None,
+        };
+        let (amount, token_id) = match topics.len() {
+            3 => {
+                let data = &log.data().data;
+                (Some(U256::try_from_be_slice(data.get(..32)?)?), None)
+            }
+            4 => (None, Some(U256::from_be_bytes(topics[3].0))),
+            _ => return None,
+        };
+        Some(Self {
+            block: log.block_number?,
+            tx_hash: log.transaction_hash?,
+            log_index: log.log_index.unwrap_or_default(),
+            token: log.address(),
+            kind,
+            from: Address::from_word(topics[1]),
+            to: Address::from_word(topics[2]),
+            amount,
+            token_id,
+        })
+    }
+
+    pub fn is_nft(&self) -> bool {
+        self.token_id.is_some()
+    }
+}
+
+/// A token the address sent or received during the scan
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenHolding {
+    pub token: Address,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+    /// ERC-20 amounts received and sent within the scanned blocks
+    pub received: U256,
+    pub sent: U256,
+    /// ERC-721 ids received and not sent on by the end of the scan
+    pub token_ids: Vec<U256>,
+    pub nft: bool,
+}
+
+/// Result of an activity scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityScan {
+    pub address: Address,
+    pub from_block: u64,
+    pub to_block: u64,
+    /// Lowest block scanned; above from_block when the scan stopped at
+    /// MAX_ACTIVITY_EVENTS
+    pub scanned_from: u64,
+    /// Newest first
+    pub events: Vec<ActivityEvent>,
+    pub holdings: Vec<TokenHolding>,
+}
+
+impl ActivityScan {
+    pub fn truncated(&self) -> bool {
+        self.scanned_from > self.from_block
+    }
+
+    pub fn holding(&self, token: Address) -> Option<&TokenHolding> {
+        self.holdings.iter().find(|h| h.token == token)
+    }
+}
+
+/// Derive per-token holdings from an address's transfers, in any order.
+/// Tokens are listed in order of first appearance.
+pub(super) fn derive_holdings(address: Address, events: &[ActivityEvent]) -> Vec<TokenHolding> {
+    let mut transfers: Vec<&ActivityEvent> = events
+        .iter()
+        .filter(|e| e.kind == ActivityKind::Transfer)
+        .collect();
+    transfers.sort_by_key(|e| (e.block, e.log_index));
+
+    let mut holdings: Vec<TokenHolding> = Vec::new();
+    let mut index: HashMap<Address, usize> = HashMap::new();
+    for event in transfers {
+        let i = *index.entry(event.token).or_insert_with(|| {
+            holdings.push(TokenHolding {
+                token: event.token,
+                nft: event.is_nft(),
+                ..Default::default()
+            });
+            holdings.len() - 1
+        });
+        let holding = &mut holdings[i];
+        let incoming = event.to == address;
+        let outgoing = event.from == address;
+        match (event.amount, event.token_id) {
+            (Some(amount), _) => {
+                if incoming {
+                    holding.received = holding.received.saturating_add(amount);
+                }
+                if outgoing {
+                    holding.sent = holding.sent.saturating_add(amount);
+                }
+            }
+            (None, Some(id)) => {
+                if outgoing {
+                    holding.token_ids.retain(|held| *held != id);
+                }
+                if incoming && !holding.token_ids.contains(&id) {
+                    holding.token_ids.push(id);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    holdings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, keccak256, Bytes, LogData};
+
+    const HOLDER: Address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    const OTHER: Address = address!("1111111111111111111111111111111111111111");
+    const TOKEN: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+    const NFT: Address = address!("BC4CA0EdA7647A8aB7C2061c2E118A18a936f13D");
+
+    fn mock_log(token: Address, topics: Vec<B256>, data: Vec<u8>, block: u64, index: u64) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: token,
+                data: LogData::new_unchecked(topics, Bytes::from(data)),
+            },
+            block_number: Some(block),
+            transaction_hash: Some(B256::repeat_byte(block as u8)),
+            log_index: Some(index),
+            ..Default::default()
+        }
+    }
+
+    fn erc20(from: Address, to: Address, amount: u64, block: u64) -> ActivityEvent {
+        let log = mock_log(
+            TOKEN,
+            vec![TRANSFER_TOPIC, from.into_word(), to.into_word()],
+            B256::from(U256::from(amount)).to_vec(),
+            block,
+            0,
+        );
+        ActivityEvent::from_log(&log).unwrap()
+    }
+
+    fn erc721(from: Address, to: Address, id: u64, block: u64) -> ActivityEvent {
+        let log = mock_log(
+            NFT,
+            vec![
+                TRANSFER_TOPIC,
+                from.into_word(),
+                to.into_word(),
+                B256::from(U256::from(id)),
+            ],
+            vec![],
+            block,
+            1,
+        );
+        ActivityEvent::from_log(&log).unwrap()
+    }
+
+    #[test]
+    fn test_topic_constants() {
+        assert_eq!(
+            TRANSFER_TOPIC,
+            keccak256("Transfer(address,address,uint256)")
+        );
+        assert_eq!(
+            APPROVAL_TOPIC,
+            keccak256("Approval(address,address,uint256)")
+        );
+    }
+
+    #[test]
+    fn test_event_from_log() {
+        let transfer = erc20(OTHER, HOLDER, 250, 7);
+        assert_eq!(transfer.kind, ActivityKind::Transfer);
+        assert_eq!((transfer.from, transfer.to), (OTHER, HOLDER));
+        assert_eq!(transfer.amount, Some(U256::from(250)));
+        assert!(!transfer.is_nft());
+        assert_eq!(transfer.block, 7);
+
+        let nft = erc721(HOLDER, OTHER, 42, 8);
+        assert_eq!(nft.token_id, Some(U256::from(42)));
+        assert_eq!(nft.amount, None);
+
+        let approval = mock_log(
+            TOKEN,
+            vec![APPROVAL_TOPIC, HOLDER.into_word(), OTHER.into_word()],
+            B256::repeat_byte(0xff).to_vec(),
+            9,
+            2,
+        );
+        let approval = ActivityEvent::from_log(&approval).unwrap();
+        assert_eq!(approval.kind, ActivityKind::Approval);
+        assert_eq!(approval.amount, Some(U256::MAX));
+
+        // Other events, malformed data and pending logs are skipped
+        let other = mock_log(
+            TOKEN,
+            vec![B256::ZERO, B256::ZERO, B256::ZERO],
+            vec![0; 32],
+            1,
+            0,
+        );
+        assert_eq!(ActivityEvent::from_log(&other), None);
+        let short = mock_log(
+            TOKEN,
+            vec![TRANSFER_TOPIC, OTHER.into_word(), HOLDER.into_word()],
+            vec![0; 4],
+            1,
+            0,
+        );
+        assert_eq!(ActivityEvent::from_log(&short), None);
+        let mut pending = mock_log(
+            TOKEN,
+            vec![TRANSFER_TOPIC, OTHER.into_word(), HOLDER.into_word()],
+            vec![0; 32],
+            1,
+            0,
+        );
+        pending.block_number = None;
+        assert_eq!(ActivityEvent::from_log(&pending), None);
+    }
+
+    #[test]
+    fn test_derive_holdings() {
+        // Newest first, as a scan returns them
+        let events = vec![
+            erc721(HOLDER, OTHER, 1, 40),
+            erc20(HOLDER, OTHER, 30, 30),
+            erc721(OTHER, HOLDER, 2, 20),
+            erc721(OTHER, HOLDER, 1, 20),
+            erc20(OTHER, HOLDER, 100, 10),
+        ];
+        let holdings = derive_holdings(HOLDER, &events);
+        assert_eq!(holdings.len(), 2);
+
+        let token = &holdings[0];
+        assert_eq!(token.token, TOKEN);
+        assert!(!token.nft);
+        assert_eq!(token.received, U256::from(100));
+        assert_eq!(token.sent, U256::from(30));
+
+        // Token 1 was sent on later; token 2 is still held
+        let nft = &holdings[1];
+        assert!(nft.nft);
+        assert_eq!(nft.token_ids, vec![U256::from(2)]);
+    }
+}
@@ -0,0 +1,408 @@
+//! Cache of RPC responses that can no longer change: blocks, block receipts,
+//! transactions and receipts at least `FINALITY_DEPTH` blocks below the
+//! highest block seen on their endpoint.
+//!
+//! Clients come and go with profile switches, so the cache is process-wide.
+//! Responses are kept in memory, least recently used out first once over the
+//! size cap, and optionally on disk as one JSON file each, so revisiting a
+//! block or transaction (even in a later session) doesn't touch the RPC.
+//! Nodes on this machine are often forks that report the chain id they forked
+//! (`anvil --fork-url` is still chain 1), so their responses are kept apart
+//! in memory and never written to disk.
+//!
+//! ```text
+//! ~/.cache/tbex/responses/1/block-full-19000000.json
+//!                         1/receipts-19000000.json
+//!                         8453/tx-0x5c50….json
+//! ```
+
+use super::transport::RpcTransport;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+static GLOBAL: OnceLock<ResponseCache> = OnceLock::new();
+
+/// Blocks a response must be below the head before it's cached: two epochs
+/// on Ethereum, and far past how deep rollups reorg
+pub const FINALITY_DEPTH: u64 = 64;
+
+/// Anvil, Hardhat and Ganache reuse block numbers whenever they restart
+const DEV_CHAIN_IDS: [u64; 2] = [31337, 1337];
+
+#[derive(Debug)]
+pub struct ResponseCache {
+    memory: Mutex<MemoryStore>,
+    disk: Option<DiskStore>,
+    chain_ids: Mutex<HashMap<RpcTransport, u64>>,
+    /// Highest block seen per endpoint
+    heads: Mutex<HashMap<RpcTransport, u64>>,
+}
+
+impl ResponseCache {
+    /// An in-memory cache holding up to `capacity` bytes of responses
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            memory: Mutex::new(MemoryStore {
+                capacity,
+                ..Default::default()
+            }),
+            disk: None,
+            chain_ids: Mutex::default(),
+            heads: Mutex::default(),
+        }
+    }
+
+    /// Also keep responses in `dir`, up to `capacity` bytes
+    pub fn with_disk(mut self, dir: impl Into<PathBuf>, capacity: u64) -> Self {
+        self.disk = Some(DiskStore {
+            dir: dir.into(),
+            capacity,
+            size: Mutex::default(),
+        });
+        self
+    }
+
+    /// Make `cache` the one every `RpcClient` uses. Only the first call has
+    /// any effect.
+    pub fn install(cache: ResponseCache) {
+        let _ = GLOBAL.set(cache);
+    }
+
+    pub fn global() -> Option<&'static ResponseCache> {
+        GLOBAL.get()
+    }
+
+    /// Chain id an endpoint reported earlier in this session
+    pub(super) fn chain_id(&self, transport: &RpcTransport) -> Option<u64> {
+        self.chain_ids.lock().unwrap().get(transport).copied()
+    }
+
+    pub(super) fn set_chain_id(&self, transport: RpcTransport, chain_id: u64) {
+        self.chain_ids.lock().unwrap().insert(transport, chain_id);
+    }
+
+    /// Record that `block` exists, moving the endpoint's head forward
+    pub(super) fn note_block(&self, endpoint: &RpcTransport, block: u64) {
+        let mut heads = self.heads.lock().unwrap();
+        let head = heads.entry(endpoint.clone()).or_default();
+        *head = (*head).max(block);
+    }
+
+    /// Whether data in `block` is deep enough below the endpoint's head to cache
+    pub(super) fn is_final(&self, endpoint: &RpcTransport, chain_id: u64, block: u64) -> bool {
+        !DEV_CHAIN_IDS.contains(&chain_id)
+            && self
+                .heads
+                .lock()
+                .unwrap()
+                .get(endpoint)
+                .is_some_and(|head| block + FINALITY_DEPTH <= *head)
+    }
+
+    /// The cached JSON response for `key` (e.g. `tx-0x…`), from memory or disk
+    pub(super) fn get(
+        &self,
+        endpoint: &RpcTransport,
+        chain_id: u64,
+        key: &str,
+    ) -> Option<Arc<str>> {
+        let key = entry_key(endpoint, chain_id, key);
+        if let Some(json) = self.memory.lock().unwrap().get(&key) {
+            return Some(json);
+        }
+        if endpoint.is_local() {
+            return None;
+        }
+        let json: Arc<str> = self.disk.as_ref()?.get(&key)?.into();
+        self.memory.lock().unwrap().insert(key, json.clone());
+        Some(json)
+    }
+
+    /// Store a response. Disk errors are ignored; the cache is best effort.
+    pub(super) fn insert(&self, endpoint: &RpcTransport, chain_id: u64, key: &str, json: String) {
+        let key = entry_key(endpoint, chain_id, key);
+        if let Some(disk) = self.disk.as_ref().filter(|_| !endpoint.is_local()) {
+            let _ = disk.insert(&key, &json);
+        }
+        self.memory.lock().unwrap().insert(key, json.into());
+    }
+}
+
+/// Where a response is kept: `<chain id>/<key>`, or under the endpoint for
+/// local nodes
+fn entry_key(endpoint: &RpcTransport, chain_id: u64, key: &str) -> String {
+    if endpoint.is_local() {
+        format!("{endpoint:?}/{chain_id}/{key}")
+    } else {
+        format!("{chain_id}/{key}")
+    }
+}
+
+/// Responses in memory with the tick they were last used at
+#[derive(Debug, Default)]
+struct MemoryStore {
+    entries: HashMap<String, (Arc<str>, u64)>,
+    size: usize,
+    capacity: usize,
+    clock: u64,
+}
+
+impl MemoryStore {
+    fn get(&mut self, key: &str) -> Option<Arc<str>> {
+        self.clock += 1;
+        let (json, last_used) = self.entries.get_mut(key)?;
+        *last_used = self.clock;
+        Some(json.clone())
+    }
+
+    fn insert(&mut self, key: String, json: Arc<str>) {
+        if json.len() > self.capacity {
+            return;
+        }
+        self.clock += 1;
+        self.size += json.len();
+        if let Some((old, _)) = self.entries.insert(key, (json, self.clock)) {
+            self.size -= old.len();
+        }
+
+        while self.size > self.capacity {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            if let Some((json, _)) = self.entries.remove(&oldest) {
+                self.size -= json.len();
+            }
+        }
+    }
+}
+
+/// Responses as `<dir>/<chain id>/<key>.json`, aged by modification time
+#[derive(Debug)]
+struct DiskStore {
+    dir: PathBuf,
+    capacity: u64,
+    /// Bytes on disk, counted on the first write
+    size: Mutex<Option<u64>>,
+}
+
+impl DiskStore {
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        let path = self.path(key);
+        let json = fs::read_to_string(&path).ok()?;
+        // Reads count as use, so often visited responses outlive the rest
+        let _ = fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+        Some(json)
+    }
+
+    fn insert(&self, key: &str, json: &str) -> std::io::Result<()> {
+        let len = json.len() as u64;
+        if len > self.capacity {
+            return Ok(());
+        }
+        let path = self.path(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut size = self.size.lock().unwrap();
+        let current = match *size {
+            Some(size) => size,
+            None => cache_files(&self.dir).iter().map(|(_, len, _)| len).sum(),
+        };
+        // Rewriting a response replaces the file rather than adding to the store
+        let replaced = fs::metadata(&path).map_or(0, |metadata| metadata.len());
+        // Write and rename, so a crash never leaves a truncated response
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &path)?;
+
+        let total = current.saturating_sub(replaced) + len;
+        *size = Some(total);
+        if total > self.capacity {
+            *size = Some(self.evict());
+        }
+        Ok(())
+    }
+
+    /// Remove the least recently used files until the store is under 90% of
+    /// its cap (so the next writes don't each rescan it). Returns the
+    /// remaining size.
+    fn evict(&self) -> u64 {
+        let mut files = cache_files(&self.dir);
+        files.sort_by_key(|(_, _, modified)| *modified);
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        for (path, len, _) in files {
+            if total <= self.capacity / 10 * 9 {
+                break;
+            }
+            if fs::remove_file(&path).is_ok() {
+                total -= len;
+            }
+        }
+        total
+    }
+}
+
+/// Response files under `dir`: path, size and modification time
+fn cache_files(dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(chains) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    chains
+        .flatten()
+        .filter_map(|chain| fs::read_dir(chain.path()).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some((entry.path(), metadata.len(), metadata.modified().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tbex-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn endpoint(url: &str) -> RpcTransport {
+        RpcTransport::parse(url).unwrap()
+    }
+
+    #[test]
+    fn test_finality() {
+        let cache = ResponseCache::new(1024);
+        let mainnet = endpoint("https://eth.example");
+        // Nothing is final before a head is known
+        assert!(!cache.is_final(&mainnet, 1, 100));
+
+        cache.note_block(&mainnet, 1_000);
+        cache.note_block(&mainnet, 900); // Heads never move back
+        assert!(cache.is_final(&mainnet, 1, 1_000 - FINALITY_DEPTH));
+        assert!(!cache.is_final(&mainnet, 1, 1_000 - FINALITY_DEPTH + 1));
+        // Heads are per endpoint: a fork of chain 1 has its own
+        let fork = endpoint("http://localhost:8545");
+        assert!(!cache.is_final(&fork, 1, 100));
+
+        let anvil = endpoint("http://127.0.0.1:8545");
+        cache.note_block(&anvil, 1_000);
+        assert!(!cache.is_final(&anvil, 31337, 1));
+    }
+
+    #[test]
+    fn test_memory_evicts_least_recently_used() {
+        let mainnet = endpoint("https://eth.example");
+        let cache = ResponseCache::new(10);
+        cache.insert(&mainnet, 1, "a", "aaaa".to_string());
+        cache.insert(&mainnet, 1, "b", "bbbb".to_string());
+        assert!(cache.get(&mainnet, 1, "a").is_some());
+
+        // "b" was used least recently
+        cache.insert(&mainnet, 1, "c", "cccc".to_string());
+        assert_eq!(cache.get(&mainnet, 1, "a").as_deref(), Some("aaaa"));
+        assert!(cache.get(&mainnet, 1, "b").is_none());
+        assert_eq!(cache.get(&mainnet, 1, "c").as_deref(), Some("cccc"));
+        // Keys are per chain
+        assert!(cache.get(&mainnet, 2, "a").is_none());
+
+        // Responses bigger than the whole cache aren't kept
+        cache.insert(&mainnet, 1, "d", "d".repeat(11));
+        assert!(cache.get(&mainnet, 1, "d").is_none());
+        assert!(cache.get(&mainnet, 1, "a").is_some());
+    }
+
+    #[test]
+    fn test_disk_store() {
+        let mainnet = endpoint("https://eth.example");
+        let dir = temp_dir("disk");
+        let cache = ResponseCache::new(1024).with_disk(&dir, 1024);
+        cache.insert(&mainnet, 1, "tx-0xaa", r#"{"hash":"0xaa"}"#.to_string());
+        assert!(dir.join("1/tx-0xaa.json").exists());
+
+        // A later session reads it back from disk
+        let cache = ResponseCache::new(1024).with_disk(&dir, 1024);
+        assert_eq!(
+            cache.get(&mainnet, 1, "tx-0xaa").as_deref(),
+            Some(r#"{"hash":"0xaa"}"#)
+        );
+        assert!(cache.get(&mainnet, 1, "tx-0xbb").is_none());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_disk_cap() {
+        let mainnet = endpoint("https://eth.example");
+        let dir = temp_dir("cap");
+        let cache = ResponseCache::new(1024).with_disk(&dir, 100);
+        for i in 0..5 {
+            cache.insert(&mainnet, 1, &format!("block-{i}"), "x".repeat(30));
+        }
+
+        // Past the cap, files are removed down to 90% of it
+        let files = cache_files(&dir);
+        assert_eq!(files.len(), 3);
+        assert_eq!(*cache.disk.as_ref().unwrap().size.lock().unwrap(), Some(90));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_disk_rewrite_keeps_size() {
+        let mainnet = endpoint("https://eth.example");
+        let dir = temp_dir("rewrite");
+        let cache = ResponseCache::new(1024).with_disk(&dir, 100);
+        for _ in 0..5 {
+            cache.insert(&mainnet, 1, "block-1", "x".repeat(30));
+        }
+
+        // The same response written again takes its place, not more room
+        let disk = cache.disk.as_ref().unwrap();
+        assert_eq!(*disk.size.lock().unwrap(), Some(30));
+        assert_eq!(cache_files(&dir).len(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_local_endpoint_stays_in_memory() {
+        let dir = temp_dir("local");
+        let cache = ResponseCache::new(1024).with_disk(&dir, 1024);
+        let mainnet = endpoint("https://eth.example");
+        let fork = endpoint("http://localhost:8545");
+        cache.insert(&fork, 1, "block-1", "fork".to_string());
+        assert!(cache_files(&dir).is_empty());
+        assert_eq!(cache.get(&fork, 1, "block-1").as_deref(), Some("fork"));
+
+        // A fork of chain 1 doesn't share entries with chain 1 itself
+        assert!(cache.get(&mainnet, 1, "block-1").is_none());
+        cache.insert(&mainnet, 1, "block-1", "mainnet".to_string());
+        assert_eq!(cache.get(&fork, 1, "block-1").as_deref(), Some("fork"));
+        assert_eq!(
+            cache.get(&mainnet, 1, "block-1").as_deref(),
+            Some("mainnet")
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
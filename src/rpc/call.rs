@@ -0,0 +1,324 @@
+//! Read-only contract calls typed into the address page's call console.
+//!
+//! A call is a function signature followed by its arguments. Return types
+//! are optional, in either cast or Solidity style; without them the output
+//! is decoded with a loaded ABI if one matches, or shown as raw words.
+//!
+//! ```text
+//! totalSupply()(uint256)
+//! balanceOf(address) returns (uint256) 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045
+//! getReserves()(uint112,uint112,uint32)
+//! allowance(address,address)(uint256) 0xabc…, 0xdef…
+//! ```
+
+use super::abi::decode_params;
+use super::types::DecodedParam;
+use alloy::dyn_abi::{DynSolType, DynSolValue, FunctionExt, JsonAbiExt, Specifier};
+use alloy::json_abi::Function;
+use alloy::primitives::{Bytes, Selector};
+use anyhow::{anyhow, bail, Context, Result};
+
+/// Modifiers allowed between a function's inputs and its return types
+const MODIFIERS: &[&str] = &[
+    "external",
+    "public",
+    "view",
+    "pure",
+    "payable",
+    "nonpayable",
+];
+
+/// A parsed view call: the function and its coerced arguments
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractCall {
+    pub(super) function: Function,
+    args: Vec<DynSolValue>,
+}
+
+impl ContractCall {
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        let input = input
+            .strip_prefix("function ")
+            .unwrap_or(input)
+            .trim_start();
+        if input.is_empty() {
+            bail!("Enter a function signature, e.g. balanceOf(address)(uint256) 0x…");
+        }
+
+        let (signature, args) = split_signature(input)?;
+        let function = Function::parse(&signature)
+            .map_err(|e| anyhow!("Invalid signature {signature}: {e}"))?;
+
+        let args = split_args(args)?;
+        if args.len() != function.inputs.len() {
+            bail!(
+                "{} takes {} argument(s), got {}",
+                function.signature(),
+                function.inputs.len(),
+                args.len()
+            );
+        }
+        let args = function
+            .inputs
+            .iter()
+            .zip(args)
+            .map(|(param, arg)| {
+                let ty: DynSolType = param
+                    .resolve()
+                    .with_context(|| format!("Unsupported type {}", param.ty))?;
+                ty.coerce_str(arg)
+                    .with_context(|| format!("Invalid {} argument: {arg}", param.ty))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { function, args })
+    }
+
+    /// `name(inputs)(outputs)`, or `name(inputs)` if no return types are known
+    pub fn signature(&self) -> String {
+        if self.function.outputs.is_empty() {
+            self.function.signature()
+        } else {
+            self.function.signature_with_outputs()
+        }
+    }
+
+    pub fn selector(&self) -> Selector {
+        self.function.selector()
+    }
+
+    pub fn calldata(&self) -> Result<Bytes> {
+        let input = self
+            .function
+            .abi_encode_input(&self.args)
+            .context("Failed to encode arguments")?;
+        Ok(input.into())
+    }
+
+    /// Decode return data with the call's return types, or as raw 32-byte
+    /// words if it has none
+    pub fn decode_output(&self, data: &[u8]) -> Result<Vec<DecodedParam>> {
+        if self.function.outputs.is_empty() {
+            return Ok(data
+                .chunks(32)
+                .enumerate()
+                .map(|(i, word)| DecodedParam {
+                    name: format!("word{i}"),
+                    value: format!("0x{}", alloy::hex::encode(word)),
+                    ..Default::default()
+                })
+                .collect());
+        }
+        let values = self
+            .function
+            .abi_decode_output(data)
+            .with_context(|| format!("Failed to decode return data as {}", self.signature()))?;
+        Ok(decode_params(&self.function.outputs, &values))
+    }
+}
+
+/// Split `name(inputs) [modifiers] [returns] (outputs) args` into a signature
+/// `Function::parse` accepts and the argument text
+fn split_signature(input: &str) -> Result<(String, &str)> {
+    let open = input.find('(').context("Missing ( in function signature")?;
+    let inputs_end = matching_paren(input, open)?;
+    let mut signature = input[..=inputs_end].to_string();
+    let mut rest = input[inputs_end + 1..].trim_start();
+
+    while let Some(modifier) = MODIFIERS.iter().find(|m| {
+        rest.strip_prefix(**m)
+            .is_some_and(|r| r.starts_with([' ', '(']))
+    }) {
+        rest = rest[modifier.len()..].trim_start();
+    }
+    let returns = rest.strip_prefix("returns");
+    if let Some(after) = returns {
+        rest = after.trim_start();
+        if !rest.starts_with('(') {
+            bail!("Missing return types after returns");
+        }
+    }
+    if rest.starts_with('(') {
+        let outputs_end = matching_paren(rest, 0)?;
+        let outputs = &rest[..=outputs_end];
+        // Without `returns`, `f((bool,uint8)) (true, 7)` is a tuple argument
+        if returns.is_some() || DynSolType::parse(outputs).is_ok() {
+            signature.push_str(" returns ");
+            signature.push_str(outputs);
+            rest = &rest[outputs_end + 1..];
+        }
+    }
+    Ok((signature, rest.trim()))
+}
+
+/// Index of the `)` closing the `(` at `open`
+fn matching_paren(s: &str, open: usize) -> Result<usize> {
+    let mut depth = 0;
+    for (i, c) in s[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    bail!("Unbalanced parentheses in {s}")
+}
+
+/// Split arguments on commas or whitespace, keeping quoted strings, arrays
+/// and tuples together
+fn split_args(input: &str) -> Result<Vec<&str>> {
+    let mut args = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut start: Option<usize> = None;
+
+    for (i, c) in input.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                start.get_or_insert(i);
+            }
+            (None, '[' | '(') => {
+                depth += 1;
+                start.get_or_insert(i);
+            }
+            (None, ']' | ')') => depth -= 1,
+            (None, c) if depth == 0 && (c == ',' || c.is_whitespace()) => {
+                if let Some(s) = start.take() {
+                    args.push(&input[s..i]);
+                }
+            }
+            _ => {
+                start.get_or_insert(i);
+            }
+        }
+    }
+    if quote.is_some() {
+        bail!("Unterminated string argument");
+    }
+    if depth != 0 {
+        bail!("Unbalanced brackets in arguments");
+    }
+    if let Some(s) = start {
+        args.push(&input[s..]);
+    }
+    Ok(args)
+}
+
+/// A finished call shown in the console
+#[derive(Debug, Clone)]
+pub struct CallResult {
+    pub call: String,
+    pub block: String,
+    pub outputs: Vec<DecodedParam>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, U256};
+    use alloy::sol_types::SolValue;
+
+    #[test]
+    fn test_parse_signature_forms() {
+        let holder = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        for input in [
+            format!("balanceOf(address)(uint256) {holder}"),
+            format!("balanceOf(address) returns (uint256) {holder}"),
+            format!("function balanceOf(address owner) external view returns (uint256) {holder}"),
+        ] {
+            let call = ContractCall::parse(&input).unwrap();
+            assert_eq!(call.signature(), "balanceOf(address)(uint256)");
+            assert_eq!(call.selector(), Selector::from([0x70, 0xa0, 0x82, 0x31]));
+        }
+
+        let call = ContractCall::parse("totalSupply()").unwrap();
+        assert_eq!(call.signature(), "totalSupply()");
+        assert_eq!(call.calldata().unwrap().as_ref(), &[0x18, 0x16, 0x0d, 0xdd]);
+    }
+
+    #[test]
+    fn test_parse_arguments() {
+        let call = ContractCall::parse(
+            r#"f(address,uint256[],string,(bool,uint8)) 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, [1, 2], "a b", (true, 7)"#,
+        )
+        .unwrap();
+        assert_eq!(
+            call.args,
+            vec![
+                DynSolValue::Address(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045")),
+                DynSolValue::Array(vec![
+                    DynSolValue::Uint(U256::from(1), 256),
+                    DynSolValue::Uint(U256::from(2), 256)
+                ]),
+                DynSolValue::String("a b".to_string()),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Bool(true),
+                    DynSolValue::Uint(U256::from(7), 8)
+                ]),
+            ]
+        );
+
+        // A lone tuple argument isn't mistaken for return types
+        let call = ContractCall::parse("f((bool,uint8)) (true, 7)").unwrap();
+        assert_eq!(call.signature(), "f((bool,uint8))");
+        assert_eq!(
+            call.args,
+            vec![DynSolValue::Tuple(vec![
+                DynSolValue::Bool(true),
+                DynSolValue::Uint(U256::from(7), 8)
+            ])]
+        );
+        let call = ContractCall::parse("f((bool,uint8))(bool) (true, 7)").unwrap();
+        assert_eq!(call.signature(), "f((bool,uint8))(bool)");
+
+        // Units are accepted for uints
+        let call = ContractCall::parse("deposit(uint256) 1.5ether").unwrap();
+        assert_eq!(
+            call.args,
+            vec![DynSolValue::Uint(
+                U256::from(1_500_000_000_000_000_000u128),
+                256
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(ContractCall::parse("").is_err());
+        assert!(ContractCall::parse("balanceOf").is_err());
+        assert!(ContractCall::parse("balanceOf(address").is_err());
+        // Wrong argument count and type
+        assert!(ContractCall::parse("balanceOf(address)").is_err());
+        assert!(ContractCall::parse("balanceOf(address) 12").is_err());
+        assert!(ContractCall::parse(r#"name(string) "abc"#).is_err());
+    }
+
+    #[test]
+    fn test_decode_output() {
+        let call = ContractCall::parse("getReserves()(uint112,uint112,uint32)").unwrap();
+        let data = (U256::from(10), U256::from(20), U256::from(30)).abi_encode();
+        let outputs = call.decode_output(&data).unwrap();
+        let values: Vec<&str> = outputs.iter().map(|p| p.value.as_str()).collect();
+        assert_eq!(values, ["10", "20", "30"]);
+        assert_eq!(outputs[0].ty.as_deref(), Some("uint112"));
+
+        // Without return types, the raw words are shown
+        let call = ContractCall::parse("getReserves()").unwrap();
+        let outputs = call.decode_output(&data).unwrap();
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs[2].name, "word2");
+        assert!(outputs[2].value.ends_with("1e"));
+
+        let call = ContractCall::parse("name()(string)").unwrap();
+        assert!(call.decode_output(&[0u8; 4]).is_err());
+    }
+}
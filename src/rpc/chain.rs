@@ -0,0 +1,280 @@
+//! Per-chain registry: ENS deployments, tokens worth checking, native
+//! currency and block time, keyed by `eth_chainId`.
+//!
+//! Chains missing from the registry still work; they just get no ENS, no
+//! built-in token list and a conservative refresh cadence.
+
+use super::helper::POPULAR_TOKENS;
+use alloy::primitives::{address, Address};
+use std::time::Duration;
+
+/// Token entries as (symbol, name, address, decimals)
+pub type TokenList = &'static [(&'static str, &'static str, &'static str, u8)];
+
+/// ENS contracts on a chain that has an ENS deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnsContracts {
+    /// Registry for forward resolution (name -> address)
+    pub registry: Address,
+    /// ReverseRecords helper for batch reverse resolution, where deployed
+    pub reverse_records: Option<Address>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainInfo {
+    pub chain_id: u64,
+    pub name: &'static str,
+    pub native_symbol: &'static str,
+    pub block_time: Duration,
+    pub ens: Option<EnsContracts>,
+    pub tokens: TokenList,
+}
+
+/// Block time assumed for chains that aren't in the registry
+pub const DEFAULT_BLOCK_TIME: Duration = Duration::from_secs(12);
+
+const ENS_REGISTRY: Address = address!("00000000000C2E074eC69A0dFb2997BA6C7d2e1e");
+
+const OPTIMISM_TOKENS: TokenList = &[
+    (
+        "USDC",
+        "USD Coin",
+        "0x0b2C639c533813f4Aa9D7837cAf62653d097Ff85",
+        6,
+    ),
+    (
+        "USDT",
+        "Tether USD",
+        "0x94b008aA00579c1307B0EF2c499aD98a8ce58e58",
+        6,
+    ),
+    (
+        "WETH",
+        "Wrapped Ether",
+        "0x4200000000000000000000000000000000000006",
+        18,
+    ),
+    (
+        "OP",
+        "Optimism",
+        "0x4200000000000000000000000000000000000042",
+        18,
+    ),
+];
+
+const BASE_TOKENS: TokenList = &[
+    (
+        "USDC",
+        "USD Coin",
+        "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913",
+        6,
+    ),
+    (
+        "WETH",
+        "Wrapped Ether",
+        "0x4200000000000000000000000000000000000006",
+        18,
+    ),
+    (
+        "DAI",
+        "Dai Stablecoin",
+        "0x50c5725949A6F0c72E6C4a641F24049A917DB0Cb",
+        18,
+    ),
+];
+
+const ARBITRUM_TOKENS: TokenList = &[
+    (
+        "USDC",
+        "USD Coin",
+        "0xaf88d065e77c8cC2239327C5EDb3A432268e5831",
+        6,
+    ),
+    (
+        "USDT",
+        "Tether USD",
+        "0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9",
+        6,
+    ),
+    (
+        "WETH",
+        "Wrapped Ether",
+        "0x82aF49447D8a07e3bd95BD0d56f35241523fBab1",
+        18,
+    ),
+    (
+        "ARB",
+        "Arbitrum",
+        "0x912CE59144191C1204E64559FE8253a0e49E6548",
+        18,
+    ),
+];
+
+const POLYGON_TOKENS: TokenList = &[
+    (
+        "USDC",
+        "USD Coin",
+        "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359",
+        6,
+    ),
+    (
+        "USDT",
+        "Tether USD",
+        "0xc2132D05D31c914a87C6611C10748AEb04B58e8F",
+        6,
+    ),
+    (
+        "WETH",
+        "Wrapped Ether",
+        "0x7ceB23fD6bC0adD59E62ac25578270cFf1b9f619",
+        18,
+    ),
+    (
+        "WPOL",
+        "Wrapped POL",
+        "0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270",
+        18,
+    ),
+];
+
+static CHAINS: &[ChainInfo] = &[
+    ChainInfo {
+        chain_id: 1,
+        name: "Ethereum",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(12),
+        ens: Some(EnsContracts {
+            registry: ENS_REGISTRY,
+            reverse_records: Some(address!("3671aE578E63FdF66ad4F3E12CC0c0d71Ac7510C")),
+        }),
+        tokens: POPULAR_TOKENS,
+    },
+    ChainInfo {
+        chain_id: 11155111,
+        name: "Sepolia",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(12),
+        ens: Some(EnsContracts {
+            registry: ENS_REGISTRY,
+            reverse_records: None,
+        }),
+        tokens: &[],
+    },
+    ChainInfo {
+        chain_id: 17000,
+        name: "Holesky",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(12),
+        ens: Some(EnsContracts {
+            registry: ENS_REGISTRY,
+            reverse_records: None,
+        }),
+        tokens: &[],
+    },
+    ChainInfo {
+        chain_id: 10,
+        name: "OP Mainnet",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(2),
+        ens: None,
+        tokens: OPTIMISM_TOKENS,
+    },
+    ChainInfo {
+        chain_id: 8453,
+        name: "Base",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(2),
+        ens: None,
+        tokens: BASE_TOKENS,
+    },
+    ChainInfo {
+        chain_id: 84532,
+        name: "Base Sepolia",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(2),
+        ens: None,
+        tokens: &[],
+    },
+    ChainInfo {
+        chain_id: 42161,
+        name: "Arbitrum One",
+        native_symbol: "ETH",
+        block_time: Duration::from_millis(250),
+        ens: None,
+        tokens: ARBITRUM_TOKENS,
+    },
+    ChainInfo {
+        chain_id: 137,
+        name: "Polygon",
+        native_symbol: "POL",
+        block_time: Duration::from_secs(2),
+        ens: None,
+        tokens: POLYGON_TOKENS,
+    },
+    ChainInfo {
+        chain_id: 100,
+        name: "Gnosis",
+        native_symbol: "xDAI",
+        block_time: Duration::from_secs(5),
+        ens: None,
+        tokens: &[],
+    },
+    ChainInfo {
+        chain_id: 56,
+        name: "BNB Smart Chain",
+        native_symbol: "BNB",
+        block_time: Duration::from_secs(3),
+        ens: None,
+        tokens: &[],
+    },
+    ChainInfo {
+        chain_id: 31337,
+        name: "Anvil",
+        native_symbol: "ETH",
+        block_time: Duration::from_secs(1),
+        ens: None,
+        tokens: &[],
+    },
+];
+
+/// Registry entry for a chain id, if tbex knows the chain
+pub fn chain_info(chain_id: u64) -> Option<&'static ChainInfo> {
+    CHAINS.iter().find(|chain| chain.chain_id == chain_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_info() {
+        let mainnet = chain_info(1).unwrap();
+        assert_eq!(mainnet.name, "Ethereum");
+        assert!(mainnet.ens.unwrap().reverse_records.is_some());
+        assert!(!mainnet.tokens.is_empty());
+
+        let polygon = chain_info(137).unwrap();
+        assert_eq!(polygon.native_symbol, "POL");
+        assert!(polygon.ens.is_none());
+
+        assert!(chain_info(999_999_999).is_none());
+    }
+
+    #[test]
+    fn test_registry_is_consistent() {
+        for (i, chain) in CHAINS.iter().enumerate() {
+            assert!(
+                CHAINS[..i].iter().all(|c| c.chain_id != chain.chain_id),
+                "duplicate chain id {}",
+                chain.chain_id
+            );
+            for (symbol, _, address, _) in chain.tokens {
+                assert!(
+                    address.parse::<Address>().is_ok(),
+                    "bad {symbol} address on {}",
+                    chain.name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,392 @@
+//! Classes of RPC failure. Retries go by what the node answered (HTTP status
+//! and JSON-RPC error code) rather than the wording of its message, and the
+//! error screen explains each class differently.
+//!
+//! `RpcClient`'s fetches return an [`Error`]: the failure with the context of
+//! what was being fetched, and its [`RpcError`] class through `kind()`.
+
+use alloy::rpc::json_rpc::ErrorPayload;
+use alloy::transports::{RpcError as TransportRpcError, TransportError, TransportErrorKind};
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+/// A failed fetch. Displays like the `anyhow::Error` it wraps, and converts
+/// into one with `?`.
+pub struct Error(anyhow::Error);
+
+impl Error {
+    /// The class of failure, or None for errors that didn't come from the
+    /// RPC (an invalid query, say)
+    pub fn kind(&self) -> Option<RpcError> {
+        RpcError::of(&self.0)
+    }
+
+    pub fn into_inner(self) -> anyhow::Error {
+        self.0
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for Error {
+    // The wrapped error's own causes, so `{:#}` reads the same either way
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        AsRef::<dyn std::error::Error>::as_ref(&self.0).source()
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(e: anyhow::Error) -> Self {
+        Self(e)
+    }
+}
+
+impl From<RpcError> for Error {
+    fn from(e: RpcError) -> Self {
+        Self(e.into())
+    }
+}
+
+/// JSON-RPC "method not found" (EIP-1474)
+const METHOD_NOT_FOUND: i64 = -32601;
+/// "Method not supported" (EIP-1474)
+const METHOD_NOT_SUPPORTED: i64 = -32004;
+/// "Resource not found" (EIP-1474)
+const RESOURCE_NOT_FOUND: i64 = -32001;
+/// Geth's code for reverts that carry revert data
+pub(super) const EXECUTION_REVERTED: i64 = 3;
+
+/// Retry-After waits longer than this aren't worth blocking the UI for
+pub const MAX_RETRY_WAIT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum RpcError {
+    /// The endpoint couldn't be reached, or answered with an HTTP error
+    #[error("{message}")]
+    Transport {
+        status: Option<u16>,
+        message: String,
+    },
+    /// HTTP 429, or a JSON-RPC error code providers use for rate limits
+    #[error("Rate limited by the RPC{}", match retry_after {
+        Some(wait) => format!(" (retry after {}s)", wait.as_secs().max(1)),
+        None => String::new(),
+    })]
+    RateLimited { retry_after: Option<Duration> },
+    #[error("{0} not found")]
+    NotFound(String),
+    #[error("Reverted: {0}")]
+    Reverted(String),
+    /// The node doesn't have the method, e.g. debug_traceTransaction on a
+    /// node without the debug API
+    #[error("Method not supported: {0}")]
+    Unsupported(String),
+    #[error("Failed to decode {0}")]
+    Decode(String),
+    /// Any other JSON-RPC error the node returned
+    #[error("RPC error {code}: {message}")]
+    Node { code: i64, message: String },
+}
+
+impl RpcError {
+    /// Failures that may go away by themselves: dropped connections, server
+    /// errors and rate limits
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport { status, .. } => {
+                status.is_none_or(|status| status == 408 || status >= 500)
+            }
+            Self::RateLimited { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// How long the endpoint asked to wait before the next request
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// The class of an error from any layer of tbex: errors raised as an
+    /// `RpcError`, straight from alloy's transport, or an `Error` from a
+    /// fetch, wherever they are in the chain of causes
+    pub fn of(err: &anyhow::Error) -> Option<Self> {
+        err.chain().find_map(|cause| {
+            if let Some(error) = cause.downcast_ref::<Self>() {
+                Some(error.clone())
+            } else if let Some(error) = cause.downcast_ref::<TransportError>() {
+                Some(Self::classify(error))
+            } else {
+                cause.downcast_ref::<Error>().and_then(Error::kind)
+            }
+        })
+    }
+
+    pub fn classify(err: &TransportError) -> Self {
+        match err {
+            TransportRpcError::ErrorResp(payload) => {
+                let message = payload.message.to_string();
+                if payload.is_retry_err() {
+                    Self::RateLimited {
+                        retry_after: backoff_hint(payload),
+                    }
+                } else if payload.code == EXECUTION_REVERTED
+                    || message.starts_with("execution reverted")
+                {
+                    let reason = message
+                        .strip_prefix("execution reverted")
+                        .map(|rest| rest.trim_start_matches(':').trim())
+                        .unwrap_or(&message);
+                    Self::Reverted(match reason {
+                        "" => "no reason given".to_string(),
+                        reason => reason.to_string(),
+                    })
+                } else if payload.code == METHOD_NOT_FOUND || payload.code == METHOD_NOT_SUPPORTED {
+                    Self::Unsupported(message)
+                } else if payload.code == RESOURCE_NOT_FOUND {
+                    Self::NotFound(message)
+                } else {
+                    Self::Node {
+                        code: payload.code,
+                        message,
+                    }
+                }
+            }
+            TransportRpcError::UnsupportedFeature(feature) => {
+                Self::Unsupported(feature.to_string())
+            }
+            TransportRpcError::NullResp => {
+                Self::Decode("the response: the RPC returned null".into())
+            }
+            TransportRpcError::SerError(e) => Self::Decode(format!("the request: {e}")),
+            TransportRpcError::DeserError { err, .. } => {
+                Self::Decode(format!("the response: {err}"))
+            }
+            TransportRpcError::Transport(kind) => Self::from_kind(kind),
+            TransportRpcError::LocalUsageError(e) => Self::Transport {
+                status: None,
+                message: e.to_string(),
+            },
+        }
+    }
+
+    fn from_kind(kind: &TransportErrorKind) -> Self {
+        let status = match kind {
+            TransportErrorKind::HttpError(e) => Some((e.status, None)),
+            TransportErrorKind::Custom(e) => e
+                .downcast_ref::<HttpStatusError>()
+                .map(|e| (e.status, e.retry_after)),
+            _ => None,
+        };
+        match status {
+            Some((429, retry_after)) => Self::RateLimited { retry_after },
+            status => Self::Transport {
+                status: status.map(|(status, _)| status),
+                message: kind.to_string(),
+            },
+        }
+    }
+
+    /// What the error means for the user, shown above it on the error screen
+    pub fn hint(&self) -> String {
+        match self {
+            Self::Transport {
+                status: Some(401 | 403),
+                ..
+            } => "The RPC endpoint refused the request. Check the API key in its URL.".into(),
+            Self::Transport { .. } => {
+                "Couldn't reach the RPC endpoint. Check the URL and your connection.".into()
+            }
+            Self::RateLimited {
+                retry_after: Some(wait),
+            } => format!(
+                "The RPC endpoint is rate limiting requests. Try again in {}s, or use an endpoint with a higher limit.",
+                wait.as_secs().max(1)
+            ),
+            Self::RateLimited { retry_after: None } => {
+                "The RPC endpoint is rate limiting requests. Try again shortly, or use an endpoint with a higher limit.".into()
+            }
+            Self::NotFound(_) => {
+                "Nothing matches on this chain. Check the search, and that the RPC is for the right network.".into()
+            }
+            Self::Reverted(_) => "The call reverted.".into(),
+            Self::Unsupported(_) => {
+                "This RPC endpoint doesn't offer the method. Traces need the debug or trace API, the mempool the txpool API.".into()
+            }
+            Self::Decode(_) => "The RPC endpoint sent a response tbex couldn't read.".into(),
+            Self::Node { .. } => "The node returned an error.".into(),
+        }
+    }
+}
+
+/// A non-2xx HTTP response, with the Retry-After header alloy's HTTP
+/// transport doesn't keep
+#[derive(Debug, Error)]
+#[error("HTTP error {status} with body: {body}")]
+pub(super) struct HttpStatusError {
+    pub(super) status: u16,
+    pub(super) retry_after: Option<Duration>,
+    pub(super) body: String,
+}
+
+/// The wait Infura puts in its rate limit errors
+fn backoff_hint(payload: &ErrorPayload) -> Option<Duration> {
+    let data = payload.try_data_as::<serde_json::Value>()?.ok()?;
+    let seconds = data["rate"]["backoff_seconds"].as_f64()?;
+    Some(Duration::from_secs_f64(seconds.max(0.0)))
+}
+
+/// A Retry-After header value in seconds (the HTTP date form isn't used by
+/// RPC providers)
+pub(super) fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn error_response(code: i64, message: &'static str) -> TransportError {
+        TransportRpcError::ErrorResp(ErrorPayload {
+            code,
+            message: Cow::Borrowed(message),
+            data: None,
+        })
+    }
+
+    fn http_status(status: u16, retry_after: Option<Duration>) -> TransportError {
+        TransportErrorKind::custom(HttpStatusError {
+            status,
+            retry_after,
+            body: String::new(),
+        })
+    }
+
+    #[test]
+    fn test_classify_json_rpc_codes() {
+        // Gas errors mention "limit" but aren't rate limits
+        let gas = RpcError::classify(&error_response(-32000, "gas limit exceeded"));
+        assert_eq!(
+            gas,
+            RpcError::Node {
+                code: -32000,
+                message: "gas limit exceeded".into()
+            }
+        );
+        assert!(!gas.is_retryable());
+
+        let limited = RpcError::classify(&error_response(-32005, "daily request count exceeded"));
+        assert!(matches!(limited, RpcError::RateLimited { .. }));
+        assert!(limited.is_retryable());
+
+        let infura = TransportRpcError::ErrorResp(ErrorPayload {
+            code: -32005,
+            message: Cow::Borrowed("project ID request rate exceeded"),
+            data: Some(
+                serde_json::value::to_raw_value(&serde_json::json!({
+                    "rate": {"backoff_seconds": 2}
+                }))
+                .unwrap(),
+            ),
+        });
+        assert_eq!(
+            RpcError::classify(&infura).retry_after(),
+            Some(Duration::from_secs(2))
+        );
+
+        assert!(matches!(
+            RpcError::classify(&error_response(
+                -32601,
+                "the method txpool_content does not exist"
+            )),
+            RpcError::Unsupported(_)
+        ));
+        assert_eq!(
+            RpcError::classify(&error_response(3, "execution reverted: Paused")).to_string(),
+            "Reverted: Paused"
+        );
+        assert!(matches!(
+            RpcError::classify(&error_response(-32001, "block not found")),
+            RpcError::NotFound(_)
+        ));
+    }
+
+    #[test]
+    fn test_classify_http_status() {
+        let limited = RpcError::classify(&http_status(429, Some(Duration::from_secs(7))));
+        assert_eq!(limited.retry_after(), Some(Duration::from_secs(7)));
+        assert_eq!(
+            limited.to_string(),
+            "Rate limited by the RPC (retry after 7s)"
+        );
+
+        assert!(RpcError::classify(&http_status(503, None)).is_retryable());
+        let unauthorized = RpcError::classify(&http_status(401, None));
+        assert!(!unauthorized.is_retryable());
+        assert!(unauthorized.hint().contains("API key"));
+
+        // Connection failures have no status and are worth retrying
+        let refused = RpcError::classify(&TransportErrorKind::backend_gone());
+        assert!(refused.is_retryable());
+    }
+
+    #[test]
+    fn test_of_finds_the_class_through_context() {
+        let err = anyhow::Error::new(RpcError::NotFound("Block 5".into()))
+            .context("Failed to fetch block #5");
+        assert_eq!(
+            RpcError::of(&err),
+            Some(RpcError::NotFound("Block 5".into()))
+        );
+
+        let err = anyhow::Error::new(http_status(502, None)).context("Failed to fetch block #5");
+        assert!(RpcError::of(&err).is_some_and(|e| e.is_retryable()));
+
+        assert_eq!(RpcError::of(&anyhow::anyhow!("Invalid address")), None);
+    }
+
+    #[test]
+    fn test_fetch_error_kind() {
+        let err = Error::from(
+            anyhow::Error::new(error_response(-32601, "method not found"))
+                .context("Failed to trace 0xaa"),
+        );
+        assert!(matches!(err.kind(), Some(RpcError::Unsupported(_))));
+        assert_eq!(
+            format!("{err:#}"),
+            "Failed to trace 0xaa: server returned an error response: \
+             error code -32601: method not found"
+        );
+
+        // Still classified once passed on as an anyhow error, and reads the same
+        let err = anyhow::Error::from(err).context("Loading trace");
+        assert!(matches!(RpcError::of(&err), Some(RpcError::Unsupported(_))));
+        assert_eq!(
+            format!("{err:#}"),
+            "Loading trace: Failed to trace 0xaa: server returned an error response: \
+             error code -32601: method not found"
+        );
+
+        assert_eq!(Error::from(anyhow::anyhow!("Invalid address")).kind(), None);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after(" 12"), Some(Duration::from_secs(12)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+}
@@ -1,4 +1,8 @@
 use alloy::primitives::{keccak256, Address, Bytes, B256, U256};
+use alloy::sol_types::SolValue;
+use serde::Serializer;
+
+use super::signatures::SignatureDb;
 
 // ============================================================================
 // Helper Functions
@@ -91,8 +95,15 @@ pub fn detect_builder_tag(extra_data: &Bytes, miner: Address) -> Option<String>
     None
 }
 
+/// Decode an event's topic0, falling back to the local signature database
+pub fn decode_event_signature(topic0: &B256) -> Option<String> {
+    known_event_signature(topic0)
+        .map(String::from)
+        .or_else(|| SignatureDb::global()?.event(topic0))
+}
+
 /// Known event signatures (topic0)
-pub fn decode_event_signature(topic0: &B256) -> Option<&'static str> {
+pub(super) fn known_event_signature(topic0: &B256) -> Option<&'static str> {
     let bytes = topic0.as_slice();
     match bytes {
         // ERC-20 Transfer
@@ -191,8 +202,24 @@ pub const POPULAR_TOKENS: &[(&str, &str, &str, u8)] = &[
     ),
 ];
 
+/// Decode the method of some calldata, falling back to the local signature
+/// database. Colliding signatures that don't fit the calldata are skipped; if
+/// several still fit, the best guess is shown with a count of the others.
+pub fn decode_function_selector(input: &[u8]) -> Option<String> {
+    if let Some(known) = known_function_selector(input) {
+        return Some(known.to_string());
+    }
+
+    let candidates = SignatureDb::global()?.resolve_function(input);
+    match candidates.as_slice() {
+        [] => None,
+        [only] => Some(only.clone()),
+        [first, rest @ ..] => Some(format!("{first} (+{} more)", rest.len())),
+    }
+}
+
 /// Known function selectors
-pub fn decode_function_selector(selector: &[u8]) -> Option<&'static str> {
+pub(super) fn known_function_selector(selector: &[u8]) -> Option<&'static str> {
     if selector.len() < 4 {
         return None;
     }
@@ -245,6 +272,78 @@ pub fn decode_function_selector(selector: &[u8]) -> Option<&'static str> {
     }
 }
 
+/// Decode the standard `Error(string)` and `Panic(uint256)` revert payloads
+pub fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    let (selector, data) = (output.get(..4)?, &output[4..]);
+    match selector {
+        [0x08, 0xc3, 0x79, 0xa0] => String::abi_decode(data).ok(),
+        [0x4e, 0x48, 0x7b, 0x71] => {
+            let code = U256::abi_decode(data).ok()?;
+            let meaning = match u64::try_from(code).unwrap_or(u64::MAX) {
+                0x00 => "generic compiler panic",
+                0x01 => "assertion failed",
+                0x11 => "arithmetic overflow or underflow",
+                0x12 => "division or modulo by zero",
+                0x21 => "invalid enum value",
+                0x22 => "invalid storage byte array encoding",
+                0x31 => "pop on empty array",
+                0x32 => "array index out of bounds",
+                0x41 => "out of memory",
+                0x51 => "call to uninitialized function",
+                _ => "unknown panic code",
+            };
+            Some(format!("Panic(0x{code:x}): {meaning}"))
+        }
+        _ => None,
+    }
+}
+
+/// Widely used custom errors (OpenZeppelin 5.x, Solady, Uniswap)
+const KNOWN_ERRORS: &[&str] = &[
+    "ERC20InsufficientBalance(address,uint256,uint256)",
+    "ERC20InsufficientAllowance(address,uint256,uint256)",
+    "ERC20InvalidSender(address)",
+    "ERC20InvalidReceiver(address)",
+    "ERC721NonexistentToken(uint256)",
+    "ERC721InsufficientApproval(address,uint256)",
+    "OwnableUnauthorizedAccount(address)",
+    "AccessControlUnauthorizedAccount(address,bytes32)",
+    "ReentrancyGuardReentrantCall()",
+    "EnforcedPause()",
+    "SafeERC20FailedOperation(address)",
+    "AddressEmptyCode(address)",
+    "FailedInnerCall()",
+    "Unauthorized()",
+    "TransferFailed()",
+    "TransferFromFailed()",
+    "InsufficientBalance()",
+    "V3TooLittleReceived()",
+    "V3TooMuchRequested()",
+    "V2TooLittleReceived()",
+    "TransactionDeadlinePassed()",
+];
+
+/// Signature of a custom error from its selector, falling back to the local
+/// signature database
+pub fn decode_error_selector(data: &[u8]) -> Option<String> {
+    known_error_selector(data.get(..4)?)
+        .map(|sig| sig.to_string())
+        .or_else(|| {
+            SignatureDb::global()?
+                .resolve_function(data)
+                .into_iter()
+                .next()
+        })
+}
+
+/// Known custom errors
+pub(super) fn known_error_selector(selector: &[u8]) -> Option<&'static str> {
+    KNOWN_ERRORS
+        .iter()
+        .find(|sig| &keccak256(sig.as_bytes())[..4] == selector)
+        .copied()
+}
+
 /// Simple hex encoding helper
 pub fn hex_encode(bytes: &[u8]) -> String {
     bytes.iter().map(|b| format!("{b:02x}")).collect()
@@ -276,6 +375,25 @@ pub fn format_u256_decimals(value: U256, decimals: u8) -> String {
     }
 }
 
+/// Serialize a U256 as a decimal string (JSON numbers can't represent 256-bit values)
+pub fn serialize_u256_decimal<S: Serializer>(
+    value: &U256,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Serialize an optional U256 as a decimal string or null
+pub fn serialize_opt_u256_decimal<S: Serializer>(
+    value: &Option<U256>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => serializer.collect_str(v),
+        None => serializer.serialize_none(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,6 +460,44 @@ mod tests {
         assert_eq!(hex_encode(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
     }
 
+    // ==================== decode_revert_reason tests ====================
+
+    #[test]
+    fn test_decode_revert_error_string() {
+        let mut output = vec![0x08, 0xc3, 0x79, 0xa0];
+        output.extend("Insufficient balance".to_string().abi_encode());
+        assert_eq!(
+            decode_revert_reason(&output).as_deref(),
+            Some("Insufficient balance")
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_panic() {
+        let mut output = vec![0x4e, 0x48, 0x7b, 0x71];
+        output.extend(U256::from(0x11).abi_encode());
+        assert_eq!(
+            decode_revert_reason(&output).as_deref(),
+            Some("Panic(0x11): arithmetic overflow or underflow")
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_unknown() {
+        assert_eq!(decode_revert_reason(&[]), None);
+        assert_eq!(decode_revert_reason(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+
+    #[test]
+    fn test_decode_error_selector() {
+        let selector = &keccak256("OwnableUnauthorizedAccount(address)")[..4];
+        assert_eq!(
+            decode_error_selector(selector).as_deref(),
+            Some("OwnableUnauthorizedAccount(address)")
+        );
+        assert_eq!(decode_error_selector(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+
     // ==================== decode_function_selector tests ====================
 
     #[test]
@@ -349,7 +505,7 @@ mod tests {
         // transfer(address,uint256) = 0xa9059cbb
         let selector = [0xa9, 0x05, 0x9c, 0xbb];
         assert_eq!(
-            decode_function_selector(&selector),
+            decode_function_selector(&selector).as_deref(),
             Some("transfer(address,uint256)")
         );
     }
@@ -359,7 +515,7 @@ mod tests {
         // approve(address,uint256) = 0x095ea7b3
         let selector = [0x09, 0x5e, 0xa7, 0xb3];
         assert_eq!(
-            decode_function_selector(&selector),
+            decode_function_selector(&selector).as_deref(),
             Some("approve(address,uint256)")
         );
     }
@@ -382,7 +538,7 @@ mod tests {
     fn test_decode_transfer_event() {
         let sig = keccak256("Transfer(address,address,uint256)");
         assert_eq!(
-            decode_event_signature(&sig),
+            decode_event_signature(&sig).as_deref(),
             Some("Transfer(address,address,uint256)")
         );
     }
@@ -391,7 +547,7 @@ mod tests {
     fn test_decode_approval_event() {
         let sig = keccak256("Approval(address,address,uint256)");
         assert_eq!(
-            decode_event_signature(&sig),
+            decode_event_signature(&sig).as_deref(),
             Some("Approval(address,address,uint256)")
         );
     }
@@ -400,7 +556,7 @@ mod tests {
     fn test_decode_swap_event() {
         let sig = keccak256("Swap(address,uint256,uint256,uint256,uint256,address)");
         assert_eq!(
-            decode_event_signature(&sig),
+            decode_event_signature(&sig).as_deref(),
             Some("Swap(address,uint256,uint256,uint256,uint256,address)")
         );
     }
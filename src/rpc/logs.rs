@@ -0,0 +1,419 @@
+//! Event log queries for the log query screen, and the block chunking shared
+//! with activity scans.
+//!
+//! Nodes cap how many blocks (or results) one eth_getLogs call may cover, and
+//! the caps differ per provider, so ranges are walked in chunks that shrink
+//! when the node rejects a query and grow again while queries succeed.
+//!
+//! ```text
+//! Address  0xA0b8…eB48, 0xdAC1…1ec7          any of these contracts
+//! Event    Transfer(address indexed from, address indexed to, uint256 value)
+//! Topic 1  0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045
+//! Topic 2                                      any
+//! ```
+
+use super::abi::{decode_event, AbiRegistry};
+use super::storage::parse_topic_word;
+use super::types::DecodedLog;
+use alloy::json_abi::Event;
+use alloy::primitives::{Address, TxHash, B256};
+use alloy::rpc::types::{Filter, Log};
+use anyhow::{anyhow, Context, Result};
+
+/// Blocks per eth_getLogs query at the start of a scan
+const INITIAL_CHUNK: u64 = 5_000;
+/// Chunks never grow past this many blocks
+const MAX_CHUNK: u64 = 100_000;
+/// Logs fetched per page of a log query
+pub const LOG_PAGE_SIZE: usize = 500;
+/// Blocks queried when no start block is given
+pub const DEFAULT_LOG_RANGE: u64 = 10_000;
+
+/// Whether an eth_getLogs error means the query covered too many blocks or
+/// matched too many logs, rather than a transport or node failure
+pub(super) fn is_range_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "block range",
+        "range too large",
+        "range is too",
+        "too wide",
+        "max range",
+        "response size",
+        "query returned more than",
+        "too many results",
+        "is limited to a",
+        "query timeout",
+    ]
+    .iter()
+    .any(|needle| message.contains(needle))
+}
+
+/// Block count per eth_getLogs query, halved when the node rejects a range
+/// and doubled after each accepted one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct ChunkSize(u64);
+
+impl ChunkSize {
+    pub(super) fn new() -> Self {
+        Self(INITIAL_CHUNK)
+    }
+
+    pub(super) fn get(self) -> u64 {
+        self.0
+    }
+
+    pub(super) fn grow(&mut self) {
+        self.0 = (self.0 * 2).min(MAX_CHUNK);
+    }
+
+    /// Halve the chunk; false if it is already a single block
+    pub(super) fn shrink(&mut self) -> bool {
+        if self.0 == 1 {
+            return false;
+        }
+        self.0 /= 2;
+        true
+    }
+}
+
+/// A parsed log query: contracts, event and indexed argument filters.
+/// Empty lists match anything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogQuery {
+    pub addresses: Vec<Address>,
+    pub topic0: Option<B256>,
+    /// Alternatives for topics 1-3
+    pub topics: [Vec<B256>; 3],
+    /// The event, when given as a signature, for decoding
+    event: Option<Event>,
+}
+
+impl LogQuery {
+    /// Parse the form fields. Addresses and topic alternatives are separated
+    /// by commas; the event is a signature or a topic hash.
+    pub fn parse(addresses: &str, event: &str, topics: [&str; 3]) -> Result<Self> {
+        let addresses = split_list(addresses)
+            .map(|a| a.parse().map_err(|_| anyhow!("Invalid address: {a}")))
+            .collect::<Result<Vec<Address>>>()?;
+
+        let event = event.trim();
+        let (topic0, event) = if event.is_empty() {
+            (None, None)
+        } else if let Some(hash) = event.strip_prefix("0x").filter(|h| h.len() == 64) {
+            let topic = hash
+                .parse::<B256>()
+                .map_err(|_| anyhow!("Invalid event topic: {event}"))?;
+            (Some(topic), None)
+        } else {
+            let parsed =
+                Event::parse(event).map_err(|e| anyhow!("Invalid event signature {event}: {e}"))?;
+            (Some(parsed.selector()), Some(parsed))
+        };
+
+        let mut parsed_topics: [Vec<B256>; 3] = Default::default();
+        for (i, (field, parsed)) in topics.iter().zip(&mut parsed_topics).enumerate() {
+            *parsed = split_list(field)
+                .map(|value| {
+                    parse_topic_word(value)
+                        .with_context(|| format!("Invalid topic {} value: {value}", i + 1))
+                })
+                .collect::<Result<_>>()?;
+        }
+
+        Ok(Self {
+            addresses,
+            topic0,
+            topics: parsed_topics,
+            event,
+        })
+    }
+
+    /// The filter without a block range
+    pub(super) fn filter(&self) -> Filter {
+        let mut filter = Filter::new().address(self.addresses.clone());
+        if let Some(topic0) = self.topic0 {
+            filter = filter.event_signature(topic0);
+        }
+        let [topic1, topic2, topic3] = &self.topics;
+        if !topic1.is_empty() {
+            filter = filter.topic1(topic1.clone());
+        }
+        if !topic2.is_empty() {
+            filter = filter.topic2(topic2.clone());
+        }
+        if !topic3.is_empty() {
+            filter = filter.topic3(topic3.clone());
+        }
+        filter
+    }
+
+    /// Short description for the results title
+    pub fn label(&self) -> String {
+        let event = match (&self.event, self.topic0) {
+            (Some(event), _) => event.signature(),
+            (None, Some(topic0)) => format!("{topic0:?}"),
+            (None, None) => "Any event".to_string(),
+        };
+        match self.addresses.as_slice() {
+            [] => event,
+            [address] => format!("{event} on {address:?}"),
+            addresses => format!("{event} on {} contracts", addresses.len()),
+        }
+    }
+
+    /// Decode a log with the query's event, a loaded ABI, or the built-in table
+    pub(super) fn decode(&self, log: &Log, abis: &AbiRegistry) -> DecodedLog {
+        let mut decoded = DecodedLog::from_log(log);
+        let topics = log.topics();
+        let data = &log.data().data;
+
+        let with_event = self
+            .event
+            .as_ref()
+            .filter(|event| topics.first() == Some(&event.selector()))
+            .and_then(|event| {
+                let event = infer_indexed(event, topics.len() - 1);
+                Some((event.signature(), decode_event(&event, topics, data)?))
+            });
+        if let Some((signature, params)) =
+            with_event.or_else(|| abis.decode_log(log.address(), topics, data))
+        {
+            decoded.event_name = Some(signature);
+            decoded.decoded_params = params;
+        }
+        decoded
+    }
+}
+
+/// Signatures typed without `indexed` (`Transfer(address,address,uint256)`)
+/// are taken to index their leading parameters, one per topic after topic0
+fn infer_indexed(event: &Event, indexed: usize) -> Event {
+    let mut event = event.clone();
+    if event.inputs.iter().all(|input| !input.indexed) {
+        for input in event.inputs.iter_mut().take(indexed) {
+            input.indexed = true;
+        }
+    }
+    event
+}
+
+fn split_list(input: &str) -> impl Iterator<Item = &str> {
+    input.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// A log found by a query, with where it was emitted
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub block: u64,
+    pub tx_hash: TxHash,
+    pub log_index: u64,
+    pub log: DecodedLog,
+}
+
+/// One page of query results, covering blocks `scanned_from..=to_block`
+#[derive(Debug, Clone)]
+pub struct LogPage {
+    pub to_block: u64,
+    pub scanned_from: u64,
+    /// Newest first
+    pub entries: Vec<LogEntry>,
+}
+
+impl LogPage {
+    /// Cut the page down to about `limit` entries on a block boundary, so the
+    /// next page can start right below `scanned_from` without repeating or
+    /// skipping logs. A single block with more logs than that is kept whole.
+    pub(super) fn truncate(&mut self, limit: usize) {
+        let Some(boundary) = self.entries.get(limit).map(|e| e.block) else {
+            return;
+        };
+        // Entries are newest first, so the boundary block's logs are contiguous
+        let start = self.entries.partition_point(|e| e.block > boundary);
+        let cut = match start {
+            0 => self.entries.partition_point(|e| e.block >= boundary),
+            start => start,
+        };
+        self.entries.truncate(cut);
+        self.scanned_from = self.entries.last().map_or(boundary, |e| e.block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, keccak256, Bytes, LogData, U256};
+
+    const USDC: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+    const HOLDER: Address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+    fn transfer_log(data: u64) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: USDC,
+                data: LogData::new_unchecked(
+                    vec![
+                        keccak256("Transfer(address,address,uint256)"),
+                        HOLDER.into_word(),
+                        Address::ZERO.into_word(),
+                    ],
+                    Bytes::from(B256::from(U256::from(data)).to_vec()),
+                ),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query = LogQuery::parse(
+            &format!("{USDC}, {HOLDER}"),
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            [&HOLDER.to_string(), "", "1, 2"],
+        )
+        .unwrap();
+        assert_eq!(query.addresses, vec![USDC, HOLDER]);
+        assert_eq!(
+            query.topic0,
+            Some(keccak256("Transfer(address,address,uint256)"))
+        );
+        assert_eq!(query.topics[0], vec![HOLDER.into_word()]);
+        assert!(query.topics[1].is_empty());
+        assert_eq!(
+            query.topics[2],
+            vec![B256::from(U256::from(1)), B256::from(U256::from(2))]
+        );
+        assert_eq!(
+            query.label(),
+            "Transfer(address,address,uint256) on 2 contracts"
+        );
+
+        // A topic hash works too; string values are hashed
+        let topic = keccak256("Transfer(address,address,uint256)");
+        let query =
+            LogQuery::parse("", &topic.to_string(), ["\"foo\"", "", " \"foo bar\" "]).unwrap();
+        assert_eq!(query.topic0, Some(topic));
+        assert_eq!(query.topics[0], vec![keccak256("foo")]);
+        assert_eq!(query.topics[2], vec![keccak256("foo bar")]);
+        assert_eq!(query.label(), format!("{topic:?}"));
+
+        assert_eq!(
+            LogQuery::parse("", "", ["", "", ""]).unwrap().label(),
+            "Any event"
+        );
+        assert!(LogQuery::parse("0x123", "", ["", "", ""]).is_err());
+        assert!(LogQuery::parse("", "Transfer(address", ["", "", ""]).is_err());
+        assert!(LogQuery::parse("", "", ["0xzz", "", ""]).is_err());
+    }
+
+    #[test]
+    fn test_decode_with_query_event() {
+        let abis = AbiRegistry::default();
+        let log = transfer_log(42);
+
+        // Without `indexed`, the leading parameters fill the topics
+        let query = LogQuery::parse(
+            "",
+            "Transfer(address src, address dst, uint256 wad)",
+            ["", "", ""],
+        )
+        .unwrap();
+        let decoded = query.decode(&log, &abis);
+        assert_eq!(
+            decoded.event_name.as_deref(),
+            Some("Transfer(address,address,uint256)")
+        );
+        let names: Vec<&str> = decoded
+            .decoded_params
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, ["src", "dst", "wad"]);
+        assert_eq!(decoded.decoded_params[2].value, "42");
+
+        // Other queries fall back to the built-in table
+        let decoded = LogQuery::parse("", "", ["", "", ""])
+            .unwrap()
+            .decode(&log, &abis);
+        assert_eq!(decoded.decoded_params[0].name, "from");
+        assert!(decoded.decoded_params[0].is_address);
+    }
+
+    #[test]
+    fn test_is_range_error() {
+        assert!(is_range_error(
+            "server returned an error response: error code -32005: query returned more than 10000 results"
+        ));
+        assert!(is_range_error(
+            "eth_getLogs block range too large, max 2000"
+        ));
+        assert!(is_range_error("Log response size exceeded."));
+        assert!(is_range_error(
+            "Exceeded max range limit for eth_getLogs: 800"
+        ));
+        assert!(!is_range_error("connection refused"));
+        // Rate limits are retried, not split
+        assert!(!is_range_error("429 Too Many Requests"));
+        assert!(!is_range_error("-32005: daily request count exceeded"));
+    }
+
+    #[test]
+    fn test_chunk_size() {
+        let mut chunk = ChunkSize::new();
+        assert_eq!(chunk.get(), INITIAL_CHUNK);
+        for _ in 0..20 {
+            chunk.grow();
+        }
+        assert_eq!(chunk.get(), MAX_CHUNK);
+
+        while chunk.shrink() {}
+        assert_eq!(chunk.get(), 1);
+        chunk.grow();
+        assert_eq!(chunk.get(), 2);
+    }
+
+    #[test]
+    fn test_truncate_page_on_block_boundary() {
+        let page = |blocks: &[u64]| LogPage {
+            to_block: 200,
+            scanned_from: 100,
+            entries: blocks
+                .iter()
+                .enumerate()
+                .map(|(i, &block)| LogEntry {
+                    block,
+                    tx_hash: TxHash::ZERO,
+                    log_index: i as u64,
+                    log: DecodedLog::from_log(&transfer_log(0)),
+                })
+                .collect(),
+        };
+        let blocks = |page: &LogPage| page.entries.iter().map(|e| e.block).collect::<Vec<_>>();
+
+        // Short pages keep everything
+        let mut short = page(&[150, 120]);
+        short.truncate(3);
+        assert_eq!((blocks(&short), short.scanned_from), (vec![150, 120], 100));
+
+        // A cut between blocks resumes below the last kept one
+        let mut between = page(&[190, 180, 170, 160]);
+        between.truncate(2);
+        assert_eq!(
+            (blocks(&between), between.scanned_from),
+            (vec![190, 180], 180)
+        );
+
+        // A block split by the cut moves whole to the next page
+        let mut split = page(&[190, 180, 180, 180, 170]);
+        split.truncate(2);
+        assert_eq!((blocks(&split), split.scanned_from), (vec![190], 190));
+
+        // ...unless it is the only block on the page
+        let mut single = page(&[180, 180, 180, 170]);
+        single.truncate(2);
+        assert_eq!(
+            (blocks(&single), single.scanned_from),
+            (vec![180, 180, 180], 180)
+        );
+    }
+}
@@ -1,32 +1,75 @@
+mod abi;
+mod activity;
+mod cache;
+mod call;
+mod chain;
+mod error;
 mod helper;
+mod logs;
+mod multicall;
+mod proxy;
+mod shared;
+mod signatures;
+mod storage;
+mod token;
+mod trace;
+mod transport;
+mod txpool;
 mod types;
 
+use activity::*;
 use helper::*;
-
+use logs::*;
+use multicall::*;
+use proxy::*;
+use token::IERC20;
+use transport::{ConcurrencyLimit, HttpTransport};
+
+pub use abi::AbiRegistry;
+pub use activity::{
+    ActivityEvent, ActivityKind, ActivityScan, TokenHolding, DEFAULT_ACTIVITY_RANGE,
+    MAX_ACTIVITY_EVENTS,
+};
+pub use cache::{ResponseCache, FINALITY_DEPTH};
+pub use call::{CallResult, ContractCall};
+pub use chain::{chain_info, ChainInfo, EnsContracts, DEFAULT_BLOCK_TIME};
+pub use error::{Error, RpcError, MAX_RETRY_WAIT};
+pub use logs::{LogEntry, LogPage, LogQuery, DEFAULT_LOG_RANGE, LOG_PAGE_SIZE};
+pub use shared::SharedClient;
+pub use signatures::{ImportStats, SignatureDb, SignatureLookup};
+pub use storage::{
+    block_label, parse_block_id, word_as_address, word_as_bool, SlotExpr, StorageRead,
+};
+pub use token::{
+    TokenFlow, TokenLookup, TokenScan, DEFAULT_TOKEN_RANGE, MAX_TOKEN_TRANSFERS, TOP_ACCOUNTS,
+};
+pub use transport::{RpcTransport, MAX_CONCURRENT_REQUESTS};
+pub use txpool::{Mempool, PoolTx};
 pub use types::*;
 
 use alloy::{
     consensus::Transaction as TxTrait,
     eips::{BlockId, BlockNumberOrTag},
     network::{Ethereum, TransactionResponse},
-    primitives::{address, keccak256, Address, Bytes, TxHash, TxKind, U256},
-    providers::{Provider, ProviderBuilder, RootProvider},
-    rpc::types::TransactionRequest,
+    primitives::{keccak256, Address, Bytes, Selector, TxHash, TxKind, B256, U256, U64},
+    providers::{IpcConnect, Provider, ProviderBuilder, RootProvider, WsConnect},
+    pubsub::Subscription,
+    rpc::client::{BatchRequest, ClientBuilder},
+    rpc::json_rpc::ErrorPayload,
+    rpc::types::{Block, Filter, Header, Log, TransactionReceipt, TransactionRequest},
     sol,
-    sol_types::SolCall,
+    sol_types::{SolCall, SolType, SolValue},
+    transports::utils::guess_local_url,
 };
 use anyhow::{anyhow, Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::OnceCell;
 use tokio::time::sleep;
 
-type HttpProvider = RootProvider<Ethereum>;
-
-// ENS ReverseRecords contract on mainnet (for reverse resolution: address -> name)
-const ENS_REVERSE_RECORDS: Address = address!("3671aE578E63FdF66ad4F3E12CC0c0d71Ac7510C");
-
-// ENS Registry contract on mainnet (for forward resolution: name -> address)
-const ENS_REGISTRY: Address = address!("00000000000C2E074eC69A0dFb2997BA6C7d2e1e");
+type EthProvider = RootProvider<Ethereum>;
 
 sol! {
     #[sol(rpc)]
@@ -51,24 +94,194 @@ sol! {
 
 /// RPC client with retry logic for rate-limited endpoints
 pub struct RpcClient {
-    provider: HttpProvider,
+    provider: EthProvider,
+    transport: RpcTransport,
+    abis: Arc<AbiRegistry>,
+    tokens: Arc<[KnownToken]>,
+    cache: Option<&'static ResponseCache>,
+    chain_id: OnceCell<u64>,
+    /// Set once this client has looked up the head for the cache's finality
+    /// checks
+    head_checked: OnceCell<()>,
     max_retries: u32,
     base_delay: Duration,
 }
 
 impl RpcClient {
+    /// Create a client for an HTTP(S) endpoint.
+    /// WebSocket and IPC endpoints need an async handshake; use `RpcClient::connect`.
     pub fn new(rpc_url: &str) -> Result<Self> {
-        let url = rpc_url.parse().context("Invalid RPC URL")?;
+        let transport = RpcTransport::parse(rpc_url)?;
+        let RpcTransport::Http(url) = &transport else {
+            return Err(anyhow!(
+                "{} endpoints must be opened with RpcClient::connect",
+                transport.name()
+            ));
+        };
+
+        let client = ClientBuilder::default()
+            .layer(ConcurrencyLimit::new(MAX_CONCURRENT_REQUESTS))
+            .transport(HttpTransport::new(url.clone()), guess_local_url(url));
         let provider = ProviderBuilder::new()
             .disable_recommended_fillers()
             .network::<Ethereum>()
-            .connect_http(url);
+            .connect_client(client);
+
+        Ok(Self::with_provider(provider, transport))
+    }
 
-        Ok(Self {
+    /// Connect to an endpoint, picking HTTP, WebSocket or IPC from the URL
+    pub async fn connect(rpc_url: &str) -> Result<Self> {
+        let transport = RpcTransport::parse(rpc_url)?;
+        let builder = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .network::<Ethereum>();
+
+        let provider = match &transport {
+            RpcTransport::Http(_) => return Self::new(rpc_url),
+            RpcTransport::Ws(url) => builder
+                .connect_ws(WsConnect::new(url.clone()))
+                .await
+                .with_context(|| format!("Failed to connect to {url}"))?,
+            RpcTransport::Ipc(path) => builder
+                .connect_ipc(IpcConnect::new(path.clone()))
+                .await
+                .with_context(|| format!("Failed to connect to IPC socket {path:?}"))?,
+        };
+
+        Ok(Self::with_provider(provider, transport))
+    }
+
+    fn with_provider(provider: EthProvider, transport: RpcTransport) -> Self {
+        Self {
             provider,
+            transport,
+            abis: Arc::default(),
+            tokens: Arc::new([]),
+            cache: ResponseCache::global(),
+            chain_id: OnceCell::new(),
+            head_checked: OnceCell::new(),
             max_retries: 5,
             base_delay: Duration::from_millis(500),
+        }
+    }
+
+    /// Decode calldata and logs with user-supplied ABIs
+    pub fn with_abis(mut self, abis: Arc<AbiRegistry>) -> Self {
+        self.abis = abis;
+        self
+    }
+
+    /// Look up balances of these tokens on address pages instead of the built-in list
+    pub fn with_tokens(mut self, tokens: &[KnownToken]) -> Self {
+        self.tokens = tokens.into();
+        self
+    }
+
+    pub fn transport(&self) -> &RpcTransport {
+        &self.transport
+    }
+
+    /// Chain id of the endpoint (eth_chainId is only called once per client,
+    /// or once per endpoint with the response cache installed)
+    pub async fn chain_id(&self) -> Result<u64, Error> {
+        self.chain_id
+            .get_or_try_init(|| async {
+                if let Some(chain_id) = self.cache.and_then(|c| c.chain_id(&self.transport)) {
+                    return Ok(chain_id);
+                }
+                let chain_id = self
+                    .with_retry(|| async {
+                        self.provider
+                            .get_chain_id()
+                            .await
+                            .context("RPC call eth_chainId failed")
+                    })
+                    .await?;
+                if let Some(cache) = self.cache {
+                    cache.set_chain_id(self.transport.clone(), chain_id);
+                }
+                Ok(chain_id)
+            })
+            .await
+            .copied()
+    }
+
+    /// Registry entry of the connected chain; None for chains tbex doesn't know
+    pub async fn chain(&self) -> Option<&'static ChainInfo> {
+        chain_info(self.chain_id().await.ok()?)
+    }
+
+    /// Subscribe to new block headers (WebSocket and IPC only)
+    pub async fn subscribe_new_heads(&self) -> Result<Subscription<Header>, Error> {
+        if !self.transport.supports_subscriptions() {
+            return Err(anyhow!(
+                "{} endpoints don't support subscriptions",
+                self.transport.name()
+            )
+            .into());
+        }
+        self.provider
+            .subscribe_blocks()
+            .await
+            .context("eth_subscribe(newHeads) failed")
+            .map_err(Error::from)
+    }
+
+    /// Subscribe to hashes of new pending transactions (WebSocket and IPC only)
+    pub async fn subscribe_pending_transactions(&self) -> Result<Subscription<B256>, Error> {
+        if !self.transport.supports_subscriptions() {
+            return Err(anyhow!(
+                "{} endpoints don't support subscriptions",
+                self.transport.name()
+            )
+            .into());
+        }
+        self.provider
+            .subscribe_pending_transactions()
+            .await
+            .context("eth_subscribe(newPendingTransactions) failed")
+            .map_err(Error::from)
+    }
+
+    /// Snapshot of the node's transaction pool from `txpool_status` and
+    /// `txpool_content`. Most hosted endpoints don't expose the txpool namespace.
+    pub async fn get_mempool(&self) -> Result<Mempool, Error> {
+        self.with_retry(|| async {
+            let status = self
+                .provider
+                .raw_request::<_, txpool::TxPoolStatus>("txpool_status".into(), ())
+                .await
+                .context("RPC call txpool_status failed")?;
+            let content = self
+                .provider
+                .raw_request::<_, txpool::TxPoolContent>("txpool_content".into(), ())
+                .await
+                .context("RPC call txpool_content failed")?;
+            Ok(Mempool {
+                pending: status.pending.to(),
+                queued: status.queued.to(),
+                txs: content.into_txs(),
+            })
         })
+        .await
+        .map_err(Error::from)
+    }
+
+    /// A transaction announced by `newPendingTransactions`; None once it has
+    /// been mined or dropped
+    pub async fn get_pool_transaction(&self, hash: TxHash) -> Result<Option<PoolTx>, Error> {
+        let tx = self
+            .with_retry(|| async {
+                self.provider
+                    .get_transaction_by_hash(hash)
+                    .await
+                    .with_context(|| format!("RPC call get_transaction_by_hash({hash:?}) failed"))
+            })
+            .await?;
+        Ok(tx
+            .filter(|tx| tx.block_number.is_none())
+            .map(|tx| PoolTx::from_tx(&tx, false)))
     }
 
     async fn with_retry<T, F, Fut>(&self, operation: F) -> Result<T>
@@ -76,65 +289,229 @@ impl RpcClient {
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<T>>,
     {
-        let mut all_errors: Vec<String> = Vec::new();
-
-        for attempt in 0..=self.max_retries {
-            match operation().await {
+        let mut attempt = 0;
+        loop {
+            let e = match operation().await {
                 Ok(result) => return Ok(result),
-                Err(e) => {
-                    let error_full = format!("{e:#}");
-                    let error_lower = error_full.to_lowercase();
-
-                    // Log this attempt's error with full chain
-                    all_errors.push(format!("Attempt {}: {}", attempt + 1, error_full));
-
-                    // Retry on rate limits and transient network errors
-                    let is_retryable = error_lower.contains("rate")
-                        || error_lower.contains("limit")
-                        || error_lower.contains("429")
-                        || error_lower.contains("too many")
-                        || error_lower.contains("timeout")
-                        || error_lower.contains("timed out")
-                        || error_lower.contains("connection")
-                        || error_lower.contains("temporarily")
-                        || error_lower.contains("unavailable")
-                        || error_lower.contains("502")
-                        || error_lower.contains("503")
-                        || error_lower.contains("504");
-
-                    if is_retryable && attempt < self.max_retries {
-                        let delay = self.base_delay * 2_u32.pow(attempt);
-                        sleep(delay).await;
-                    } else {
-                        // Return with full context of all attempts
-                        if all_errors.len() > 1 {
-                            return Err(anyhow!(
-                                "{:#}\n\nAll attempts:\n{}",
-                                e,
-                                all_errors.join("\n")
-                            ));
-                        }
-                        return Err(e);
-                    }
-                }
+                Err(e) => e,
+            };
+
+            // Retry on rate limits and transient network errors, waiting as
+            // long as the endpoint asks or backing off exponentially
+            let error = RpcError::of(&e);
+            let delay = match error.as_ref().and_then(RpcError::retry_after) {
+                Some(wait) => wait,
+                None => self.base_delay * 2_u32.pow(attempt),
+            };
+            let retryable = error.as_ref().is_some_and(RpcError::is_retryable);
+            if !retryable || attempt == self.max_retries || delay > MAX_RETRY_WAIT {
+                return match attempt {
+                    0 => Err(e),
+                    _ => Err(e.context(format!("Gave up after {} attempts", attempt + 1))),
+                };
             }
+
+            sleep(delay).await;
+            attempt += 1;
         }
+    }
 
-        Err(anyhow!(
-            "All {} retries failed:\n{}",
-            self.max_retries + 1,
-            all_errors.join("\n")
-        ))
+    /// Run `fetch` unless the response cache has its response. `block_of`
+    /// gives the block a response belongs to (None for missing blocks and
+    /// pending transactions); responses are stored once that block is final.
+    async fn cached<T, F, Fut>(
+        &self,
+        key: String,
+        block_of: impl Fn(&T) -> Option<u64>,
+        fetch: F,
+    ) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        if let Some(value) = self.cache_get(&key).await {
+            return Ok(value);
+        }
+        let value = fetch().await?;
+        self.cache_put(&key, block_of(&value), &value).await;
+        Ok(value)
+    }
+
+    async fn cache_get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let cache = self.cache?;
+        let chain_id = self.chain_id().await.ok()?;
+        serde_json::from_str(&cache.get(&self.transport, chain_id, key)?).ok()
+    }
+
+    /// Store a response belonging to `block`, if that block is final
+    async fn cache_put<T: Serialize>(&self, key: &str, block: Option<u64>, value: &T) {
+        let (Some(cache), Some(block)) = (self.cache, block) else {
+            return;
+        };
+        let Ok(chain_id) = self.chain_id().await else {
+            return;
+        };
+        cache.note_block(&self.transport, block);
+        if !cache.is_final(&self.transport, chain_id, block) {
+            // CLI commands fetch a single block or transaction and never ask
+            // for the head, so find it before treating anything as recent
+            self.head_checked
+                .get_or_init(|| async {
+                    let _ = self.get_latest_block_number().await;
+                })
+                .await;
+        }
+        if cache.is_final(&self.transport, chain_id, block) {
+            if let Ok(json) = serde_json::to_string(value) {
+                cache.insert(&self.transport, chain_id, key, json);
+            }
+        }
+    }
+
+    /// eth_getBlockByNumber, with full transactions or just their hashes
+    async fn block_by_number(&self, number: u64, full: bool) -> Result<Option<Block>> {
+        let key = match full {
+            true => format!("block-full-{number}"),
+            false => format!("block-{number}"),
+        };
+        self.cached(
+            key,
+            |block: &Option<Block>| block.as_ref().map(|b| b.header.number),
+            || async {
+                let request = self
+                    .provider
+                    .get_block_by_number(BlockNumberOrTag::Number(number));
+                let request = if full { request.full() } else { request };
+                request
+                    .await
+                    .with_context(|| format!("RPC call get_block_by_number({number}) failed"))
+            },
+        )
+        .await
+    }
+
+    async fn block_by_hash(&self, hash: B256) -> Result<Option<Block>> {
+        self.cached(
+            format!("block-{hash:?}"),
+            |block: &Option<Block>| block.as_ref().map(|b| b.header.number),
+            || async {
+                self.provider
+                    .get_block_by_hash(hash)
+                    .await
+                    .with_context(|| format!("RPC call get_block_by_hash({hash}) failed"))
+            },
+        )
+        .await
+    }
+
+    async fn block_receipts(&self, number: u64) -> Result<Option<Vec<TransactionReceipt>>> {
+        self.cached(
+            format!("receipts-{number}"),
+            |receipts: &Option<Vec<_>>| receipts.is_some().then_some(number),
+            || async {
+                self.provider
+                    .get_block_receipts(BlockId::Number(BlockNumberOrTag::Number(number)))
+                    .await
+                    .with_context(|| format!("RPC call get_block_receipts({number}) failed"))
+            },
+        )
+        .await
+    }
+
+    /// The block with full transactions and its receipts, as one batch
+    /// request. Receipts are None where the node lacks eth_getBlockReceipts.
+    /// When the cache has one of the two, only the other is fetched.
+    async fn block_with_receipts(
+        &self,
+        number: u64,
+    ) -> Result<(Option<Block>, Option<Vec<TransactionReceipt>>)> {
+        let block_key = format!("block-full-{number}");
+        let receipts_key = format!("receipts-{number}");
+        match (
+            self.cache_get(&block_key).await,
+            self.cache_get(&receipts_key).await,
+        ) {
+            (Some(block), Some(receipts)) => return Ok((block, receipts)),
+            // Missing receipts aren't cached, so on nodes without
+            // eth_getBlockReceipts this is the usual case for final blocks
+            (Some(block), None) => {
+                let receipts = self.block_receipts(number).await.ok().flatten();
+                return Ok((block, receipts));
+            }
+            (None, Some(receipts)) => {
+                return Ok((self.block_by_number(number, true).await?, receipts));
+            }
+            (None, None) => {}
+        }
+
+        let tag = BlockNumberOrTag::Number(number);
+        let mut batch = BatchRequest::new(self.provider.client());
+        let block = batch.add_call::<_, Option<Block>>("eth_getBlockByNumber", &(tag, true))?;
+        let receipts = batch.add_call::<_, Option<Vec<TransactionReceipt>>>(
+            "eth_getBlockReceipts",
+            &(BlockId::from(tag),),
+        )?;
+        let block = match batch.send().await {
+            Ok(()) => block.await,
+            Err(e) => Err(e),
+        };
+        let Ok(block) = block else {
+            // Some endpoints refuse batches; ask for each on its own
+            let block = self.block_by_number(number, true).await?;
+            let receipts = self.block_receipts(number).await.ok().flatten();
+            return Ok((block, receipts));
+        };
+        let receipts = receipts.await.ok().flatten();
+
+        self.cache_put(&block_key, block.as_ref().map(|b| b.header.number), &block)
+            .await;
+        self.cache_put(
+            &receipts_key,
+            receipts.is_some().then_some(number),
+            &receipts,
+        )
+        .await;
+        Ok((block, receipts))
     }
 
-    pub async fn get_block(&self, number: u64) -> Result<BlockInfo> {
+    async fn transaction_by_hash(
+        &self,
+        hash: TxHash,
+    ) -> Result<Option<alloy::rpc::types::Transaction>> {
+        self.cached(
+            format!("tx-{hash:?}"),
+            |tx: &Option<alloy::rpc::types::Transaction>| tx.as_ref()?.block_number,
+            || async {
+                self.provider
+                    .get_transaction_by_hash(hash)
+                    .await
+                    .with_context(|| format!("RPC call get_transaction_by_hash({hash:?}) failed"))
+            },
+        )
+        .await
+    }
+
+    async fn receipt_by_hash(&self, hash: TxHash) -> Result<Option<TransactionReceipt>> {
+        self.cached(
+            format!("receipt-{hash:?}"),
+            |receipt: &Option<TransactionReceipt>| receipt.as_ref()?.block_number,
+            || async {
+                self.provider
+                    .get_transaction_receipt(hash)
+                    .await
+                    .with_context(|| format!("RPC call get_transaction_receipt({hash:?}) failed"))
+            },
+        )
+        .await
+    }
+
+    pub async fn get_block(&self, number: u64) -> Result<BlockInfo, Error> {
         self.with_retry(|| async {
             let block = self
-                .provider
-                .get_block_by_number(BlockNumberOrTag::Number(number))
-                .await
-                .with_context(|| format!("RPC call get_block_by_number({number}) failed"))?
-                .ok_or_else(|| anyhow!("Block {number} not found (RPC returned null)"))?;
+                .block_by_number(number, false)
+                .await?
+                .ok_or_else(|| RpcError::NotFound(format!("Block {number}")))?;
 
             let mut info = BlockInfo::from_block(&block);
 
@@ -145,16 +522,136 @@ impl RpcClient {
         })
         .await
         .with_context(|| format!("Failed to fetch block #{number}"))
+        .map_err(Error::from)
     }
 
-    pub async fn get_block_tx_hashes(&self, number: u64) -> Result<Vec<String>> {
+    pub async fn get_block_by_hash(&self, hash: B256) -> Result<BlockInfo, Error> {
         self.with_retry(|| async {
             let block = self
-                .provider
-                .get_block_by_number(BlockNumberOrTag::Number(number))
+                .block_by_hash(hash)
+                .await?
+                .ok_or_else(|| RpcError::NotFound(format!("Block {hash}")))?;
+
+            let mut info = BlockInfo::from_block(&block);
+
+            // Resolve ENS for miner
+            info.miner_ens = self.resolve_ens_name(block.header.beneficiary).await;
+
+            Ok(info)
+        })
+        .await
+        .with_context(|| format!("Failed to fetch block {hash}"))
+        .map_err(Error::from)
+    }
+
+    /// Get header-level block info without resolving ENS or fetching transactions
+    pub async fn get_block_header(&self, number: u64) -> Result<BlockInfo, Error> {
+        self.with_retry(|| async {
+            let block = self
+                .block_by_number(number, false)
+                .await?
+                .ok_or_else(|| RpcError::NotFound(format!("Block {number}")))?;
+
+            Ok(BlockInfo::from_block(&block))
+        })
+        .await
+        .with_context(|| format!("Failed to fetch block #{number}"))
+        .map_err(Error::from)
+    }
+
+    /// Number of the last block mined at or before `timestamp` (unix
+    /// seconds), found by binary search over block headers
+    pub async fn find_block_by_timestamp(&self, timestamp: u64) -> Result<u64, Error> {
+        let head = self
+            .get_block_header(self.get_latest_block_number().await?)
+            .await?;
+        if timestamp >= head.timestamp + 60 {
+            return Err(anyhow!(
+                "No block yet at that time: the latest block is #{}",
+                head.number
+            )
+            .into());
+        }
+        if timestamp >= head.timestamp {
+            return Ok(head.number);
+        }
+        if timestamp < self.get_block_header(0).await?.timestamp {
+            return Err(anyhow!("That time is before the genesis block").into());
+        }
+
+        // Block `lo` is at or before the timestamp, block `hi` is after it
+        let (mut lo, mut hi) = (0, head.number);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if self.get_block_header(mid).await?.timestamp <= timestamp {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(lo)
+    }
+
+    /// Get blocks newer than `after` up to the chain head, newest first.
+    /// At most `limit` blocks are returned; older ones are skipped if we fell behind.
+    /// The headers come in one batch request.
+    pub async fn get_new_blocks(
+        &self,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<BlockInfo>, Error> {
+        let head = self.get_latest_block_number().await?;
+        let window_start = head.saturating_sub(limit.max(1) as u64 - 1);
+        let oldest = after.map_or(window_start, |after| (after + 1).max(window_start));
+        let numbers: Vec<u64> = (oldest..=head).rev().collect();
+        if numbers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if let Ok(blocks) = self.block_headers(&numbers).await {
+            return Ok(blocks);
+        }
+        // Some endpoints refuse batches; ask for each on its own
+        let mut blocks = Vec::new();
+        for number in numbers {
+            blocks.push(self.get_block_header(number).await?);
+        }
+        Ok(blocks)
+    }
+
+    /// Header-level info of several blocks, as one batch request
+    async fn block_headers(&self, numbers: &[u64]) -> Result<Vec<BlockInfo>> {
+        let mut batch = BatchRequest::new(self.provider.client());
+        let waiters = numbers
+            .iter()
+            .map(|number| {
+                let tag = BlockNumberOrTag::Number(*number);
+                batch.add_call::<_, Option<Block>>("eth_getBlockByNumber", &(tag, false))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        batch
+            .send()
+            .await
+            .context("RPC batch for block headers failed")?;
+
+        let mut blocks = Vec::with_capacity(numbers.len());
+        for (number, waiter) in numbers.iter().zip(waiters) {
+            let block = waiter
+                .await
+                .with_context(|| format!("RPC call get_block_by_number({number}) failed"))?
+                .ok_or_else(|| RpcError::NotFound(format!("Block {number}")))?;
+            blocks.push(BlockInfo::from_block(&block));
+        }
+        Ok(blocks)
+    }
+
+    pub async fn get_block_tx_hashes(&self, number: u64) -> Result<Vec<String>, Error> {
+        self.with_retry(|| async {
+            let block = self
+                .block_by_number(number, false)
                 .await
                 .context("Failed to fetch block")?
-                .ok_or_else(|| anyhow!("Block {number} not found"))?;
+                .ok_or_else(|| RpcError::NotFound(format!("Block {number}")))?;
 
             let hashes: Vec<String> = block
                 .transactions
@@ -164,26 +661,23 @@ impl RpcClient {
             Ok(hashes)
         })
         .await
+        .map_err(Error::from)
     }
 
-    /// Get block transactions with full details (for block screen)
-    /// Also computes block statistics: total value, fees, blob count
-    pub async fn get_block_transactions(
+    /// Everything the block screen shows: the block, its transactions with
+    /// full details and statistics (total value, fees, blob count). The block
+    /// and its receipts come in one batch request, and every ENS name
+    /// (the miner's too) in one call.
+    pub async fn get_block_page(
         &self,
         number: u64,
-    ) -> Result<(Vec<TxSummary>, BlockStats)> {
+    ) -> Result<(BlockInfo, Vec<TxSummary>, BlockStats), Error> {
         self.with_retry(|| async {
-            // Fetch block with full transactions
-            let block = self
-                .provider
-                .get_block_by_number(BlockNumberOrTag::Number(number))
-                .full()
-                .await
-                .with_context(|| format!("RPC call get_block_by_number({number}).full() failed"))?
-                .ok_or_else(|| anyhow!("Block {number} not found (RPC returned null)"))?;
+            let (block, receipts) = self.block_with_receipts(number).await?;
+            let block = block.ok_or_else(|| RpcError::NotFound(format!("Block {number}")))?;
 
             // Collect all unique addresses for ENS resolution
-            let mut addresses: Vec<Address> = Vec::new();
+            let mut addresses: Vec<Address> = vec![block.header.beneficiary];
             for tx in block.transactions.txns() {
                 addresses.push(tx.from());
                 if let Some(to) = tx.to() {
@@ -197,14 +691,11 @@ impl RpcClient {
             // Batch resolve ENS names
             let ens_names = self.resolve_ens_names(&addresses).await;
 
-            // Try to fetch block receipts for fee calculations
-            let receipts = self
-                .provider
-                .get_block_receipts(BlockId::Number(BlockNumberOrTag::Number(number)))
-                .await
-                .ok()
-                .flatten()
-                .unwrap_or_default();
+            let mut info = BlockInfo::from_block(&block);
+            info.miner_ens = ens_names.get(&block.header.beneficiary).cloned();
+
+            // Receipts are only needed for fees
+            let receipts = receipts.unwrap_or_default();
 
             // Build a map of tx_hash -> receipt
             let receipt_map: HashMap<_, _> =
@@ -261,38 +752,49 @@ impl RpcClient {
                 blob_count,
             };
 
-            Ok((summaries, stats))
+            Ok((info, summaries, stats))
         })
         .await
-        .with_context(|| format!("Failed to fetch transactions for block #{number}"))
+        .with_context(|| format!("Failed to fetch block #{number}"))
+        .map_err(Error::from)
     }
 
-    pub async fn get_latest_block_number(&self) -> Result<u64> {
-        self.with_retry(|| async {
-            self.provider
-                .get_block_number()
-                .await
-                .context("Failed to fetch latest block number")
-        })
-        .await
+    pub async fn get_latest_block_number(&self) -> Result<u64, Error> {
+        let head = self
+            .with_retry(|| async {
+                self.provider
+                    .get_block_number()
+                    .await
+                    .context("Failed to fetch latest block number")
+            })
+            .await?;
+        if let Some(cache) = self.cache {
+            cache.note_block(&self.transport, head);
+        }
+        Ok(head)
     }
 
-    pub async fn get_transaction(&self, hash: TxHash) -> Result<TxInfo> {
+    /// A transaction with its receipt, decoded calldata and logs, and revert
+    /// reason. State changes need a trace and are left to `get_state_diff`.
+    pub async fn get_transaction(&self, hash: TxHash) -> Result<TxInfo, Error> {
         self.with_retry(|| async {
             let tx = self
-                .provider
-                .get_transaction_by_hash(hash)
-                .await
-                .with_context(|| format!("RPC call get_transaction_by_hash({hash:?}) failed"))?
-                .ok_or_else(|| anyhow!("Transaction {hash:?} not found (RPC returned null)"))?;
+                .transaction_by_hash(hash)
+                .await?
+                .ok_or_else(|| RpcError::NotFound(format!("Transaction {hash:?}")))?;
 
-            let receipt = self
-                .provider
-                .get_transaction_receipt(hash)
-                .await
-                .with_context(|| format!("RPC call get_transaction_receipt({hash:?}) failed"))?;
+            let receipt = self.receipt_by_hash(hash).await?;
 
             let mut info = TxInfo::from_tx_and_receipt(&tx, receipt.as_ref());
+            let logs = receipt.as_ref().map_or(&[][..], |r| r.inner.logs());
+            self.abis.decode_transaction(&mut info, tx.to(), logs);
+
+            if info.status == Some(false) {
+                info.revert_reason = self.replay_revert_reason(&tx).await;
+                if info.revert_reason.is_none() && info.gas_used == Some(info.gas_limit) {
+                    info.revert_reason = Some("Out of gas".to_string());
+                }
+            }
 
             // Resolve ENS names for from and to addresses
             let mut addresses_to_resolve = vec![tx.from()];
@@ -310,57 +812,223 @@ impl RpcClient {
         })
         .await
         .with_context(|| format!("Failed to fetch transaction {hash:?}"))
+        .map_err(Error::from)
     }
 
-    pub async fn get_address(&self, address: Address) -> Result<AddressInfo> {
-        self.with_retry(|| async {
-            let balance = self
-                .provider
-                .get_balance(address)
-                .await
-                .with_context(|| format!("RPC call get_balance({address:?}) failed"))?;
+    /// Replay a failed transaction with eth_call on top of its parent block and
+    /// decode the revert. Returns None if the replay doesn't revert (the failure
+    /// depended on earlier transactions in the same block).
+    async fn replay_revert_reason(&self, tx: &alloy::rpc::types::Transaction) -> Option<String> {
+        let parent = tx.block_number()?.checked_sub(1)?;
+        let request = TransactionRequest {
+            from: Some(tx.from()),
+            to: Some(tx.kind()),
+            value: Some(tx.value()),
+            gas: Some(tx.gas_limit()),
+            input: alloy::rpc::types::TransactionInput::new(tx.input().clone()),
+            access_list: TxTrait::access_list(tx).cloned(),
+            ..Default::default()
+        };
 
-            let nonce = self
-                .provider
-                .get_transaction_count(address)
+        let err = self
+            .provider
+            .call(request)
+            .block(BlockId::number(parent))
+            .await
+            .err()?;
+        self.describe_revert_response(tx.to(), err.as_error_resp()?)
+    }
+
+    /// The revert reason in an `eth_call` error response. Errors that aren't
+    /// reverts (missing trie nodes on pruned nodes, rate limits) give None.
+    fn describe_revert_response(
+        &self,
+        contract: Option<Address>,
+        payload: &ErrorPayload,
+    ) -> Option<String> {
+        if let Some(data) = payload.as_revert_data() {
+            return Some(self.describe_revert(contract, &data));
+        }
+        let reverted = payload.code == error::EXECUTION_REVERTED
+            || payload.message.starts_with("execution reverted");
+        reverted.then(|| payload.message.to_string())
+    }
+
+    /// Human-readable description of revert data: `Error(string)`, `Panic(uint256)`,
+    /// or a custom error from the loaded ABIs / signature tables
+    pub fn describe_revert(&self, contract: Option<Address>, data: &[u8]) -> String {
+        if data.is_empty() {
+            return "Reverted without a reason".to_string();
+        }
+        if let Some(reason) = decode_revert_reason(data) {
+            return reason;
+        }
+
+        let decoded = self.abis.decode_error(contract, data).or_else(|| {
+            let signature = decode_error_selector(data)?;
+            let params = abi::decode_with_signature(&signature, &data[4..])?;
+            Some((signature, params))
+        });
+        match decoded {
+            Some((signature, params)) => {
+                let name = signature.split('(').next().unwrap_or(&signature);
+                let args: Vec<String> = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        if p.name == format!("arg{i}") {
+                            p.value.clone()
+                        } else {
+                            format!("{}: {}", p.name, p.value)
+                        }
+                    })
+                    .collect();
+                format!("{name}({})", args.join(", "))
+            }
+            // A known error whose arguments don't match its signature
+            None => match self.error_name(contract, data) {
+                Some(name) => format!("{name}(<undecodable args>)"),
+                None => format!("Custom error 0x{}", hex_encode(&data[..data.len().min(4)])),
+            },
+        }
+    }
+
+    /// Name of the custom error revert data starts with, from the loaded
+    /// ABIs or the signature tables
+    fn error_name(&self, contract: Option<Address>, data: &[u8]) -> Option<String> {
+        let selector = Selector::try_from(data.get(..4)?).ok()?;
+        if let Some(error) = self.abis.error(contract, selector) {
+            return Some(error.name.clone());
+        }
+        let signature = decode_error_selector(data)?;
+        signature.split('(').next().map(str::to_string)
+    }
+
+    /// Fetch the call tree of a transaction with `debug_traceTransaction`'s callTracer,
+    /// falling back to Parity-style `trace_transaction` (Erigon, Nethermind, Reth)
+    pub async fn get_call_trace(&self, hash: TxHash) -> Result<CallTrace, Error> {
+        let geth = self
+            .with_retry(|| async {
+                self.provider
+                    .raw_request::<_, trace::GethCallFrame>(
+                        "debug_traceTransaction".into(),
+                        (hash, serde_json::json!({ "tracer": "callTracer" })),
+                    )
+                    .await
+                    .with_context(|| format!("RPC call debug_traceTransaction({hash:?}) failed"))
+            })
+            .await;
+
+        let mut root = match geth {
+            Ok(frame) => CallTrace::from(frame),
+            Err(geth_err) => self
+                .with_retry(|| async {
+                    let traces = self
+                        .provider
+                        .raw_request::<_, Vec<trace::ParityTrace>>(
+                            "trace_transaction".into(),
+                            (hash,),
+                        )
+                        .await
+                        .with_context(|| format!("RPC call trace_transaction({hash:?}) failed"))?;
+                    trace::parity_call_tree(traces)
+                        .ok_or_else(|| anyhow!("Transaction {hash:?} has no call trace"))
+                })
                 .await
-                .with_context(|| format!("RPC call get_transaction_count({address:?}) failed"))?;
+                .with_context(|| format!("{geth_err:#}"))
+                .context("Node supports neither debug_traceTransaction nor trace_transaction")?,
+        };
+
+        self.decode_trace_methods(&mut root);
+        Ok(root)
+    }
 
-            let code = self
+    /// Accounts whose balance, nonce, code or storage a transaction changed,
+    /// from `debug_traceTransaction`'s prestateTracer in diff mode
+    pub async fn get_state_diff(&self, hash: TxHash) -> Result<Vec<AccountStateDiff>, Error> {
+        self.with_retry(|| async {
+            let diff = self
                 .provider
-                .get_code_at(address)
+                .raw_request::<_, trace::PrestateDiff>(
+                    "debug_traceTransaction".into(),
+                    (
+                        hash,
+                        serde_json::json!({
+                            "tracer": "prestateTracer",
+                            "tracerConfig": { "diffMode": true },
+                        }),
+                    ),
+                )
                 .await
-                .with_context(|| format!("RPC call get_code_at({address:?}) failed"))?;
+                .with_context(|| format!("RPC call debug_traceTransaction({hash:?}) failed"))?;
+            Ok(diff.into_account_diffs())
+        })
+        .await
+        .map_err(Error::from)
+    }
 
-            let is_contract = !code.is_empty();
-            let code_size = if is_contract { Some(code.len()) } else { None };
+    fn decode_trace_methods(&self, call: &mut CallTrace) {
+        if !call.call_type.starts_with("CREATE") {
+            let to = call.to.as_deref().and_then(|a| a.parse().ok());
+            call.method = self
+                .abis
+                .decode_call(to, &call.input)
+                .map(|(signature, _)| signature)
+                .or_else(|| decode_function_selector(&call.input));
+            if call.error.is_some() && call.revert_reason.is_none() && !call.output.is_empty() {
+                call.revert_reason = Some(self.describe_revert(to, &call.output));
+            }
+        }
+        for child in &mut call.calls {
+            self.decode_trace_methods(child);
+        }
+    }
 
-            // Check for EIP-1967 proxy implementation slot
-            let proxy_impl = if is_contract {
-                self.get_proxy_implementation(address).await.ok().flatten()
-            } else {
-                None
-            };
+    pub async fn get_address(&self, address: Address) -> Result<AddressInfo, Error> {
+        self.get_address_at(address, BlockId::latest()).await
+    }
 
-            // Try to detect ERC-20 token info
-            let token_info = if is_contract {
-                self.detect_erc20(address).await.ok().flatten()
-            } else {
-                None
-            };
+    /// Address state as of a block: balance, nonce, code, proxy, token info
+    /// and token balances. Blocks older than the node's pruning window need
+    /// an archive node.
+    pub async fn get_address_at(
+        &self,
+        address: Address,
+        block: BlockId,
+    ) -> Result<AddressInfo, Error> {
+        // Pin tags and hashes to a number so every read sees the same block
+        let pinned = match block {
+            BlockId::Number(BlockNumberOrTag::Latest) => None,
+            BlockId::Number(BlockNumberOrTag::Number(number)) => Some(number),
+            _ => Some(self.resolve_block_number(block).await?),
+        };
+        let block = pinned.map_or(block, BlockId::number);
 
-            // Resolve ENS name
-            let ens_name = self.resolve_ens_name(address).await;
+        self.with_retry(|| async {
+            let (balance, nonce, code) = self.account_state(address, block).await?;
 
-            // Try to read owner() if contract
-            let owner = if is_contract {
-                self.read_owner(address).await.ok()
-            } else {
-                None
-            };
+            let is_contract = !code.is_empty();
+            let code_size = if is_contract { Some(code.len()) } else { None };
 
-            // Fetch token balances for popular tokens
-            let token_balances = self.get_token_balances(address).await;
+            // Everything else depends on the code at most, so look it up concurrently
+            let contract = async {
+                if !is_contract {
+                    return (None, (None, None));
+                }
+                tokio::join!(
+                    // Check the known proxy patterns
+                    self.detect_proxy(address, &code, block),
+                    // ERC-20 token info and owner()
+                    self.read_contract_getters(address, block),
+                )
+            };
+            let ((proxy, (token_info, owner)), ens_name, token_balances) = tokio::join!(
+                contract,
+                // Resolve ENS name (always the current one)
+                self.resolve_ens_name(address),
+                // Fetch token balances for popular tokens
+                self.get_token_balances(address, block),
+            );
 
             Ok(AddressInfo {
                 address,
@@ -368,202 +1036,663 @@ impl RpcClient {
                 nonce,
                 is_contract,
                 code_size,
-                proxy_impl,
+                proxy,
                 token_info,
                 ens_name,
                 owner,
                 token_balances,
+                block: pinned,
             })
         })
         .await
-        .with_context(|| format!("Failed to fetch address {address:?}"))
+        .with_context(|| match pinned {
+            Some(number) => format!(
+                "Failed to fetch address {address:?} at block {number} \
+                 (state this old may need an archive node)"
+            ),
+            None => format!("Failed to fetch address {address:?}"),
+        })
+        .map_err(Error::from)
     }
 
-    /// Get EIP-1967 proxy implementation address
-    async fn get_proxy_implementation(&self, address: Address) -> Result<Option<Address>> {
-        // EIP-1967 implementation slot: keccak256("eip1967.proxy.implementation") - 1
-        let impl_slot: U256 = "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc"
-            .parse()
-            .unwrap();
-
-        let storage = self
-            .provider
-            .get_storage_at(address, impl_slot)
+    /// Balance, nonce and code of an account at a block, as one batch request
+    async fn account_state(&self, address: Address, block: BlockId) -> Result<(U256, u64, Bytes)> {
+        let mut batch = BatchRequest::new(self.provider.client());
+        let balance = batch.add_call::<_, U256>("eth_getBalance", &(address, block))?;
+        let nonce = batch.add_call::<_, U64>("eth_getTransactionCount", &(address, block))?;
+        let code = batch.add_call::<_, Bytes>("eth_getCode", &(address, block))?;
+        batch
+            .send()
             .await
-            .context("Failed to read storage")?;
-
-        // Check if the slot has a non-zero value
-        if storage != U256::ZERO {
-            // Convert U256 to Address (take last 20 bytes)
-            let bytes: [u8; 32] = storage.to_be_bytes();
-            let addr_bytes: [u8; 20] = bytes[12..32].try_into().unwrap();
-            let impl_addr = Address::from(addr_bytes);
-            if impl_addr != Address::ZERO {
-                return Ok(Some(impl_addr));
-            }
-        }
+            .with_context(|| format!("RPC batch for account {address:?} failed"))?;
 
-        Ok(None)
+        let balance = balance
+            .await
+            .with_context(|| format!("RPC call get_balance({address:?}) failed"))?;
+        let nonce = nonce
+            .await
+            .with_context(|| format!("RPC call get_transaction_count({address:?}) failed"))?;
+        let code = code
+            .await
+            .with_context(|| format!("RPC call get_code_at({address:?}) failed"))?;
+        Ok((balance, nonce.to(), code))
     }
 
-    /// Try to detect if address is an ERC-20 token
-    async fn detect_erc20(&self, address: Address) -> Result<Option<TokenInfo>> {
-        // Try calling name(), symbol(), decimals()
-        let name = self.call_string_getter(address, "name()").await.ok();
-        let symbol = self.call_string_getter(address, "symbol()").await.ok();
-        let decimals = self.call_uint8_getter(address, "decimals()").await.ok();
-        let total_supply = self
-            .call_uint256_getter(address, "totalSupply()")
+    /// Storage words of a contract at a block, as one batch request
+    async fn storage_words<const N: usize>(
+        &self,
+        address: Address,
+        slots: &[B256; N],
+        block: BlockId,
+    ) -> Result<[B256; N]> {
+        let mut batch = BatchRequest::new(self.provider.client());
+        let mut waiters = Vec::with_capacity(N);
+        for slot in slots {
+            let slot = U256::from_be_bytes(slot.0);
+            waiters.push(batch.add_call::<_, U256>("eth_getStorageAt", &(address, slot, block))?);
+        }
+        batch
+            .send()
             .await
-            .ok();
+            .with_context(|| format!("RPC batch for storage of {address:?} failed"))?;
 
-        // If we got at least symbol and decimals, it's likely an ERC-20
-        if symbol.is_some() && decimals.is_some() {
-            return Ok(Some(TokenInfo {
-                name,
-                symbol,
-                decimals,
-                total_supply,
-            }));
+        let mut words = [B256::ZERO; N];
+        for (word, waiter) in words.iter_mut().zip(waiters) {
+            let value = waiter
+                .await
+                .with_context(|| format!("RPC call get_storage_at({address:?}) failed"))?;
+            *word = B256::from(value);
         }
+        Ok(words)
+    }
 
-        Ok(None)
+    /// Number of the block a tag or hash refers to
+    pub async fn resolve_block_number(&self, block: BlockId) -> Result<u64, Error> {
+        self.with_retry(|| async {
+            let header = self
+                .provider
+                .get_block(block)
+                .await
+                .with_context(|| format!("RPC call get_block({}) failed", block_label(block)))?
+                .ok_or_else(|| RpcError::NotFound(format!("Block {}", block_label(block))))?
+                .header;
+            Ok(header.number)
+        })
+        .await
+        .map_err(Error::from)
     }
 
-    async fn call_string_getter(&self, address: Address, signature: &str) -> Result<String> {
-        use alloy::sol_types::SolValue;
+    /// Detect the proxy pattern of a contract and the addresses it points at
+    async fn detect_proxy(
+        &self,
+        address: Address,
+        code: &Bytes,
+        block: BlockId,
+    ) -> Option<ProxyInfo> {
+        if let Some(implementation) = parse_minimal_proxy(code) {
+            return Some(ProxyInfo::new(
+                ProxyKind::MinimalProxy,
+                Some(implementation),
+            ));
+        }
 
-        let selector = &alloy::primitives::keccak256(signature.as_bytes())[..4];
-        let input = Bytes::copy_from_slice(selector);
+        // Every slot in one batch; slot 0 is where Safe proxies keep the singleton
+        let slots = [
+            EIP1967_IMPLEMENTATION_SLOT,
+            EIP1967_BEACON_SLOT,
+            EIP1967_ADMIN_SLOT,
+            OZ_IMPLEMENTATION_SLOT,
+            OZ_ADMIN_SLOT,
+            EIP1822_PROXIABLE_SLOT,
+            B256::ZERO,
+        ];
+        let words = self
+            .with_retry(|| self.storage_words(address, &slots, block))
+            .await
+            .ok()?;
+        let [implementation, beacon, admin, oz_implementation, oz_admin, proxiable, slot0] =
+            words.map(slot_address);
+
+        if let Some(beacon) = beacon {
+            let implementation = self
+                .call(
+                    beacon,
+                    IBeacon::implementationCall {}.abi_encode().into(),
+                    block,
+                )
+                .await
+                .ok()
+                .and_then(|ret| IBeacon::implementationCall::abi_decode_returns(&ret).ok());
+            return Some(ProxyInfo {
+                beacon: Some(beacon),
+                admin,
+                ..ProxyInfo::new(ProxyKind::Eip1967Beacon, implementation)
+            });
+        }
+        if implementation.is_some() {
+            return Some(ProxyInfo {
+                admin,
+                ..ProxyInfo::new(ProxyKind::Eip1967, implementation)
+            });
+        }
+        if oz_implementation.is_some() {
+            return Some(ProxyInfo {
+                admin: oz_admin,
+                ..ProxyInfo::new(ProxyKind::OpenZeppelinLegacy, oz_implementation)
+            });
+        }
+        if proxiable.is_some() {
+            return Some(ProxyInfo::new(ProxyKind::Eip1822, proxiable));
+        }
 
-        let result = self
-            .provider
-            .call(alloy::rpc::types::TransactionRequest {
-                to: Some(TxKind::Call(address)),
-                input: alloy::rpc::types::TransactionInput::new(input),
-                ..Default::default()
+        // Safe proxies answer masterCopy() with the singleton in slot 0. The
+        // getters are only called on code that dispatches their selectors.
+        if slot0.is_some() && has_selector(code, SafeProxy::masterCopyCall::SELECTOR) {
+            let singleton = self
+                .call(
+                    address,
+                    SafeProxy::masterCopyCall {}.abi_encode().into(),
+                    block,
+                )
+                .await
+                .ok()
+                .and_then(|ret| SafeProxy::masterCopyCall::abi_decode_returns(&ret).ok());
+            if singleton.is_some() && singleton == slot0 {
+                return Some(ProxyInfo::new(ProxyKind::Safe, singleton));
+            }
+        }
+
+        if !has_selector(code, DiamondLoupe::facetsCall::SELECTOR) {
+            return None;
+        }
+        let facets = self
+            .call(
+                address,
+                DiamondLoupe::facetsCall {}.abi_encode().into(),
+                block,
+            )
+            .await;
+        let facets: Vec<Facet> = facets
+            .ok()
+            .and_then(|ret| DiamondLoupe::facetsCall::abi_decode_returns(&ret).ok())?
+            .into_iter()
+            .map(|facet| Facet {
+                address: facet.facetAddress,
+                selectors: facet.functionSelectors,
             })
+            .collect();
+        (!facets.is_empty()).then(|| ProxyInfo {
+            facets,
+            ..ProxyInfo::new(ProxyKind::Diamond, None)
+        })
+    }
+
+    /// eth_call at a block, returning the raw return data. Reverts are
+    /// reported with their decoded reason.
+    pub async fn call(&self, to: Address, input: Bytes, block: BlockId) -> Result<Bytes, Error> {
+        self.with_retry(|| self.eth_call(to, input.clone(), block))
             .await
-            .context("Call failed")?;
+            .map_err(Error::from)
+    }
 
-        // Try to decode as string (ABI encoded)
-        if result.len() >= 64 {
-            let decoded = String::abi_decode(&result).map_err(|e| anyhow!("Decode error: {e}"))?;
-            Ok(decoded)
-        } else {
-            Err(anyhow!("Invalid response length"))
-        }
+    /// Call a view function and decode its return values. Calls without
+    /// return types use the loaded ABI's, if one has the function.
+    pub async fn call_function(
+        &self,
+        to: Address,
+        call: &ContractCall,
+        block: BlockId,
+    ) -> Result<Vec<DecodedParam>, Error> {
+        let output = self.call(to, call.calldata()?, block).await?;
+        let abi_function = self.abis.function(Some(to), call.selector());
+        Ok(match abi_function {
+            Some(function) if call.function.outputs.is_empty() => {
+                let mut call = call.clone();
+                call.function.outputs = function.outputs.clone();
+                call.decode_output(&output)?
+            }
+            _ => call.decode_output(&output)?,
+        })
     }
 
-    async fn call_uint8_getter(&self, address: Address, signature: &str) -> Result<u8> {
-        let selector = &alloy::primitives::keccak256(signature.as_bytes())[..4];
-        let input = Bytes::copy_from_slice(selector);
+    /// Call a no-argument getter at a block and decode its single return
+    /// value, e.g. `client.call_getter::<String>(token, "symbol()", block)`
+    pub async fn call_getter<T>(
+        &self,
+        address: Address,
+        signature: &str,
+        block: BlockId,
+    ) -> Result<T, Error>
+    where
+        T: SolValue + From<<T::SolType as SolType>::RustType>,
+    {
+        let selector = &keccak256(signature.as_bytes())[..4];
+        let output = self
+            .eth_call(address, Bytes::copy_from_slice(selector), block)
+            .await?;
+        T::abi_decode(&output).map_err(|e| RpcError::Decode(format!("{signature}: {e}")).into())
+    }
 
-        let result = self
-            .provider
-            .call(alloy::rpc::types::TransactionRequest {
-                to: Some(TxKind::Call(address)),
-                input: alloy::rpc::types::TransactionInput::new(input),
-                ..Default::default()
-            })
+    async fn eth_call(&self, to: Address, input: Bytes, block: BlockId) -> Result<Bytes> {
+        let request = TransactionRequest {
+            to: Some(TxKind::Call(to)),
+            input: alloy::rpc::types::TransactionInput::new(input),
+            ..Default::default()
+        };
+        match self.provider.call(request).block(block).await {
+            Ok(output) => Ok(output),
+            Err(err) => {
+                if let Some(data) = err.as_error_resp().and_then(|e| e.as_revert_data()) {
+                    return Err(RpcError::Reverted(self.describe_revert(Some(to), &data)).into());
+                }
+                Err(err).with_context(|| format!("Call to {to:?} failed"))
+            }
+        }
+    }
+
+    /// Run `eth_call`s at a block in one round trip: through Multicall3
+    /// where it's deployed, otherwise as a JSON-RPC batch. Calls that revert
+    /// or fail give None.
+    async fn multicall(&self, calls: &[(Address, Bytes)], block: BlockId) -> Vec<Option<Bytes>> {
+        if let Ok(output) = self
+            .eth_call(MULTICALL3, encode_aggregate(calls), block)
             .await
-            .context("Call failed")?;
+        {
+            if let Some(results) = decode_aggregate(&output, calls.len()) {
+                return results;
+            }
+        }
 
-        if result.len() >= 32 {
-            Ok(result[31])
-        } else {
-            Err(anyhow!("Invalid response length"))
+        let mut batch = BatchRequest::new(self.provider.client());
+        let waiters: Vec<_> = calls
+            .iter()
+            .map(|(to, input)| {
+                let request = TransactionRequest {
+                    to: Some(TxKind::Call(*to)),
+                    input: alloy::rpc::types::TransactionInput::new(input.clone()),
+                    ..Default::default()
+                };
+                batch
+                    .add_call::<_, Bytes>("eth_call", &(request, block))
+                    .ok()
+            })
+            .collect();
+        if batch.send().await.is_err() {
+            return vec![None; calls.len()];
+        }
+        let mut results = Vec::with_capacity(calls.len());
+        for waiter in waiters {
+            results.push(match waiter {
+                Some(waiter) => waiter.await.ok(),
+                None => None,
+            });
         }
+        results
     }
 
-    async fn call_uint256_getter(&self, address: Address, signature: &str) -> Result<U256> {
-        let selector = &alloy::primitives::keccak256(signature.as_bytes())[..4];
-        let input = Bytes::copy_from_slice(selector);
+    /// Read one storage word of a contract at a block
+    pub async fn get_storage_at(
+        &self,
+        address: Address,
+        slot: B256,
+        block: BlockId,
+    ) -> Result<B256, Error> {
+        self.with_retry(|| async {
+            let value = self
+                .provider
+                .get_storage_at(address, slot.into())
+                .block_id(block)
+                .await
+                .with_context(|| format!("RPC call get_storage_at({address:?}, {slot}) failed"))?;
+            Ok(B256::from(value))
+        })
+        .await
+        .map_err(Error::from)
+    }
 
-        let result = self
-            .provider
-            .call(alloy::rpc::types::TransactionRequest {
-                to: Some(TxKind::Call(address)),
-                input: alloy::rpc::types::TransactionInput::new(input),
-                ..Default::default()
-            })
+    /// Scan blocks `from..=to`, newest first, for token Transfer and Approval
+    /// events with the address as sender/owner or recipient/spender. Stops
+    /// early after MAX_ACTIVITY_EVENTS. `progress` is called with the lowest
+    /// block scanned so far after each chunk.
+    pub async fn scan_activity(
+        &self,
+        address: Address,
+        from: u64,
+        to: u64,
+        progress: impl Fn(u64),
+    ) -> Result<ActivityScan, Error> {
+        if from > to {
+            return Err(anyhow!("Start block {from} is after end block {to}").into());
+        }
+
+        let filter = Filter::new().event_signature(vec![TRANSFER_TOPIC, APPROVAL_TOPIC]);
+        let word = address.into_word();
+        let (mut events, scanned_from) = self
+            .get_logs_chunked(
+                &[filter.clone().topic1(word), filter.topic2(word)],
+                from,
+                to,
+                MAX_ACTIVITY_EVENTS,
+                ActivityEvent::from_log,
+                progress,
+            )
             .await
-            .context("Call failed")?;
+            .with_context(|| format!("Failed to scan activity of {address:?}"))?;
 
-        if result.len() >= 32 {
-            Ok(U256::from_be_slice(&result[..32]))
-        } else {
-            Err(anyhow!("Invalid response length"))
+        events.sort_by_key(|e| std::cmp::Reverse((e.block, e.log_index)));
+        events.dedup_by(|a, b| a.tx_hash == b.tx_hash && a.log_index == b.log_index);
+
+        // Airdropped spam can touch hundreds of tokens; only name the first ones
+        let mut holdings = derive_holdings(address, &events);
+        for holding in holdings.iter_mut().take(ACTIVITY_TOKEN_LOOKUPS) {
+            let latest = BlockId::latest();
+            holding.symbol = self
+                .call_getter(holding.token, "symbol()", latest)
+                .await
+                .ok();
+            if !holding.nft {
+                holding.decimals = self
+                    .call_getter::<U256>(holding.token, "decimals()", latest)
+                    .await
+                    .ok()
+                    .and_then(|d| u8::try_from(d).ok());
+            }
         }
+
+        Ok(ActivityScan {
+            address,
+            from_block: from,
+            to_block: to,
+            scanned_from,
+            events,
+            holdings,
+        })
     }
 
-    /// Try to read owner() from a contract (common in Ownable pattern)
-    async fn read_owner(&self, address: Address) -> Result<String> {
-        let selector = &keccak256("owner()".as_bytes())[..4];
-        let input = Bytes::copy_from_slice(selector);
+    /// Scan blocks `from..=to`, newest first, for a token's ERC-20 Transfer
+    /// events, and read its info as of `to`. Stops early after
+    /// MAX_TOKEN_TRANSFERS. `progress` is called with the lowest block
+    /// scanned so far after each chunk.
+    pub async fn scan_token(
+        &self,
+        token: Address,
+        from: u64,
+        to: u64,
+        progress: impl Fn(u64),
+    ) -> Result<TokenScan, Error> {
+        if from > to {
+            return Err(anyhow!("Start block {from} is after end block {to}").into());
+        }
 
-        let result = self
-            .provider
-            .call(alloy::rpc::types::TransactionRequest {
-                to: Some(TxKind::Call(address)),
-                input: alloy::rpc::types::TransactionInput::new(input),
-                ..Default::default()
-            })
-            .await
-            .context("owner() call failed")?;
+        let filters = [Filter::new().address(token).event_signature(TRANSFER_TOPIC)];
+        let (transfers, (info, _)) = tokio::join!(
+            self.get_logs_chunked(
+                &filters,
+                from,
+                to,
+                MAX_TOKEN_TRANSFERS,
+                // ERC-721 transfers index the token id instead of an amount
+                |log| ActivityEvent::from_log(log).filter(|e| e.amount.is_some()),
+                progress,
+            ),
+            self.read_contract_getters(token, BlockId::number(to)),
+        );
+        let (mut transfers, scanned_from) =
+            transfers.with_context(|| format!("Failed to scan transfers of {token:?}"))?;
+        let info = info
+            .ok_or_else(|| anyhow!("{token:?} doesn't look like an ERC-20 token at block {to}"))?;
+
+        transfers.sort_by_key(|e| std::cmp::Reverse((e.block, e.log_index)));
+        transfers.dedup_by(|a, b| a.tx_hash == b.tx_hash && a.log_index == b.log_index);
+
+        Ok(TokenScan::new(
+            token,
+            info,
+            from,
+            to,
+            scanned_from,
+            transfers,
+        ))
+    }
 
-        if result.len() >= 32 {
-            let owner_addr = Address::from_slice(&result[12..32]);
-            if owner_addr != Address::ZERO {
-                Ok(format!("{owner_addr:?}"))
-            } else {
-                Err(anyhow!("No owner"))
+    /// A holder's token balance at a block, and what a spender may transfer
+    /// on its behalf if one is given
+    pub async fn token_balance_and_allowance(
+        &self,
+        token: Address,
+        holder: Address,
+        spender: Option<Address>,
+        block: BlockId,
+    ) -> Result<(U256, Option<U256>), Error> {
+        let balance = async {
+            let input = IERC20::balanceOfCall { owner: holder }.abi_encode();
+            let output = self.call(token, input.into(), block).await?;
+            IERC20::balanceOfCall::abi_decode_returns(&output)
+                .map_err(|e| RpcError::Decode(format!("balanceOf: {e}")).into())
+        };
+        let allowance = async {
+            let Some(spender) = spender else {
+                return Ok(None);
+            };
+            let input = IERC20::allowanceCall {
+                owner: holder,
+                spender,
             }
-        } else {
-            Err(anyhow!("Invalid response"))
+            .abi_encode();
+            let output = self.call(token, input.into(), block).await?;
+            IERC20::allowanceCall::abi_decode_returns(&output)
+                .map(Some)
+                .map_err(|e| RpcError::Decode(format!("allowance: {e}")).into())
+        };
+        tokio::try_join!(balance, allowance)
+    }
+
+    /// Run a log query over blocks `from..=to`, newest first, stopping after
+    /// the chunk that brings the page to LOG_PAGE_SIZE entries and trimming
+    /// the excess at a block boundary. The next page continues below
+    /// `scanned_from`.
+    pub async fn query_logs(
+        &self,
+        query: &LogQuery,
+        from: u64,
+        to: u64,
+        progress: impl Fn(u64),
+    ) -> Result<LogPage, Error> {
+        if from > to {
+            return Err(anyhow!("Start block {from} is after end block {to}").into());
         }
+
+        let (mut entries, scanned_from) = self
+            .get_logs_chunked(
+                &[query.filter()],
+                from,
+                to,
+                LOG_PAGE_SIZE,
+                |log| {
+                    Some(LogEntry {
+                        block: log.block_number?,
+                        tx_hash: log.transaction_hash?,
+                        log_index: log.log_index?,
+                        log: query.decode(log, &self.abis),
+                    })
+                },
+                progress,
+            )
+            .await?;
+        entries.sort_by_key(|e| std::cmp::Reverse((e.block, e.log_index)));
+
+        let mut page = LogPage {
+            to_block: to,
+            scanned_from,
+            entries,
+        };
+        page.truncate(LOG_PAGE_SIZE);
+        Ok(page)
     }
 
-    /// Get ERC-20 balances for popular tokens
+    /// Walk blocks `from..=to` newest first in chunks, running every filter
+    /// over each chunk and keeping the logs `map` accepts. Chunks shrink when
+    /// the node rejects a range; other failures are retried. Stops after the
+    /// chunk that reaches `limit` items and returns the lowest block scanned.
+    async fn get_logs_chunked<T>(
+        &self,
+        filters: &[Filter],
+        from: u64,
+        to: u64,
+        limit: usize,
+        map: impl Fn(&Log) -> Option<T>,
+        progress: impl Fn(u64),
+    ) -> Result<(Vec<T>, u64)> {
+        let mut items = Vec::new();
+        let mut chunk = ChunkSize::new();
+        let mut high = to;
+        loop {
+            let low = high.saturating_sub(chunk.get() - 1).max(from);
+            match self.get_logs_in_range(filters, low, high).await {
+                Ok(logs) => {
+                    items.extend(logs.iter().filter_map(&map));
+                    chunk.grow();
+                    progress(low);
+                    if low == from || items.len() >= limit {
+                        return Ok((items, low));
+                    }
+                    high = low - 1;
+                }
+                Err(e) if is_range_error(&format!("{e:#}")) && chunk.shrink() => {}
+                Err(e) => {
+                    return Err(e).context(format!("Failed to get logs in blocks {low}-{high}"))
+                }
+            }
+        }
+    }
+
+    /// Logs matching any of the filters in one block range. A rejected range
+    /// is returned as is so the caller can split it; other failures are
+    /// retried.
+    async fn get_logs_in_range(&self, filters: &[Filter], from: u64, to: u64) -> Result<Vec<Log>> {
+        let mut logs = Vec::new();
+        for filter in filters {
+            let filter = filter.clone().from_block(from).to_block(to);
+            let fetch = || async {
+                self.provider
+                    .get_logs(&filter)
+                    .await
+                    .with_context(|| format!("RPC call get_logs({from}-{to}) failed"))
+            };
+            logs.extend(match fetch().await {
+                Ok(logs) => logs,
+                Err(e) if is_range_error(&format!("{e:#}")) => return Err(e),
+                Err(e) if !RpcError::of(&e).is_some_and(|error| error.is_retryable()) => {
+                    return Err(e)
+                }
+                Err(_) => self.with_retry(fetch).await?,
+            });
+        }
+        Ok(logs)
+    }
+
+    /// ERC-20 token info, if the contract looks like a token, and its
+    /// owner() (the Ownable pattern), read in one multicall
+    async fn read_contract_getters(
+        &self,
+        address: Address,
+        block: BlockId,
+    ) -> (Option<TokenInfo>, Option<String>) {
+        const GETTERS: [&str; 5] = [
+            "name()",
+            "symbol()",
+            "decimals()",
+            "totalSupply()",
+            "owner()",
+        ];
+        let calls: Vec<(Address, Bytes)> = GETTERS
+            .iter()
+            .map(|signature| {
+                let selector = &keccak256(signature.as_bytes())[..4];
+                (address, Bytes::copy_from_slice(selector))
+            })
+            .collect();
+        let results = self.multicall(&calls, block).await;
+        let [name, symbol, decimals, total_supply, owner] = &results[..] else {
+            return (None, None);
+        };
+
+        let decimals = decode_return::<U256>(decimals).and_then(|d| u8::try_from(d).ok());
+        let symbol: Option<String> = decode_return(symbol);
+        // If we got at least symbol and decimals, it's likely an ERC-20
+        let token_info = (symbol.is_some() && decimals.is_some()).then(|| TokenInfo {
+            name: decode_return(name),
+            symbol,
+            decimals,
+            total_supply: decode_return(total_supply),
+        });
+        let owner = decode_return::<Address>(owner)
+            .filter(|owner| *owner != Address::ZERO)
+            .map(|owner| format!("{owner:?}"));
+        (token_info, owner)
+    }
+
+    /// Get ERC-20 balances for the profile's tokens, or the chain's popular tokens
     /// Returns empty vec on any error to avoid breaking address queries
-    async fn get_token_balances(&self, address: Address) -> Vec<TokenBalance> {
+    async fn get_token_balances(&self, address: Address, block: BlockId) -> Vec<TokenBalance> {
         // Wrap in timeout to avoid hanging
         let result = tokio::time::timeout(
             Duration::from_secs(5),
-            self.fetch_token_balances_inner(address),
+            self.fetch_token_balances_inner(address, block),
         )
         .await;
 
         result.unwrap_or_default()
     }
 
-    async fn fetch_token_balances_inner(&self, address: Address) -> Vec<TokenBalance> {
+    async fn fetch_token_balances_inner(
+        &self,
+        address: Address,
+        block: BlockId,
+    ) -> Vec<TokenBalance> {
         let mut balances = Vec::new();
 
         // balanceOf(address) selector
         let selector = &keccak256("balanceOf(address)".as_bytes())[..4];
 
-        for (symbol, name, token_addr, decimals) in POPULAR_TOKENS {
-            let Ok(token_address) = token_addr.parse::<Address>() else {
-                continue;
+        let tokens: Vec<KnownToken> = if self.tokens.is_empty() {
+            let Some(chain) = self.chain().await else {
+                return balances;
             };
+            chain
+                .tokens
+                .iter()
+                .filter_map(|(symbol, name, address, decimals)| {
+                    Some(KnownToken {
+                        symbol: symbol.to_string(),
+                        name: name.to_string(),
+                        address: address.parse().ok()?,
+                        decimals: *decimals,
+                    })
+                })
+                .collect()
+        } else {
+            self.tokens.to_vec()
+        };
 
-            // Build calldata: selector + padded address
-            let mut calldata = Vec::with_capacity(36);
-            calldata.extend_from_slice(selector);
-            calldata.extend_from_slice(&[0u8; 12]); // padding
-            calldata.extend_from_slice(address.as_slice());
+        // Build calldata: selector + padded address
+        let mut calldata = Vec::with_capacity(36);
+        calldata.extend_from_slice(selector);
+        calldata.extend_from_slice(&[0u8; 12]); // padding
+        calldata.extend_from_slice(address.as_slice());
+        let calldata = Bytes::from(calldata);
 
-            let result = self
-                .provider
-                .call(alloy::rpc::types::TransactionRequest {
-                    to: Some(TxKind::Call(token_address)),
-                    input: alloy::rpc::types::TransactionInput::new(Bytes::from(calldata)),
-                    ..Default::default()
-                })
-                .await;
+        let calls: Vec<(Address, Bytes)> = tokens
+            .iter()
+            .map(|token| (token.address, calldata.clone()))
+            .collect();
+        let results = self.multicall(&calls, block).await;
+
+        for (token, result) in tokens.into_iter().zip(results) {
+            let token_address = token.address;
+            let decimals = token.decimals;
 
-            if let Ok(data) = result {
+            if let Some(data) = result {
                 if data.len() >= 32 {
                     let balance = U256::from_be_slice(&data[..32]);
                     // Filter out tiny balances (< 0.0001 in token units)
@@ -571,11 +1700,15 @@ impl RpcClient {
                     let min_balance = U256::from(10u64).pow(U256::from(decimals.saturating_sub(4)));
                     if balance >= min_balance {
                         balances.push(TokenBalance {
-                            symbol: symbol.to_string(),
-                            name: name.to_string(),
+                            name: if token.name.is_empty() {
+                                token.symbol.clone()
+                            } else {
+                                token.name
+                            },
+                            symbol: token.symbol,
                             address: token_address,
                             balance,
-                            decimals: *decimals,
+                            decimals,
                         });
                     }
                 }
@@ -585,7 +1718,7 @@ impl RpcClient {
         balances
     }
 
-    pub async fn get_network_info(&self) -> Result<NetworkInfo> {
+    pub async fn get_network_info(&self) -> Result<NetworkInfo, Error> {
         let latest_block = self.get_latest_block_number().await?;
 
         let gas_price = self
@@ -621,6 +1754,7 @@ impl RpcClient {
         });
 
         Ok(NetworkInfo {
+            chain_id: self.chain_id().await.ok(),
             latest_block,
             gas_price,
             client_version,
@@ -637,6 +1771,14 @@ impl RpcClient {
         if addresses.is_empty() {
             return result;
         }
+        let Some(reverse_records) = self
+            .chain()
+            .await
+            .and_then(|chain| chain.ens)
+            .and_then(|ens| ens.reverse_records)
+        else {
+            return result;
+        };
 
         // Build the call data for ReverseRecords.getNames(addresses)
         let call = ReverseRecords::getNamesCall {
@@ -644,7 +1786,7 @@ impl RpcClient {
         };
 
         let tx = TransactionRequest {
-            to: Some(TxKind::Call(ENS_REVERSE_RECORDS)),
+            to: Some(TxKind::Call(reverse_records)),
             input: alloy::rpc::types::TransactionInput::new(call.abi_encode().into()),
             ..Default::default()
         };
@@ -670,13 +1812,18 @@ impl RpcClient {
     }
 
     /// Resolve an ENS name to an address (forward: name -> address)
-    pub async fn resolve_ens_to_address(&self, name: &str) -> Result<Address> {
+    pub async fn resolve_ens_to_address(&self, name: &str) -> Result<Address, Error> {
+        let registry = match self.chain().await {
+            Some(ChainInfo { ens: Some(ens), .. }) => ens.registry,
+            Some(chain) => return Err(anyhow!("ENS is not available on {}", chain.name).into()),
+            None => return Err(anyhow!("ENS is not available on this chain").into()),
+        };
         let node = namehash(name);
 
         // Step 1: Get the resolver address from the ENS registry
         let registry_call = ENSRegistry::resolverCall { node };
         let registry_tx = TransactionRequest {
-            to: Some(TxKind::Call(ENS_REGISTRY)),
+            to: Some(TxKind::Call(registry)),
             input: alloy::rpc::types::TransactionInput::new(registry_call.abi_encode().into()),
             ..Default::default()
         };
@@ -691,7 +1838,7 @@ impl RpcClient {
             .context("Failed to decode resolver address")?;
 
         if resolver_addr == Address::ZERO {
-            return Err(anyhow!("No resolver found for ENS name: {name}"));
+            return Err(anyhow!("No resolver found for ENS name: {name}").into());
         }
 
         // Step 2: Query the resolver for the address
@@ -712,9 +1859,136 @@ impl RpcClient {
             .context("Failed to decode resolved address")?;
 
         if resolved_addr == Address::ZERO {
-            return Err(anyhow!("ENS name {name} does not resolve to an address"));
+            return Err(anyhow!("ENS name {name} does not resolve to an address").into());
         }
 
         Ok(resolved_addr)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::address;
+    use alloy::sol_types::SolValue;
+    use alloy::transports::mock::Asserter;
+
+    #[test]
+    fn test_describe_revert() {
+        let client = RpcClient::new("http://localhost:8545").unwrap();
+        assert_eq!(
+            client.describe_revert(None, &[]),
+            "Reverted without a reason"
+        );
+
+        let mut data = vec![0x08, 0xc3, 0x79, 0xa0];
+        data.extend("Ownable: caller is not the owner".to_string().abi_encode());
+        assert_eq!(
+            client.describe_revert(None, &data),
+            "Ownable: caller is not the owner"
+        );
+
+        let owner = address!("00000000000000000000000000000000000000aa");
+        let mut data = keccak256("OwnableUnauthorizedAccount(address)")[..4].to_vec();
+        data.extend(owner.abi_encode());
+        assert_eq!(
+            client.describe_revert(None, &data),
+            format!("OwnableUnauthorizedAccount({owner:?})")
+        );
+
+        assert_eq!(
+            client.describe_revert(None, &[0xde, 0xad, 0xbe, 0xef]),
+            "Custom error 0xdeadbeef"
+        );
+    }
+
+    #[test]
+    fn test_describe_revert_with_abi() {
+        let mut abis = AbiRegistry::default();
+        abis.insert(
+            None,
+            serde_json::from_str(
+                r#"[{"type":"error","name":"TooLate",
+                    "inputs":[{"name":"deadline","type":"uint256"}]}]"#,
+            )
+            .unwrap(),
+        );
+        let client = RpcClient::new("http://localhost:8545")
+            .unwrap()
+            .with_abis(Arc::new(abis));
+
+        let mut data = keccak256("TooLate(uint256)")[..4].to_vec();
+        data.extend(U256::from(1700000000u64).abi_encode());
+        assert_eq!(
+            client.describe_revert(None, &data),
+            "TooLate(deadline: 1700000000)"
+        );
+
+        // Known names are kept when the arguments don't decode
+        assert_eq!(
+            client.describe_revert(None, &keccak256("TooLate(uint256)")[..4]),
+            "TooLate(<undecodable args>)"
+        );
+        let data = keccak256("OwnableUnauthorizedAccount(address)")[..4].to_vec();
+        assert_eq!(
+            client.describe_revert(None, &data),
+            "OwnableUnauthorizedAccount(<undecodable args>)"
+        );
+    }
+
+    #[test]
+    fn test_describe_revert_response() {
+        let client = RpcClient::new("http://localhost:8545").unwrap();
+        let payload = |code, message: &'static str| ErrorPayload {
+            code,
+            message: message.into(),
+            data: None,
+        };
+
+        assert_eq!(
+            client.describe_revert_response(None, &payload(3, "execution reverted")),
+            Some("execution reverted".to_string())
+        );
+        assert_eq!(
+            client.describe_revert_response(None, &payload(-32000, "execution reverted: Paused")),
+            Some("execution reverted: Paused".to_string())
+        );
+        // Node failures aren't the transaction's revert reason
+        assert_eq!(
+            client.describe_revert_response(None, &payload(-32000, "missing trie node 0xab")),
+            None
+        );
+        assert_eq!(
+            client.describe_revert_response(None, &payload(-32005, "rate limit exceeded")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_logs_retries_only_retryable_errors() {
+        let asserter = Asserter::new();
+        let provider = RootProvider::new(alloy::rpc::client::RpcClient::mocked(asserter.clone()));
+        let mut client = RpcClient::with_provider(
+            provider,
+            RpcTransport::parse("http://localhost:8545").unwrap(),
+        );
+        client.base_delay = Duration::from_millis(1);
+        let filters = [Filter::new()];
+        let payload = |code, message: &'static str| ErrorPayload {
+            code,
+            message: message.into(),
+            data: None,
+        };
+
+        // A rejected request is returned as is, leaving the next response queued
+        asserter.push_failure(payload(-32602, "invalid params"));
+        asserter.push_success(&Vec::<Log>::new());
+        assert!(client.get_logs_in_range(&filters, 1, 2).await.is_err());
+        assert!(client.get_logs_in_range(&filters, 1, 2).await.is_ok());
+
+        // A rate limit is retried
+        asserter.push_failure(payload(-32005, "rate limit exceeded"));
+        asserter.push_success(&Vec::<Log>::new());
+        assert!(client.get_logs_in_range(&filters, 1, 2).await.is_ok());
+    }
+}
@@ -0,0 +1,109 @@
+//! Multicall3, deployed at the same address on nearly every chain, to run
+//! many `eth_call`s as one.
+
+use alloy::primitives::{address, Address, Bytes};
+use alloy::sol;
+use alloy::sol_types::{SolCall, SolType, SolValue};
+
+sol! {
+    interface Multicall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+        struct Call3Result {
+            bool success;
+            bytes returnData;
+        }
+        function aggregate3(Call3[] calldata calls) external payable returns (Call3Result[] memory returnData);
+    }
+}
+
+pub(super) const MULTICALL3: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+/// `aggregate3` calldata for calls that may each fail on their own
+pub(super) fn encode_aggregate(calls: &[(Address, Bytes)]) -> Bytes {
+    Multicall3::aggregate3Call {
+        calls: calls
+            .iter()
+            .map(|(target, input)| Multicall3::Call3 {
+                target: *target,
+                allowFailure: true,
+                callData: input.clone(),
+            })
+            .collect(),
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Return data of each call, None where it reverted. None overall if the
+/// output isn't results for `count` calls, as when Multicall3 isn't
+/// deployed at that block.
+pub(super) fn decode_aggregate(output: &[u8], count: usize) -> Option<Vec<Option<Bytes>>> {
+    let results = Multicall3::aggregate3Call::abi_decode_returns(output).ok()?;
+    (results.len() == count).then(|| {
+        results
+            .into_iter()
+            .map(|result| result.success.then_some(result.returnData))
+            .collect()
+    })
+}
+
+/// A call's return value, if it succeeded and decodes as `T`
+pub(super) fn decode_return<T>(output: &Option<Bytes>) -> Option<T>
+where
+    T: SolValue + From<<T::SolType as SolType>::RustType>,
+{
+    T::abi_decode(output.as_ref()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_round_trip() {
+        let calls = [
+            (
+                Address::repeat_byte(0x11),
+                Bytes::from_static(&[0x95, 0xd8, 0x9b, 0x41]),
+            ),
+            (Address::repeat_byte(0x22), Bytes::new()),
+        ];
+        let input = encode_aggregate(&calls);
+        assert_eq!(input[..4], Multicall3::aggregate3Call::SELECTOR);
+
+        let decoded = Multicall3::aggregate3Call::abi_decode(&input).unwrap();
+        assert_eq!(decoded.calls.len(), 2);
+        assert_eq!(decoded.calls[0].target, calls[0].0);
+        assert!(decoded.calls.iter().all(|call| call.allowFailure));
+
+        let output = vec![
+            Multicall3::Call3Result {
+                success: true,
+                returnData: "USDC".abi_encode().into(),
+            },
+            Multicall3::Call3Result {
+                success: false,
+                returnData: Bytes::new(),
+            },
+        ]
+        .abi_encode();
+        let results = decode_aggregate(&output, 2).unwrap();
+        assert_eq!(
+            decode_return::<String>(&results[0]).as_deref(),
+            Some("USDC")
+        );
+        assert_eq!(results[1], None);
+        assert_eq!(decode_return::<String>(&results[1]), None);
+        assert!(decode_aggregate(&output, 3).is_none());
+    }
+
+    #[test]
+    fn test_aggregate_without_multicall() {
+        // eth_call to an address without code returns nothing
+        assert!(decode_aggregate(&[], 2).is_none());
+    }
+}
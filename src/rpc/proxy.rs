@@ -0,0 +1,140 @@
+//! Proxy pattern detection: well-known storage slots, minimal proxy
+//! bytecode, and the Safe and Diamond (EIP-2535) getters.
+
+use alloy::primitives::{b256, hex, Address, Bytes, B256};
+use alloy::sol;
+
+/// keccak256("eip1967.proxy.implementation") - 1
+pub(super) const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+/// keccak256("eip1967.proxy.beacon") - 1
+pub(super) const EIP1967_BEACON_SLOT: B256 =
+    b256!("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+/// keccak256("eip1967.proxy.admin") - 1
+pub(super) const EIP1967_ADMIN_SLOT: B256 =
+    b256!("b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103");
+/// keccak256("org.zeppelinos.proxy.implementation"), used by OpenZeppelin before EIP-1967
+pub(super) const OZ_IMPLEMENTATION_SLOT: B256 =
+    b256!("7050c9e0f4ca769c69bd3a8ef740bc37934f8e2c036e5a723fd8ee048ed3f8c3");
+/// keccak256("org.zeppelinos.proxy.admin")
+pub(super) const OZ_ADMIN_SLOT: B256 =
+    b256!("10d6a54a4754c8869d6886b5f5d7fbfa5b4522237ea5c60d11bc4e7a1ff9390b");
+/// keccak256("PROXIABLE"), the EIP-1822 (UUPS) implementation slot
+pub(super) const EIP1822_PROXIABLE_SLOT: B256 =
+    b256!("c5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bcf7");
+
+sol! {
+    interface IBeacon {
+        function implementation() external view returns (address);
+    }
+}
+
+sol! {
+    interface SafeProxy {
+        function masterCopy() external view returns (address);
+    }
+}
+
+sol! {
+    interface DiamondLoupe {
+        struct Facet {
+            address facetAddress;
+            bytes4[] functionSelectors;
+        }
+        function facets() external view returns (Facet[] memory);
+    }
+}
+
+const PUSH4: u8 = 0x63;
+
+/// EIP-1167 minimal proxy runtime code around the implementation address,
+/// and the EIP-7511 variant that uses PUSH0
+const MINIMAL_PROXY_CODE: &[(&[u8], &[u8])] = &[
+    (
+        &hex!("363d3d373d3d3d363d73"),
+        &hex!("5af43d82803e903d91602b57fd5bf3"),
+    ),
+    (
+        &hex!("365f5f375f5f365f73"),
+        &hex!("5af43d5f5f3e5f3d91602a57fd5bf3"),
+    ),
+];
+
+/// Implementation address of an EIP-1167 minimal proxy, parsed from its runtime code
+pub(super) fn parse_minimal_proxy(code: &Bytes) -> Option<Address> {
+    MINIMAL_PROXY_CODE.iter().find_map(|(prefix, suffix)| {
+        let rest = code.strip_prefix(*prefix)?.strip_suffix(*suffix)?;
+        (rest.len() == 20).then(|| Address::from_slice(rest))
+    })
+}
+
+/// Whether runtime code pushes `selector` (PUSH4), as function dispatch does
+pub(super) fn has_selector(code: &[u8], selector: [u8; 4]) -> bool {
+    code.windows(5)
+        .any(|window| window[0] == PUSH4 && window[1..] == selector)
+}
+
+/// The address held in a storage word, if it is non-zero
+pub(super) fn slot_address(word: B256) -> Option<Address> {
+    let address = Address::from_word(word);
+    (address != Address::ZERO).then_some(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, keccak256, U256};
+
+    #[test]
+    fn test_slot_constants() {
+        let eip1967 =
+            |label: &str| B256::from(U256::from_be_bytes(keccak256(label).0) - U256::from(1));
+        assert_eq!(
+            EIP1967_IMPLEMENTATION_SLOT,
+            eip1967("eip1967.proxy.implementation")
+        );
+        assert_eq!(EIP1967_BEACON_SLOT, eip1967("eip1967.proxy.beacon"));
+        assert_eq!(EIP1967_ADMIN_SLOT, eip1967("eip1967.proxy.admin"));
+        assert_eq!(
+            OZ_IMPLEMENTATION_SLOT,
+            keccak256("org.zeppelinos.proxy.implementation")
+        );
+        assert_eq!(OZ_ADMIN_SLOT, keccak256("org.zeppelinos.proxy.admin"));
+        assert_eq!(EIP1822_PROXIABLE_SLOT, keccak256("PROXIABLE"));
+    }
+
+    #[test]
+    fn test_parse_minimal_proxy() {
+        let implementation = address!("bebebebebebebebebebebebebebebebebebebebe");
+        let eip1167 = Bytes::from(hex!(
+            "363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3"
+        ));
+        assert_eq!(parse_minimal_proxy(&eip1167), Some(implementation));
+
+        let eip7511 = Bytes::from(hex!(
+            "365f5f375f5f365f73bebebebebebebebebebebebebebebebebebebebe5af43d5f5f3e5f3d91602a57fd5bf3"
+        ));
+        assert_eq!(parse_minimal_proxy(&eip7511), Some(implementation));
+
+        // Truncated or longer code isn't a minimal proxy
+        assert_eq!(parse_minimal_proxy(&eip1167.slice(..44)), None);
+        assert_eq!(parse_minimal_proxy(&Bytes::from(hex!("6080604052"))), None);
+    }
+
+    #[test]
+    fn test_has_selector() {
+        // PUSH4 0xa619486e (masterCopy()) then EQ, as in the Safe proxy
+        let code = hex!("6000356001600160a01b031663a619486e14");
+        assert!(has_selector(&code, [0xa6, 0x19, 0x48, 0x6e]));
+        // The same bytes outside a PUSH4 don't count
+        assert!(!has_selector(&hex!("60a619486e"), [0xa6, 0x19, 0x48, 0x6e]));
+        assert!(!has_selector(&[], [0xa6, 0x19, 0x48, 0x6e]));
+    }
+
+    #[test]
+    fn test_slot_address() {
+        assert_eq!(slot_address(B256::ZERO), None);
+        let word = B256::left_padding_from(&[0x11; 20]);
+        assert_eq!(slot_address(word), Some(Address::repeat_byte(0x11)));
+    }
+}
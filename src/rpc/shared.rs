@@ -0,0 +1,66 @@
+//! One connection to the configured endpoint, shared by every request the
+//! TUI makes.
+
+use super::{AbiRegistry, KnownToken, RpcClient};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+/// A client that connects on first use and is then shared by every task it
+/// is cloned into, so fetches reuse one HTTP connection pool (and its
+/// concurrency limit) or one WebSocket/IPC connection instead of opening
+/// their own.
+#[derive(Clone)]
+pub struct SharedClient {
+    rpc_url: String,
+    abis: Arc<AbiRegistry>,
+    tokens: Arc<[KnownToken]>,
+    client: Arc<OnceCell<Arc<RpcClient>>>,
+}
+
+impl SharedClient {
+    pub fn new(rpc_url: &str, abis: Arc<AbiRegistry>, tokens: &[KnownToken]) -> Self {
+        Self {
+            rpc_url: rpc_url.to_string(),
+            abis,
+            tokens: tokens.into(),
+            client: Arc::default(),
+        }
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    /// The connected client. A failed connection is retried on the next call.
+    pub async fn get(&self) -> Result<Arc<RpcClient>> {
+        self.client
+            .get_or_try_init(|| async {
+                let client = RpcClient::connect(&self.rpc_url)
+                    .await?
+                    .with_abis(self.abis.clone())
+                    .with_tokens(&self.tokens);
+                Ok(Arc::new(client))
+            })
+            .await
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_clones_share_the_client() {
+        let shared = SharedClient::new("http://localhost:8545", Arc::default(), &[]);
+        let clone = shared.clone();
+        let client = shared.get().await.unwrap();
+        assert!(Arc::ptr_eq(&client, &clone.get().await.unwrap()));
+
+        // Unsupported URLs fail on use, and keep failing
+        let shared = SharedClient::new("ftp://example.com", Arc::default(), &[]);
+        assert!(shared.get().await.is_err());
+        assert!(shared.get().await.is_err());
+    }
+}
@@ -0,0 +1,505 @@
+use super::abi::AbiRegistry;
+use super::helper::{
+    hex_encode, known_error_selector, known_event_signature, known_function_selector,
+};
+use alloy::{
+    dyn_abi::{DynSolType, JsonAbiExt},
+    json_abi::Function,
+    primitives::{keccak256, Selector, B256},
+};
+use anyhow::{anyhow, Context, Result};
+use std::collections::{BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+static GLOBAL: OnceLock<SignatureDb> = OnceLock::new();
+
+/// Local function/event signature database (4byte-style).
+///
+/// Stored as a sorted text file with one `<keccak256 hex> <signature>` entry
+/// per line, so lookups binary search the file on disk instead of loading it.
+/// A function selector is the first 8 hex chars of the hash and an event
+/// topic is the whole hash, which lets one file serve both.
+#[derive(Debug)]
+pub struct SignatureDb {
+    path: PathBuf,
+    cache: Mutex<HashMap<String, Vec<String>>>,
+}
+
+/// Result of importing a signature dump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportStats {
+    pub added: usize,
+    pub total: usize,
+}
+
+impl SignatureDb {
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cache: Mutex::default(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Make `db` the database consulted by `decode_function_selector` and
+    /// `decode_event_signature`. Only the first call has any effect.
+    pub fn install(db: SignatureDb) {
+        let _ = GLOBAL.set(db);
+    }
+
+    pub fn global() -> Option<&'static SignatureDb> {
+        GLOBAL.get()
+    }
+
+    /// All known signatures for a 4-byte function selector
+    pub fn functions(&self, selector: &[u8]) -> Vec<String> {
+        match selector.get(..4) {
+            Some(selector) => self.lookup(&hex_encode(selector)),
+            None => Vec::new(),
+        }
+    }
+
+    /// Signatures for the selector of `input` that fit its calldata.
+    ///
+    /// When several signatures share a selector, candidates whose argument
+    /// types don't round-trip the calldata are dropped. Whatever survives is
+    /// ordered shortest first; if nothing fits (e.g. malformed calldata) all
+    /// candidates are returned.
+    pub fn resolve_function(&self, input: &[u8]) -> Vec<String> {
+        let mut candidates = self.functions(input);
+        if candidates.len() > 1 {
+            let args = &input[4..];
+            let fitting: Vec<String> = candidates
+                .iter()
+                .filter(|sig| calldata_fits(sig, args))
+                .cloned()
+                .collect();
+            if !fitting.is_empty() {
+                candidates = fitting;
+            }
+        }
+        candidates.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        candidates
+    }
+
+    /// Event signature for a log's topic0
+    pub fn event(&self, topic0: &B256) -> Option<String> {
+        self.events(topic0).into_iter().next()
+    }
+
+    /// All known signatures for an event topic
+    pub fn events(&self, topic0: &B256) -> Vec<String> {
+        self.lookup(&hex_encode(topic0.as_slice()))
+    }
+
+    /// Every signature whose hash starts with `prefix` (lowercase hex)
+    fn lookup(&self, prefix: &str) -> Vec<String> {
+        if let Some(hit) = self.cache.lock().unwrap().get(prefix) {
+            return hit.clone();
+        }
+
+        let found = self.search_file(prefix).unwrap_or_default();
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(prefix.to_string(), found.clone());
+        found
+    }
+
+    fn search_file(&self, prefix: &str) -> Result<Vec<String>> {
+        let file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+
+        // Binary search for the first line whose hash is >= prefix
+        let (mut lo, mut hi) = (0, len);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match read_line_from(&mut reader, mid)? {
+                Some(line) if line.as_str() < prefix => lo = mid + 1,
+                _ => hi = mid,
+            }
+        }
+
+        next_line_start(&mut reader, lo)?;
+        let mut found = Vec::new();
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 {
+            let Some((hash, signature)) = line.trim_end().split_once(' ') else {
+                break;
+            };
+            if !hash.starts_with(prefix) {
+                break;
+            }
+            found.push(signature.to_string());
+            line.clear();
+        }
+        Ok(found)
+    }
+
+    /// Merge every signature found in `dump` into the database.
+    ///
+    /// Accepts 4byte-style dumps in any common shape (plain signature lists,
+    /// `selector,signature` CSV, or JSON from 4byte.directory/openchain) by
+    /// scanning for text that looks like a signature. Hashes are recomputed
+    /// from the signature text, so stray or mismatched selectors are ignored.
+    pub fn import(&self, dump: &str) -> Result<ImportStats> {
+        let mut entries: BTreeSet<String> = match std::fs::read_to_string(&self.path) {
+            Ok(existing) => existing.lines().map(String::from).collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeSet::new(),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to read {:?}", self.path));
+            }
+        };
+        let before = entries.len();
+
+        for signature in extract_signatures(dump) {
+            let hash = keccak256(signature.as_bytes());
+            entries.insert(format!("{} {signature}", hex_encode(hash.as_slice())));
+        }
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create directory {dir:?}"))?;
+        }
+
+        // Write to a temp file first so an interrupted import can't truncate the db
+        let tmp = self.path.with_extension("tmp");
+        let mut out = std::io::BufWriter::new(
+            File::create(&tmp).with_context(|| format!("Failed to create {tmp:?}"))?,
+        );
+        for entry in &entries {
+            writeln!(out, "{entry}")?;
+        }
+        out.flush()?;
+        drop(out);
+        std::fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to write {:?}", self.path))?;
+
+        self.cache.lock().unwrap().clear();
+
+        Ok(ImportStats {
+            added: entries.len() - before,
+            total: entries.len(),
+        })
+    }
+}
+
+/// Seek to the first line starting at or after `offset`
+fn next_line_start(reader: &mut BufReader<File>, offset: u64) -> Result<()> {
+    if offset == 0 {
+        reader.seek(SeekFrom::Start(0))?;
+    } else {
+        // Start one byte early so a line beginning exactly at `offset` isn't skipped
+        reader.seek(SeekFrom::Start(offset - 1))?;
+        reader.skip_until(b'\n')?;
+    }
+    Ok(())
+}
+
+/// Read the first full line starting at or after `offset` (None at EOF)
+fn read_line_from(reader: &mut BufReader<File>, offset: u64) -> Result<Option<String>> {
+    next_line_start(reader, offset)?;
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+/// Whether `args` is exactly the ABI encoding of some arguments for `signature`
+fn calldata_fits(signature: &str, args: &[u8]) -> bool {
+    let Ok(function) = Function::parse(signature) else {
+        return false;
+    };
+    function
+        .abi_decode_input(args)
+        .ok()
+        .and_then(|values| function.abi_encode_input_raw(&values).ok())
+        .is_some_and(|encoded| encoded == args)
+}
+
+/// Find everything in `text` shaped like `name(type,...)` with valid ABI types
+fn extract_signatures(text: &str) -> Vec<String> {
+    let bytes = text.as_bytes();
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$';
+    let mut signatures = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let starts_ident = (bytes[i].is_ascii_alphabetic() || bytes[i] == b'_' || bytes[i] == b'$')
+            && (i == 0 || !is_ident(bytes[i - 1]));
+        if !starts_ident {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && is_ident(bytes[i]) {
+            i += 1;
+        }
+        if bytes.get(i) != Some(&b'(') {
+            continue;
+        }
+
+        // Find the matching close paren
+        let params_start = i;
+        let mut depth = 0;
+        let mut end = None;
+        for (j, &b) in bytes.iter().enumerate().skip(i) {
+            match b {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(j + 1);
+                        break;
+                    }
+                }
+                b if b.is_ascii_alphanumeric() || b"[],_".contains(&b) => {}
+                _ => break,
+            }
+        }
+
+        let Some(end) = end else {
+            continue;
+        };
+        i = end;
+
+        if DynSolType::parse(&text[params_start..end]).is_ok() {
+            signatures.push(text[start..end].to_string());
+        }
+    }
+
+    signatures
+}
+
+/// What a selector, event topic or signature is known as: the built-in
+/// tables, loaded ABIs and the signature database, for the lookup screen
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignatureLookup {
+    pub query: String,
+    pub selector: Option<Selector>,
+    pub topic: Option<B256>,
+    pub functions: Vec<String>,
+    pub errors: Vec<String>,
+    pub events: Vec<String>,
+}
+
+impl SignatureLookup {
+    /// Functions and custom errors with a 4-byte selector
+    pub fn selector(selector: Selector, abis: &AbiRegistry, db: Option<&SignatureDb>) -> Self {
+        let mut functions: Vec<String> = known_function_selector(selector.as_slice())
+            .map(String::from)
+            .into_iter()
+            .chain(abis.function(None, selector).map(|f| f.signature()))
+            .chain(
+                db.map(|db| db.functions(selector.as_slice()))
+                    .unwrap_or_default(),
+            )
+            .collect();
+        let mut errors: Vec<String> = known_error_selector(selector.as_slice())
+            .map(String::from)
+            .into_iter()
+            .chain(abis.error(None, selector).map(|e| e.signature()))
+            .collect();
+        dedup(&mut errors);
+        // Errors in the database are stored alongside functions
+        functions.retain(|f| !errors.contains(f));
+        dedup(&mut functions);
+
+        Self {
+            query: selector.to_string(),
+            selector: Some(selector),
+            functions,
+            errors,
+            ..Default::default()
+        }
+    }
+
+    /// Events with a topic0
+    pub fn topic(topic: B256, abis: &AbiRegistry, db: Option<&SignatureDb>) -> Self {
+        let mut events: Vec<String> = known_event_signature(&topic)
+            .map(String::from)
+            .into_iter()
+            .chain(abis.event(None, topic).map(|e| e.signature()))
+            .chain(db.map(|db| db.events(&topic)).unwrap_or_default())
+            .collect();
+        dedup(&mut events);
+
+        Self {
+            query: topic.to_string(),
+            topic: Some(topic),
+            events,
+            ..Default::default()
+        }
+    }
+
+    /// The selector and topic of a text signature such as
+    /// `transfer(address to, uint256 amount)`, and what else shares them
+    pub fn signature(text: &str, abis: &AbiRegistry, db: Option<&SignatureDb>) -> Result<Self> {
+        let text = text.trim();
+        let text = ["function ", "event ", "error "]
+            .iter()
+            .find_map(|keyword| text.strip_prefix(keyword))
+            .unwrap_or(text);
+        let function =
+            Function::parse(text).map_err(|e| anyhow!("Invalid signature {text}: {e}"))?;
+        let signature = function.signature();
+
+        let by_selector = Self::selector(function.selector(), abis, db);
+        let by_topic = Self::topic(keccak256(&signature), abis, db);
+        Ok(Self {
+            query: signature,
+            topic: by_topic.topic,
+            events: by_topic.events,
+            ..by_selector
+        })
+    }
+}
+
+/// Drop repeated entries, keeping the first of each
+fn dedup(signatures: &mut Vec<String>) {
+    let mut seen = BTreeSet::new();
+    signatures.retain(|sig| seen.insert(sig.clone()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, U256};
+    use alloy::sol_types::SolValue;
+
+    fn temp_db(name: &str) -> SignatureDb {
+        let path =
+            std::env::temp_dir().join(format!("tbex-sigdb-{name}-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        SignatureDb::open(path)
+    }
+
+    #[test]
+    fn test_extract_signatures() {
+        let text = "0xa9059cbb,transfer(address,uint256)\n\
+                    swap((address,uint256)[],bytes) ; foo(notatype)\n\
+                    {\"text_signature\": \"Transfer(address,address,uint256)\"}";
+        assert_eq!(
+            extract_signatures(text),
+            [
+                "transfer(address,uint256)",
+                "swap((address,uint256)[],bytes)",
+                "Transfer(address,address,uint256)",
+            ]
+        );
+        assert_eq!(extract_signatures("pause()"), ["pause()"]);
+        assert!(extract_signatures("not a signature").is_empty());
+    }
+
+    #[test]
+    fn test_import_and_lookup() {
+        let db = temp_db("lookup");
+        let stats = db
+            .import("transfer(address,uint256)\nTransfer(address,address,uint256)\npause()\n")
+            .unwrap();
+        assert_eq!(stats, ImportStats { added: 3, total: 3 });
+
+        // Re-importing is a no-op
+        let stats = db.import("pause()").unwrap();
+        assert_eq!(stats, ImportStats { added: 0, total: 3 });
+
+        assert_eq!(
+            db.functions(&[0xa9, 0x05, 0x9c, 0xbb]),
+            ["transfer(address,uint256)"]
+        );
+        let transfer_topic = keccak256("Transfer(address,address,uint256)");
+        assert_eq!(
+            db.event(&transfer_topic).as_deref(),
+            Some("Transfer(address,address,uint256)")
+        );
+        assert!(db.functions(&[0xde, 0xad, 0xbe, 0xef]).is_empty());
+        assert!(db.event(&B256::ZERO).is_none());
+
+        std::fs::remove_file(db.path()).unwrap();
+    }
+
+    #[test]
+    fn test_lookup_many_entries() {
+        let db = temp_db("many");
+        let dump: String = (0..2000).map(|i| format!("fn{i}(uint256)\n")).collect();
+        db.import(&dump).unwrap();
+
+        for i in [0, 1, 999, 1999] {
+            let sig = format!("fn{i}(uint256)");
+            let selector = &keccak256(sig.as_bytes())[..4];
+            assert_eq!(db.functions(selector), [sig]);
+        }
+
+        std::fs::remove_file(db.path()).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_function_ambiguity() {
+        // Both of these hash to selector 0xa9059cbb
+        let db = temp_db("collide");
+        db.import("transfer(address,uint256)\nmany_msg_babbage(bytes1)\n")
+            .unwrap();
+
+        let to = address!("00000000000000000000000000000000000000aa");
+        let mut input = vec![0xa9, 0x05, 0x9c, 0xbb];
+        input.extend((to, U256::from(5u64)).abi_encode_params());
+        assert_eq!(db.resolve_function(&input), ["transfer(address,uint256)"]);
+
+        // Bare selector: nothing fits, so both are returned (shortest first)
+        assert_eq!(
+            db.resolve_function(&input[..4]),
+            ["many_msg_babbage(bytes1)", "transfer(address,uint256)"]
+        );
+
+        std::fs::remove_file(db.path()).unwrap();
+    }
+
+    #[test]
+    fn test_signature_lookup() {
+        let abis = AbiRegistry::default();
+        let db = temp_db("signature-lookup");
+        db.import("transfer(address,uint256)\nTransfer(address,address,uint256)\nfoo(uint256)\n")
+            .unwrap();
+
+        // Built-in and database matches are merged without repeats
+        let lookup =
+            SignatureLookup::selector(Selector::from([0xa9, 0x05, 0x9c, 0xbb]), &abis, Some(&db));
+        assert_eq!(lookup.functions, ["transfer(address,uint256)"]);
+        assert!(lookup.errors.is_empty() && lookup.topic.is_none());
+
+        let error = Selector::from_slice(&keccak256("ERC20InvalidSender(address)")[..4]);
+        let lookup = SignatureLookup::selector(error, &abis, None);
+        assert_eq!(lookup.errors, ["ERC20InvalidSender(address)"]);
+
+        let lookup = SignatureLookup::topic(
+            keccak256("Transfer(address,address,uint256)"),
+            &abis,
+            Some(&db),
+        );
+        assert_eq!(lookup.events, ["Transfer(address,address,uint256)"]);
+
+        // Text signatures are normalized and looked up both ways
+        let lookup =
+            SignatureLookup::signature("function foo(uint256 amount) external", &abis, Some(&db))
+                .unwrap();
+        assert_eq!(lookup.query, "foo(uint256)");
+        assert_eq!(
+            lookup.selector,
+            Some(Selector::from_slice(&keccak256("foo(uint256)")[..4]))
+        );
+        assert_eq!(lookup.topic, Some(keccak256("foo(uint256)")));
+        assert_eq!(lookup.functions, ["foo(uint256)"]);
+        assert!(SignatureLookup::signature("foo(", &abis, None).is_err());
+
+        std::fs::remove_file(db.path()).unwrap();
+    }
+}
@@ -0,0 +1,308 @@
+//! Storage slot expressions for the address page's storage inspector.
+//!
+//! A slot is either a plain number (`5`, `0x1f`) or a Solidity-style mapping
+//! lookup. Mapping keys are hashed the way solc lays out storage:
+//! `keccak256(pad32(key) ++ pad32(slot))`, or `keccak256(key ++ pad32(slot))`
+//! for string keys.
+//!
+//! ```text
+//! 3[0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045]      mapping at base slot 3
+//! balances[0xd8dA…6045] @ 3                          same, with a label
+//! 7[0xabc…][0xdef…]                                  nested mapping
+//! 2["foo"]+1                                         second word of a struct value
+//! ```
+
+use alloy::eips::{BlockId, BlockNumberOrTag};
+use alloy::primitives::{keccak256, Address, B256, U256};
+use anyhow::{anyhow, bail, Context, Result};
+
+/// A mapping key inside `[...]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MappingKey {
+    /// Value types (uint, int, address, bool, bytes32), left-padded to a word
+    Word(B256),
+    /// `string` / `bytes` keys, hashed unpadded
+    Bytes(Vec<u8>),
+}
+
+/// A parsed storage slot expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotExpr {
+    base: U256,
+    keys: Vec<MappingKey>,
+    offset: U256,
+}
+
+impl SlotExpr {
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            bail!("Enter a slot number, hex slot or mapping expression");
+        }
+
+        // `name[key]... @ base` names the mapping; the base slot comes after the @
+        let (expr, labelled_base) = match input.rsplit_once('@') {
+            Some((expr, base)) if !base.contains(['[', '"']) => {
+                (expr.trim_end(), Some(parse_number(base.trim())?))
+            }
+            _ => (input, None),
+        };
+
+        let head_end = expr.find(['[', '+']).unwrap_or(expr.len());
+        let head = expr[..head_end].trim();
+        let base = match labelled_base {
+            Some(base) => {
+                if head.is_empty() || !head.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    bail!("Invalid mapping name: {head}");
+                }
+                base
+            }
+            None => parse_number(head).with_context(|| {
+                if head.chars().next().is_some_and(|c| c.is_alphabetic()) {
+                    format!("Give the base slot of {head}, e.g. {head}[key] @ 3")
+                } else {
+                    format!("Invalid slot: {head}")
+                }
+            })?,
+        };
+
+        let mut rest = &expr[head_end..];
+        let mut keys = Vec::new();
+        while let Some(after) = rest.strip_prefix('[') {
+            let (key, tail) = split_key(after)?;
+            keys.push(parse_key(key.trim())?);
+            rest = tail.trim_start();
+        }
+
+        let offset = match rest.strip_prefix('+') {
+            Some(offset) => parse_number(offset.trim())?,
+            None if rest.is_empty() => U256::ZERO,
+            None => bail!("Unexpected input: {rest}"),
+        };
+
+        Ok(Self { base, keys, offset })
+    }
+
+    /// The storage slot the expression points at
+    pub fn slot(&self) -> B256 {
+        let mut slot = B256::from(self.base);
+        for key in &self.keys {
+            let mut preimage = match key {
+                MappingKey::Word(word) => word.to_vec(),
+                MappingKey::Bytes(bytes) => bytes.clone(),
+            };
+            preimage.extend_from_slice(slot.as_slice());
+            slot = keccak256(preimage);
+        }
+        B256::from(U256::from_be_bytes(slot.0).wrapping_add(self.offset))
+    }
+}
+
+/// Split `key]rest` at the closing bracket, allowing `]` inside quoted keys
+fn split_key(input: &str) -> Result<(&str, &str)> {
+    let close = if let Some(quoted) = input.trim_start().strip_prefix('"') {
+        let start = input.len() - quoted.len();
+        let end = quoted.find('"').context("Unterminated string key")?;
+        input[start + end + 1..]
+            .find(']')
+            .map(|i| start + end + 1 + i)
+    } else {
+        input.find(']')
+    };
+    let close = close.context("Missing ] after mapping key")?;
+    Ok((&input[..close], &input[close + 1..]))
+}
+
+fn parse_key(key: &str) -> Result<MappingKey> {
+    if let Some(s) = key.strip_prefix('"').and_then(|k| k.strip_suffix('"')) {
+        return Ok(MappingKey::Bytes(s.as_bytes().to_vec()));
+    }
+    let word = match key {
+        "" => bail!("Empty mapping key"),
+        "true" => U256::from(1),
+        "false" => U256::ZERO,
+        _ => match key.strip_prefix('-') {
+            // Negative int keys are stored in two's complement
+            Some(abs) => U256::ZERO.wrapping_sub(parse_number(abs)?),
+            None => parse_number(key)?,
+        },
+    };
+    Ok(MappingKey::Word(B256::from(word)))
+}
+
+/// An indexed event argument as its topic word: value types are
+/// left-padded like mapping keys, `"string"` values are hashed
+pub(super) fn parse_topic_word(input: &str) -> Result<B256> {
+    Ok(match parse_key(input.trim())? {
+        MappingKey::Word(word) => word,
+        MappingKey::Bytes(bytes) => keccak256(bytes),
+    })
+}
+
+/// Decimal or 0x-prefixed hex number
+fn parse_number(s: &str) -> Result<U256> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) if !hex.is_empty() && hex.len() <= 64 => U256::from_str_radix(hex, 16).ok(),
+        Some(_) => None,
+        None if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) => s.parse().ok(),
+        None => None,
+    };
+    parsed.ok_or_else(|| anyhow!("Invalid number: {s}"))
+}
+
+/// Block to read state at: a number (decimal or hex) or a tag.
+/// An empty string means the latest block.
+pub fn parse_block_id(input: &str) -> Result<BlockId> {
+    let input = input.trim();
+    let tag = match input.to_lowercase().as_str() {
+        "" | "latest" => BlockNumberOrTag::Latest,
+        "pending" => BlockNumberOrTag::Pending,
+        "safe" => BlockNumberOrTag::Safe,
+        "finalized" => BlockNumberOrTag::Finalized,
+        "earliest" => BlockNumberOrTag::Earliest,
+        _ => {
+            let number = parse_number(input)
+                .ok()
+                .and_then(|n| u64::try_from(n).ok())
+                .with_context(|| format!("Invalid block: {input}"))?;
+            BlockNumberOrTag::Number(number)
+        }
+    };
+    Ok(BlockId::Number(tag))
+}
+
+/// A block id for display, with numbers in decimal
+pub fn block_label(block: BlockId) -> String {
+    match block {
+        BlockId::Number(BlockNumberOrTag::Number(number)) => number.to_string(),
+        block => block.to_string(),
+    }
+}
+
+/// A storage word read by the inspector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageRead {
+    pub expr: String,
+    pub block: String,
+    pub slot: B256,
+    pub value: B256,
+}
+
+/// The word as an address, if it looks like one: upper 12 bytes zero, and
+/// too large to be a counter or amount (values below 2^64 are left as uints)
+pub fn word_as_address(word: B256) -> Option<Address> {
+    (word[..12].iter().all(|b| *b == 0) && word[12..24].iter().any(|b| *b != 0))
+        .then(|| Address::from_slice(&word[12..]))
+}
+
+/// The word as a bool, if it is 0 or 1
+pub fn word_as_bool(word: B256) -> Option<bool> {
+    match U256::from_be_bytes(word.0) {
+        v if v.is_zero() => Some(false),
+        v if v == U256::from(1) => Some(true),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, b256};
+
+    #[test]
+    fn test_plain_slots() {
+        assert_eq!(
+            SlotExpr::parse("5").unwrap().slot(),
+            B256::from(U256::from(5))
+        );
+        assert_eq!(
+            SlotExpr::parse("0x1f").unwrap().slot(),
+            B256::from(U256::from(31))
+        );
+        assert_eq!(
+            SlotExpr::parse("3+2").unwrap().slot(),
+            B256::from(U256::from(5))
+        );
+        assert!(SlotExpr::parse("").is_err());
+        assert!(SlotExpr::parse("0xzz").is_err());
+        // A named mapping needs its base slot
+        assert!(SlotExpr::parse("balances[1]").is_err());
+    }
+
+    #[test]
+    fn test_mapping_slots() {
+        let holder = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+        let key = B256::left_padding_from(holder.as_slice());
+        let expected = keccak256([key.as_slice(), B256::from(U256::from(3)).as_slice()].concat());
+
+        let expr = SlotExpr::parse(&format!("3[{holder}]")).unwrap();
+        assert_eq!(expr.slot(), expected);
+        let labelled = SlotExpr::parse(&format!("balances[{holder}] @ 3")).unwrap();
+        assert_eq!(labelled, expr);
+
+        // Nested mappings hash outermost key first
+        let nested = SlotExpr::parse(&format!("3[{holder}][1]")).unwrap();
+        let inner = keccak256([B256::from(U256::from(1)).as_slice(), expected.as_slice()].concat());
+        assert_eq!(nested.slot(), inner);
+
+        // Struct members are offsets from the value's slot
+        let member = SlotExpr::parse(&format!("3[{holder}]+1")).unwrap();
+        assert_eq!(
+            member.slot(),
+            B256::from(U256::from_be_bytes(expected.0) + U256::from(1))
+        );
+    }
+
+    #[test]
+    fn test_mapping_key_types() {
+        let slot0 = B256::ZERO;
+        let string_key = SlotExpr::parse(r#"0["a]b"]"#).unwrap();
+        assert_eq!(
+            string_key.slot(),
+            keccak256([b"a]b".as_slice(), slot0.as_slice()].concat())
+        );
+
+        let minus_one = SlotExpr::parse("0[-1]").unwrap();
+        assert_eq!(
+            minus_one.slot(),
+            keccak256([B256::repeat_byte(0xff).as_slice(), slot0.as_slice()].concat())
+        );
+        assert_eq!(
+            SlotExpr::parse("0[true]").unwrap(),
+            SlotExpr::parse("0[1]").unwrap()
+        );
+        assert!(SlotExpr::parse("0[1").is_err());
+        assert!(SlotExpr::parse("0[]").is_err());
+    }
+
+    #[test]
+    fn test_parse_block_id() {
+        assert_eq!(parse_block_id("").unwrap(), BlockId::latest());
+        assert_eq!(parse_block_id("Finalized").unwrap(), BlockId::finalized());
+        assert_eq!(
+            parse_block_id("19000000").unwrap(),
+            BlockId::number(19_000_000)
+        );
+        assert_eq!(parse_block_id("0x10").unwrap(), BlockId::number(16));
+        assert!(parse_block_id("yesterday").is_err());
+
+        assert_eq!(block_label(BlockId::number(19_000_000)), "19000000");
+        assert_eq!(block_label(BlockId::safe()), "safe");
+    }
+
+    #[test]
+    fn test_word_decoding() {
+        let addr = b256!("000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(
+            word_as_address(addr),
+            Some(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"))
+        );
+        assert_eq!(word_as_address(B256::ZERO), None);
+        assert_eq!(word_as_address(B256::repeat_byte(1)), None);
+        assert_eq!(word_as_address(B256::from(U256::from(1))), None);
+
+        assert_eq!(word_as_bool(B256::ZERO), Some(false));
+        assert_eq!(word_as_bool(B256::from(U256::from(1))), Some(true));
+        assert_eq!(word_as_bool(addr), None);
+    }
+}
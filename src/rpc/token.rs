@@ -0,0 +1,219 @@
+//! The token page of an ERC-20 contract: its Transfer events over a block
+//! window, the accounts that moved the most of it, and balance and allowance
+//! lookups.
+
+use super::activity::ActivityEvent;
+use super::types::TokenInfo;
+use alloy::primitives::{Address, U256};
+use alloy::sol;
+use std::collections::HashMap;
+
+sol! {
+    interface IERC20 {
+        function balanceOf(address owner) external view returns (uint256);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+}
+
+/// A token scan stops once it has found this many transfers
+pub const MAX_TOKEN_TRANSFERS: usize = 5_000;
+/// Blocks in the window when no start block is given. Busy tokens move
+/// thousands of times per hour, so it's far shorter than an activity scan's.
+pub const DEFAULT_TOKEN_RANGE: u64 = 1_000;
+/// Accounts ranked as top senders, and as top receivers
+pub const TOP_ACCOUNTS: usize = 10;
+
+/// Tokens one account sent or received within the scanned blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenFlow {
+    pub account: Address,
+    pub amount: U256,
+    pub transfers: usize,
+}
+
+/// Result of a token page scan
+#[derive(Debug, Clone)]
+pub struct TokenScan {
+    pub token: Address,
+    /// Name, symbol, decimals and supply at to_block
+    pub info: TokenInfo,
+    pub from_block: u64,
+    pub to_block: u64,
+    /// Lowest block scanned; above from_block when the scan stopped at
+    /// MAX_TOKEN_TRANSFERS
+    pub scanned_from: u64,
+    /// Newest first
+    pub transfers: Vec<ActivityEvent>,
+    /// Largest amount first, without the zero address
+    pub senders: Vec<TokenFlow>,
+    pub receivers: Vec<TokenFlow>,
+    /// Amounts moved within the scanned blocks
+    pub volume: U256,
+    pub minted: U256,
+    pub burned: U256,
+}
+
+impl TokenScan {
+    /// Tally the transfers of a scan; mints and burns (from or to the zero
+    /// address) are counted apart from the top accounts
+    pub(super) fn new(
+        token: Address,
+        info: TokenInfo,
+        from_block: u64,
+        to_block: u64,
+        scanned_from: u64,
+        transfers: Vec<ActivityEvent>,
+    ) -> Self {
+        let mut volume = U256::ZERO;
+        let mut minted = U256::ZERO;
+        let mut burned = U256::ZERO;
+        let mut sent: HashMap<Address, TokenFlow> = HashMap::new();
+        let mut received: HashMap<Address, TokenFlow> = HashMap::new();
+        for transfer in &transfers {
+            let amount = transfer.amount.unwrap_or_default();
+            volume = volume.saturating_add(amount);
+            if transfer.from == Address::ZERO {
+                minted = minted.saturating_add(amount);
+            } else {
+                add_flow(&mut sent, transfer.from, amount);
+            }
+            if transfer.to == Address::ZERO {
+                burned = burned.saturating_add(amount);
+            } else {
+                add_flow(&mut received, transfer.to, amount);
+            }
+        }
+
+        Self {
+            token,
+            info,
+            from_block,
+            to_block,
+            scanned_from,
+            transfers,
+            senders: top_flows(sent),
+            receivers: top_flows(received),
+            volume,
+            minted,
+            burned,
+        }
+    }
+
+    pub fn truncated(&self) -> bool {
+        self.scanned_from > self.from_block
+    }
+}
+
+fn add_flow(flows: &mut HashMap<Address, TokenFlow>, account: Address, amount: U256) {
+    let flow = flows.entry(account).or_insert(TokenFlow {
+        account,
+        amount: U256::ZERO,
+        transfers: 0,
+    });
+    flow.amount = flow.amount.saturating_add(amount);
+    flow.transfers += 1;
+}
+
+/// The TOP_ACCOUNTS largest flows, ties broken by address so the order is stable
+fn top_flows(flows: HashMap<Address, TokenFlow>) -> Vec<TokenFlow> {
+    let mut flows: Vec<TokenFlow> = flows.into_values().collect();
+    flows.sort_by(|a, b| b.amount.cmp(&a.amount).then(a.account.cmp(&b.account)));
+    flows.truncate(TOP_ACCOUNTS);
+    flows
+}
+
+/// A balance lookup on the token page, with an allowance if a spender was given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenLookup {
+    pub holder: Address,
+    pub spender: Option<Address>,
+    pub block: String,
+    pub balance: U256,
+    pub allowance: Option<U256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::ActivityKind;
+    use alloy::primitives::{address, B256};
+
+    const TOKEN: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+
+    fn transfer(from: Address, to: Address, amount: u64, block: u64) -> ActivityEvent {
+        ActivityEvent {
+            block,
+            tx_hash: B256::repeat_byte(block as u8),
+            log_index: 0,
+            token: TOKEN,
+            kind: ActivityKind::Transfer,
+            from,
+            to,
+            amount: Some(U256::from(amount)),
+            token_id: None,
+        }
+    }
+
+    #[test]
+    fn test_token_scan_tallies_flows() {
+        let (a, b, c) = (
+            Address::repeat_byte(0xaa),
+            Address::repeat_byte(0xbb),
+            Address::repeat_byte(0xcc),
+        );
+        let transfers = vec![
+            transfer(c, Address::ZERO, 5, 14),
+            transfer(b, c, 40, 13),
+            transfer(a, c, 30, 12),
+            transfer(a, b, 20, 11),
+            transfer(Address::ZERO, a, 100, 10),
+        ];
+        let info = TokenInfo {
+            name: None,
+            symbol: Some("USDC".to_string()),
+            decimals: Some(6),
+            total_supply: None,
+        };
+        let scan = TokenScan::new(TOKEN, info, 10, 20, 10, transfers);
+
+        assert_eq!(scan.volume, U256::from(195));
+        assert_eq!(scan.minted, U256::from(100));
+        assert_eq!(scan.burned, U256::from(5));
+        assert!(!scan.truncated());
+
+        // a sent 50 in two transfers; the zero address isn't ranked
+        let senders: Vec<(Address, u64, usize)> = scan
+            .senders
+            .iter()
+            .map(|f| (f.account, f.amount.to(), f.transfers))
+            .collect();
+        assert_eq!(senders, [(a, 50, 2), (b, 40, 1), (c, 5, 1)]);
+        let receivers: Vec<(Address, u64)> = scan
+            .receivers
+            .iter()
+            .map(|f| (f.account, f.amount.to()))
+            .collect();
+        assert_eq!(receivers, [(a, 100), (c, 70), (b, 20)]);
+    }
+
+    #[test]
+    fn test_top_flows_are_capped() {
+        let transfers: Vec<ActivityEvent> = (1..=TOP_ACCOUNTS as u8 + 5)
+            .map(|i| transfer(Address::repeat_byte(i), TOKEN, i as u64, 1))
+            .collect();
+        let info = TokenInfo {
+            name: None,
+            symbol: None,
+            decimals: None,
+            total_supply: None,
+        };
+        let scan = TokenScan::new(TOKEN, info, 1, 1, 1, transfers);
+        assert_eq!(scan.senders.len(), TOP_ACCOUNTS);
+        assert_eq!(
+            scan.senders[0].account,
+            Address::repeat_byte(TOP_ACCOUNTS as u8 + 5)
+        );
+        assert_eq!(scan.receivers.len(), 1);
+        assert_eq!(scan.receivers[0].transfers, TOP_ACCOUNTS + 5);
+    }
+}
@@ -0,0 +1,450 @@
+//! Wire formats of the geth `callTracer` and Parity `trace_transaction` APIs,
+//! converted into the common `CallTrace` tree, and of the `prestateTracer`
+//! diff used for a transaction's state changes.
+
+use super::helper::decode_revert_reason;
+use super::types::{AccountStateDiff, CallTrace, StorageChange};
+use alloy::primitives::{Address, Bytes, B256, U256, U64};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// A frame from `debug_traceTransaction` with `{"tracer": "callTracer"}`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GethCallFrame {
+    #[serde(rename = "type")]
+    call_type: String,
+    from: Address,
+    to: Option<Address>,
+    value: Option<U256>,
+    #[serde(default)]
+    gas: U64,
+    #[serde(default)]
+    gas_used: U64,
+    #[serde(default)]
+    input: Bytes,
+    output: Option<Bytes>,
+    error: Option<String>,
+    revert_reason: Option<String>,
+    #[serde(default)]
+    calls: Vec<GethCallFrame>,
+}
+
+impl From<GethCallFrame> for CallTrace {
+    fn from(frame: GethCallFrame) -> Self {
+        let output = frame.output.unwrap_or_default();
+        let revert_reason = frame.error.as_ref().and_then(|_| {
+            frame
+                .revert_reason
+                .clone()
+                .or_else(|| decode_revert_reason(&output))
+        });
+
+        Self {
+            call_type: frame.call_type.to_uppercase(),
+            from: format!("{:?}", frame.from),
+            to: frame.to.map(|a| format!("{a:?}")),
+            value: frame.value.unwrap_or_default(),
+            gas: frame.gas.to(),
+            gas_used: frame.gas_used.to(),
+            input: frame.input,
+            output,
+            method: None,
+            error: frame.error,
+            revert_reason,
+            calls: frame.calls.into_iter().map(CallTrace::from).collect(),
+        }
+    }
+}
+
+/// One entry of the flat list returned by `trace_transaction`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ParityTrace {
+    #[serde(rename = "type")]
+    kind: String,
+    action: ParityAction,
+    result: Option<ParityResult>,
+    error: Option<String>,
+    #[serde(default)]
+    trace_address: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParityAction {
+    call_type: Option<String>,
+    from: Option<Address>,
+    to: Option<Address>,
+    value: Option<U256>,
+    gas: Option<U64>,
+    input: Option<Bytes>,
+    init: Option<Bytes>,
+    // selfdestruct
+    address: Option<Address>,
+    refund_address: Option<Address>,
+    balance: Option<U256>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParityResult {
+    gas_used: Option<U64>,
+    output: Option<Bytes>,
+    address: Option<Address>, // Created contract
+}
+
+impl ParityTrace {
+    fn into_call_trace(self) -> CallTrace {
+        let action = self.action;
+        let result = self.result;
+        let (call_type, from, to, value) = match self.kind.as_str() {
+            "suicide" | "selfdestruct" => (
+                "SELFDESTRUCT".to_string(),
+                action.address,
+                action.refund_address,
+                action.balance,
+            ),
+            "create" => (
+                "CREATE".to_string(),
+                action.from,
+                result.as_ref().and_then(|r| r.address),
+                action.value,
+            ),
+            _ => (
+                action
+                    .call_type
+                    .unwrap_or_else(|| self.kind.clone())
+                    .to_uppercase(),
+                action.from,
+                action.to,
+                action.value,
+            ),
+        };
+
+        CallTrace {
+            call_type,
+            from: from.map(|a| format!("{a:?}")).unwrap_or_default(),
+            to: to.map(|a| format!("{a:?}")),
+            value: value.unwrap_or_default(),
+            gas: action.gas.unwrap_or_default().to(),
+            gas_used: result
+                .as_ref()
+                .and_then(|r| r.gas_used)
+                .unwrap_or_default()
+                .to(),
+            input: action.input.or(action.init).unwrap_or_default(),
+            output: result.and_then(|r| r.output).unwrap_or_default(),
+            method: None,
+            error: self.error,
+            revert_reason: None,
+            calls: Vec::new(),
+        }
+    }
+}
+
+/// Rebuild the call tree from Parity traces (depth-first, addressed by `traceAddress`)
+pub(super) fn parity_call_tree(traces: Vec<ParityTrace>) -> Option<CallTrace> {
+    let mut traces = traces.into_iter().filter(|t| t.kind != "reward");
+    let root = traces.next()?;
+    if !root.trace_address.is_empty() {
+        return None;
+    }
+
+    let mut root = root.into_call_trace();
+    for trace in traces {
+        let Some((_, parents)) = trace.trace_address.split_last() else {
+            continue;
+        };
+        let mut node = Some(&mut root);
+        for &i in parents {
+            node = node.and_then(|n| n.calls.get_mut(i));
+        }
+        if let Some(parent) = node {
+            parent.calls.push(trace.into_call_trace());
+        }
+    }
+    Some(root)
+}
+
+/// Result of `debug_traceTransaction` with `prestateTracer` in diff mode.
+///
+/// `pre` holds the modified accounts before the tx (only the storage slots
+/// that were modified), `post` only the fields that changed. Zero values are
+/// omitted on both sides, so a slot missing from `post` was cleared.
+#[derive(Debug, Deserialize)]
+pub(super) struct PrestateDiff {
+    #[serde(default)]
+    pre: BTreeMap<Address, PrestateAccount>,
+    #[serde(default)]
+    post: BTreeMap<Address, PrestateAccount>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PrestateAccount {
+    balance: Option<U256>,
+    nonce: Option<Quantity>,
+    code: Option<Bytes>,
+    #[serde(default)]
+    storage: BTreeMap<B256, B256>,
+}
+
+/// geth reports nonces as JSON numbers, some clients as hex quantities
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+enum Quantity {
+    Number(u64),
+    Hex(U64),
+}
+
+impl Quantity {
+    fn to_u64(self) -> u64 {
+        match self {
+            Quantity::Number(n) => n,
+            Quantity::Hex(n) => n.to(),
+        }
+    }
+}
+
+impl PrestateDiff {
+    /// Per-account before/after values, in address order
+    pub(super) fn into_account_diffs(self) -> Vec<AccountStateDiff> {
+        let (mut pre, mut post) = (self.pre, self.post);
+        let mut addresses: Vec<Address> = pre.keys().chain(post.keys()).copied().collect();
+        addresses.sort();
+        addresses.dedup();
+
+        addresses
+            .into_iter()
+            .filter_map(|address| {
+                let before = pre.remove(&address).unwrap_or_default();
+                let after = post.remove(&address);
+                account_diff(address, before, after)
+            })
+            .collect()
+    }
+}
+
+fn account_diff(
+    address: Address,
+    before: PrestateAccount,
+    after: Option<PrestateAccount>,
+) -> Option<AccountStateDiff> {
+    let mut diff = AccountStateDiff {
+        address: format!("{address:?}"),
+        ..Default::default()
+    };
+
+    // Unchanged accounts are dropped from `pre`, so one missing from `post`
+    // was deleted (selfdestruct or EIP-161 empty account cleanup)
+    let deleted = after.is_none();
+    let after = after.unwrap_or_default();
+
+    let balance_before = before.balance.unwrap_or_default();
+    let balance_after = after.balance.or(deleted.then_some(U256::ZERO));
+    if let Some(balance_after) = balance_after.filter(|b| *b != balance_before) {
+        diff.balance_before = Some(balance_before);
+        diff.balance_after = Some(balance_after);
+    }
+
+    let nonce_before = before.nonce.map_or(0, Quantity::to_u64);
+    let nonce_after = after.nonce.map(Quantity::to_u64);
+    if let Some(nonce_after) = nonce_after.filter(|n| *n != nonce_before) {
+        diff.nonce_before = Some(nonce_before);
+        diff.nonce_after = Some(nonce_after);
+    }
+
+    let code_before = before.code.unwrap_or_default();
+    let code_after = after.code.or_else(|| deleted.then(Bytes::new));
+    if let Some(code_after) = code_after.filter(|code| *code != code_before) {
+        diff.code_size_before = Some(code_before.len());
+        diff.code_size_after = Some(code_after.len());
+    }
+
+    let mut slots: Vec<B256> = before
+        .storage
+        .keys()
+        .chain(after.storage.keys())
+        .copied()
+        .collect();
+    slots.sort();
+    slots.dedup();
+    diff.storage = slots
+        .into_iter()
+        .map(|slot| StorageChange {
+            slot,
+            before: before.storage.get(&slot).copied().unwrap_or_default(),
+            after: after.storage.get(&slot).copied().unwrap_or_default(),
+        })
+        .filter(|change| change.before != change.after)
+        .collect();
+
+    let changed = diff.balance_after.is_some()
+        || diff.nonce_after.is_some()
+        || diff.code_size_after.is_some()
+        || !diff.storage.is_empty();
+    changed.then_some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geth_call_frame() {
+        let json = r#"{
+            "type": "CALL",
+            "from": "0x1111111111111111111111111111111111111111",
+            "to": "0x2222222222222222222222222222222222222222",
+            "value": "0xde0b6b3a7640000",
+            "gas": "0x5208",
+            "gasUsed": "0x5000",
+            "input": "0xa9059cbb",
+            "error": "execution reverted",
+            "revertReason": "not allowed",
+            "calls": [{
+                "type": "DELEGATECALL",
+                "from": "0x2222222222222222222222222222222222222222",
+                "to": "0x3333333333333333333333333333333333333333",
+                "gas": "0x100",
+                "gasUsed": "0x10",
+                "input": "0x"
+            }]
+        }"#;
+        let frame: GethCallFrame = serde_json::from_str(json).unwrap();
+        let trace = CallTrace::from(frame);
+
+        assert_eq!(trace.call_type, "CALL");
+        assert_eq!(trace.value, U256::from(10u64).pow(U256::from(18)));
+        assert_eq!(trace.gas, 21000);
+        assert_eq!(trace.gas_used, 0x5000);
+        assert_eq!(trace.revert_reason.as_deref(), Some("not allowed"));
+        assert_eq!(trace.calls.len(), 1);
+        assert_eq!(trace.calls[0].call_type, "DELEGATECALL");
+        assert!(trace.calls[0].value.is_zero());
+        assert_eq!(trace.call_count(), 2);
+    }
+
+    #[test]
+    fn test_parity_call_tree() {
+        let json = r#"[
+            {"type": "call", "traceAddress": [], "subtraces": 2,
+             "action": {"callType": "call", "from": "0x1111111111111111111111111111111111111111",
+                        "to": "0x2222222222222222222222222222222222222222",
+                        "value": "0x0", "gas": "0x1000", "input": "0x"},
+             "result": {"gasUsed": "0x500", "output": "0x"}},
+            {"type": "call", "traceAddress": [0], "subtraces": 1,
+             "action": {"callType": "staticcall", "from": "0x2222222222222222222222222222222222222222",
+                        "to": "0x3333333333333333333333333333333333333333",
+                        "value": "0x0", "gas": "0x800", "input": "0x"},
+             "result": {"gasUsed": "0x100", "output": "0x"}},
+            {"type": "create", "traceAddress": [0, 0], "subtraces": 0,
+             "action": {"from": "0x3333333333333333333333333333333333333333",
+                        "value": "0x0", "gas": "0x400", "init": "0x6000"},
+             "result": {"gasUsed": "0x50", "code": "0x",
+                        "address": "0x4444444444444444444444444444444444444444"}},
+            {"type": "call", "traceAddress": [1], "subtraces": 0, "error": "Reverted",
+             "action": {"callType": "call", "from": "0x2222222222222222222222222222222222222222",
+                        "to": "0x5555555555555555555555555555555555555555",
+                        "value": "0x1", "gas": "0x100", "input": "0x"},
+             "result": null}
+        ]"#;
+        let traces: Vec<ParityTrace> = serde_json::from_str(json).unwrap();
+        let root = parity_call_tree(traces).unwrap();
+
+        assert_eq!(root.call_count(), 4);
+        assert_eq!(root.calls.len(), 2);
+        assert_eq!(root.calls[0].call_type, "STATICCALL");
+        let create = &root.calls[0].calls[0];
+        assert_eq!(create.call_type, "CREATE");
+        assert_eq!(
+            create.to.as_deref(),
+            Some("0x4444444444444444444444444444444444444444")
+        );
+        assert_eq!(root.calls[1].error.as_deref(), Some("Reverted"));
+        assert_eq!(root.calls[1].value, U256::from(1));
+    }
+
+    #[test]
+    fn test_parity_call_tree_empty() {
+        assert!(parity_call_tree(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_prestate_diff() {
+        let json = r#"{
+            "pre": {
+                "0x1111111111111111111111111111111111111111": {
+                    "balance": "0xde0b6b3a7640000", "nonce": 5
+                },
+                "0x2222222222222222222222222222222222222222": {
+                    "balance": "0x0", "code": "0x6080",
+                    "storage": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000001": "0x0000000000000000000000000000000000000000000000000000000000000064",
+                        "0x0000000000000000000000000000000000000000000000000000000000000002": "0x0000000000000000000000000000000000000000000000000000000000000007"
+                    }
+                }
+            },
+            "post": {
+                "0x1111111111111111111111111111111111111111": {
+                    "balance": "0xb1a2bc2ec50000", "nonce": 6
+                },
+                "0x2222222222222222222222222222222222222222": {
+                    "storage": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000001": "0x00000000000000000000000000000000000000000000000000000000000000c8",
+                        "0x0000000000000000000000000000000000000000000000000000000000000003": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                    }
+                },
+                "0x4444444444444444444444444444444444444444": {
+                    "balance": "0x0", "nonce": "0x1", "code": "0x60806040"
+                }
+            }
+        }"#;
+        let diff: PrestateDiff = serde_json::from_str(json).unwrap();
+        let accounts = diff.into_account_diffs();
+
+        assert_eq!(accounts.len(), 3);
+
+        let sender = &accounts[0];
+        assert_eq!(sender.address, "0x1111111111111111111111111111111111111111");
+        assert_eq!(sender.nonce_before, Some(5));
+        assert_eq!(sender.nonce_after, Some(6));
+        assert!(sender.balance_after.unwrap() < sender.balance_before.unwrap());
+        assert!(sender.code_size_after.is_none());
+
+        // Slot 1 updated, slot 2 cleared, slot 3 newly set
+        let contract = &accounts[1];
+        assert!(contract.balance_after.is_none());
+        assert_eq!(contract.storage.len(), 3);
+        assert_eq!(contract.storage[0].before, B256::with_last_byte(0x64));
+        assert_eq!(contract.storage[0].after, B256::with_last_byte(0xc8));
+        assert_eq!(contract.storage[1].after, B256::ZERO);
+        assert_eq!(contract.storage[2].before, B256::ZERO);
+        assert_eq!(contract.storage[2].after, B256::with_last_byte(1));
+
+        // Created contract
+        let created = &accounts[2];
+        assert_eq!(created.nonce_before, Some(0));
+        assert_eq!(created.nonce_after, Some(1));
+        assert_eq!(created.code_size_before, Some(0));
+        assert_eq!(created.code_size_after, Some(4));
+        assert!(created.balance_after.is_none());
+    }
+
+    #[test]
+    fn test_prestate_diff_selfdestruct() {
+        let json = r#"{
+            "pre": {
+                "0x2222222222222222222222222222222222222222": { "balance": "0x5", "code": "0x6080" }
+            },
+            "post": {}
+        }"#;
+        let diff: PrestateDiff = serde_json::from_str(json).unwrap();
+        let accounts = diff.into_account_diffs();
+
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].balance_after, Some(U256::ZERO));
+        assert_eq!(accounts[0].code_size_before, Some(2));
+        assert_eq!(accounts[0].code_size_after, Some(0));
+    }
+}
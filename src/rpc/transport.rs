@@ -0,0 +1,337 @@
+use super::error::{parse_retry_after, HttpStatusError};
+use alloy::rpc::json_rpc::{RequestPacket, ResponsePacket};
+use alloy::transports::http::reqwest::{header::RETRY_AFTER, Client, Url};
+use alloy::transports::utils::guess_local_url;
+use alloy::transports::{TransportError, TransportErrorKind, TransportFut};
+use anyhow::{anyhow, Context, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+use tokio::sync::Semaphore;
+use tower::{Layer, Service};
+
+/// HTTP requests a client has in flight at once; the rest queue until one
+/// finishes, so a burst of lookups doesn't trip the endpoint's rate limit
+pub const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// How tbex talks to the node, picked from the RPC URL scheme
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RpcTransport {
+    /// http:// or https://
+    Http(Url),
+    /// ws:// or wss://
+    Ws(String),
+    /// Path to a geth/reth IPC socket (optionally prefixed with file:// or ipc://)
+    Ipc(PathBuf),
+}
+
+impl RpcTransport {
+    /// Parse an RPC URL or IPC socket path
+    pub fn parse(rpc_url: &str) -> Result<Self> {
+        let trimmed = rpc_url.trim();
+        let lower = trimmed.to_lowercase();
+
+        if lower.starts_with("http://") || lower.starts_with("https://") {
+            let url = trimmed.parse().context("Invalid RPC URL")?;
+            return Ok(Self::Http(url));
+        }
+
+        if lower.starts_with("ws://") || lower.starts_with("wss://") {
+            trimmed
+                .parse::<Url>()
+                .context("Invalid WebSocket RPC URL")?;
+            return Ok(Self::Ws(trimmed.to_string()));
+        }
+
+        let path = trimmed
+            .strip_prefix("file://")
+            .or_else(|| trimmed.strip_prefix("ipc://"))
+            .unwrap_or(trimmed);
+
+        if !path.is_empty() && !path.contains("://") {
+            let looks_like_path = path.starts_with('/')
+                || path.starts_with("./")
+                || path.starts_with("~/")
+                || path.ends_with(".ipc")
+                || path.starts_with(r"\\.\pipe\");
+            if looks_like_path {
+                return Ok(Self::Ipc(expand_home(path)));
+            }
+        }
+
+        Err(anyhow!(
+            "Unsupported RPC URL: {trimmed} (expected http(s)://, ws(s):// or an IPC socket path)"
+        ))
+    }
+
+    /// Whether the transport supports eth_subscribe push notifications
+    pub fn supports_subscriptions(&self) -> bool {
+        !matches!(self, Self::Http(_))
+    }
+
+    /// Whether the node runs on this machine (IPC or a loopback host), as
+    /// forks and dev chains do
+    pub fn is_local(&self) -> bool {
+        match self {
+            Self::Http(url) => guess_local_url(url),
+            Self::Ws(url) => guess_local_url(url),
+            Self::Ipc(_) => true,
+        }
+    }
+
+    /// Short transport name for display
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Http(_) => "HTTP",
+            Self::Ws(_) => "WebSocket",
+            Self::Ipc(_) => "IPC",
+        }
+    }
+}
+
+/// HTTP transport like alloy's, except failed responses keep their status
+/// and Retry-After header for `RpcError` to classify
+#[derive(Debug, Clone)]
+pub(super) struct HttpTransport {
+    client: Client,
+    url: Url,
+}
+
+impl HttpTransport {
+    pub(super) fn new(url: Url) -> Self {
+        Self {
+            client: Client::new(),
+            url,
+        }
+    }
+}
+
+impl Service<RequestPacket> for HttpTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move {
+            let response = this
+                .client
+                .post(this.url)
+                .json(&request)
+                .headers(request.headers())
+                .send()
+                .await
+                .map_err(TransportErrorKind::custom)?;
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after);
+            let body = response.bytes().await.map_err(TransportErrorKind::custom)?;
+
+            if !status.is_success() {
+                return Err(TransportErrorKind::custom(HttpStatusError {
+                    status: status.as_u16(),
+                    retry_after,
+                    body: String::from_utf8_lossy(&body).into_owned(),
+                }));
+            }
+            serde_json::from_slice(&body)
+                .map_err(|err| TransportError::deser_err(err, String::from_utf8_lossy(&body)))
+        })
+    }
+}
+
+/// Transport layer that holds back requests past a concurrency limit
+#[derive(Debug, Clone)]
+pub(super) struct ConcurrencyLimit {
+    permits: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimit {
+    pub(super) fn new(max: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max)),
+        }
+    }
+}
+
+impl<S> Layer<S> for ConcurrencyLimit {
+    type Service = ConcurrencyLimited<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConcurrencyLimited {
+            inner,
+            permits: self.permits.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ConcurrencyLimited<S> {
+    inner: S,
+    permits: Arc<Semaphore>,
+}
+
+impl<S> Service<RequestPacket> for ConcurrencyLimited<S>
+where
+    S: Service<RequestPacket, Response = ResponsePacket, Error = TransportError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let permits = self.permits.clone();
+        let mut inner = self.inner.clone();
+        Box::pin(async move {
+            let _permit = permits
+                .acquire_owned()
+                .await
+                .map_err(TransportErrorKind::custom)?;
+            inner.call(request).await
+        })
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http() {
+        assert!(matches!(
+            RpcTransport::parse("https://eth.llamarpc.com"),
+            Ok(RpcTransport::Http(_))
+        ));
+        assert!(matches!(
+            RpcTransport::parse("http://localhost:8545"),
+            Ok(RpcTransport::Http(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_ws() {
+        assert_eq!(
+            RpcTransport::parse("ws://localhost:8546").unwrap(),
+            RpcTransport::Ws("ws://localhost:8546".to_string())
+        );
+        assert!(matches!(
+            RpcTransport::parse("WSS://mainnet.example.com/ws"),
+            Ok(RpcTransport::Ws(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_ipc() {
+        assert_eq!(
+            RpcTransport::parse("/tmp/reth.ipc").unwrap(),
+            RpcTransport::Ipc(PathBuf::from("/tmp/reth.ipc"))
+        );
+        assert_eq!(
+            RpcTransport::parse("file:///var/run/geth.ipc").unwrap(),
+            RpcTransport::Ipc(PathBuf::from("/var/run/geth.ipc"))
+        );
+        assert!(matches!(
+            RpcTransport::parse("geth.ipc"),
+            Ok(RpcTransport::Ipc(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(RpcTransport::parse("").is_err());
+        assert!(RpcTransport::parse("ftp://example.com").is_err());
+        assert!(RpcTransport::parse("eth.llamarpc.com").is_err());
+    }
+
+    #[test]
+    fn test_supports_subscriptions() {
+        let http = RpcTransport::parse("http://localhost:8545").unwrap();
+        let ws = RpcTransport::parse("ws://localhost:8546").unwrap();
+        let ipc = RpcTransport::parse("/tmp/geth.ipc").unwrap();
+        assert!(!http.supports_subscriptions());
+        assert!(ws.supports_subscriptions());
+        assert!(ipc.supports_subscriptions());
+    }
+
+    #[test]
+    fn test_is_local() {
+        let local = [
+            "http://localhost:8545",
+            "ws://127.0.0.1:8546",
+            "/tmp/geth.ipc",
+        ];
+        for url in local {
+            assert!(RpcTransport::parse(url).unwrap().is_local(), "{url}");
+        }
+        assert!(!RpcTransport::parse("https://eth.llamarpc.com")
+            .unwrap()
+            .is_local());
+    }
+
+    /// Answers every request after a delay, recording the most requests it
+    /// saw in flight at once
+    #[derive(Clone, Default)]
+    struct SlowService {
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Service<RequestPacket> for SlowService {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: RequestPacket) -> Self::Future {
+            use std::sync::atomic::Ordering;
+            let (in_flight, peak) = (self.in_flight.clone(), self.peak.clone());
+            Box::pin(async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(ResponsePacket::Batch(vec![]))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit() {
+        let inner = SlowService::default();
+        let service = ConcurrencyLimit::new(3).layer(inner.clone());
+
+        let requests = (0..10).map(|_| {
+            let mut service = service.clone();
+            tokio::spawn(async move { service.call(RequestPacket::Batch(vec![])).await })
+        });
+        for request in requests.collect::<Vec<_>>() {
+            assert!(request.await.unwrap().is_ok());
+        }
+        assert_eq!(inner.peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,194 @@
+//! Wire formats of the `txpool_content` and `txpool_status` APIs (geth, Erigon,
+//! Reth, Nethermind), converted into the transactions shown on the mempool
+//! screen.
+
+use super::helper::decode_function_selector;
+use super::types::TxType;
+use alloy::consensus::{Transaction as TxTrait, Typed2718};
+use alloy::network::TransactionResponse;
+use alloy::primitives::{Address, TxHash, U256, U64};
+use alloy::rpc::types::Transaction;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Transactions of one pool section, by sender, then nonce (as a decimal string)
+type PoolSection = BTreeMap<Address, BTreeMap<String, Transaction>>;
+
+/// `txpool_content`
+#[derive(Debug, Deserialize)]
+pub(super) struct TxPoolContent {
+    #[serde(default)]
+    pending: PoolSection,
+    #[serde(default)]
+    queued: PoolSection,
+}
+
+impl TxPoolContent {
+    /// All transactions, sorted by sender and then nonce
+    pub(super) fn into_txs(self) -> Vec<PoolTx> {
+        let pending = self.pending.values().flat_map(|txs| txs.values());
+        let queued = self.queued.values().flat_map(|txs| txs.values());
+        let mut txs: Vec<PoolTx> = pending
+            .map(|tx| PoolTx::from_tx(tx, false))
+            .chain(queued.map(|tx| PoolTx::from_tx(tx, true)))
+            .collect();
+        txs.sort_by_key(|tx| (tx.from, tx.nonce));
+        txs
+    }
+}
+
+/// `txpool_status`: transaction counts, cheaper than the full content
+#[derive(Debug, Deserialize)]
+pub(super) struct TxPoolStatus {
+    pub(super) pending: U64,
+    pub(super) queued: U64,
+}
+
+/// A transaction waiting in the mempool
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolTx {
+    pub hash: TxHash,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub nonce: u64,
+    pub value: U256,
+    pub gas_limit: u64,
+    pub max_fee: u128,              // Gas price for legacy transactions
+    pub priority_fee: Option<u128>, // EIP-1559 and later
+    pub tx_type: TxType,
+    pub method: Option<String>, // Function name, if the selector is known
+    pub queued: bool,           // Waiting on an earlier nonce (or not yet executable)
+}
+
+impl PoolTx {
+    pub(super) fn from_tx(tx: &Transaction, queued: bool) -> Self {
+        let input = tx.input();
+        let method = decode_function_selector(input)
+            .filter(|_| tx.to().is_some())
+            .map(|s| s.split('(').next().unwrap_or(&s).to_string());
+
+        Self {
+            hash: tx.tx_hash(),
+            from: tx.from(),
+            to: tx.to(),
+            nonce: tx.nonce(),
+            value: tx.value(),
+            gas_limit: tx.gas_limit(),
+            max_fee: TxTrait::max_fee_per_gas(tx),
+            priority_fee: TxTrait::max_priority_fee_per_gas(tx),
+            tx_type: TxType::from_type_byte(tx.ty()),
+            method,
+            queued,
+        }
+    }
+}
+
+/// A snapshot of the node's transaction pool
+#[derive(Debug, Clone, Default)]
+pub struct Mempool {
+    pub pending: u64,
+    pub queued: u64,
+    pub txs: Vec<PoolTx>, // By sender, then nonce
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trimmed from a geth txpool_content response
+    const CONTENT: &str = r#"{
+        "pending": {
+            "0x2222222222222222222222222222222222222222": {
+                "7": {
+                    "type": "0x2", "chainId": "0x1", "nonce": "0x7",
+                    "to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "gas": "0xea60", "maxFeePerGas": "0x6fc23ac00", "maxPriorityFeePerGas": "0x3b9aca00",
+                    "value": "0x0",
+                    "input": "0xa9059cbb000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000f4240",
+                    "accessList": [], "v": "0x0", "r": "0x1", "s": "0x1", "yParity": "0x0",
+                    "hash": "0xbb00000000000000000000000000000000000000000000000000000000000007",
+                    "blockHash": null, "blockNumber": null, "transactionIndex": null,
+                    "from": "0x2222222222222222222222222222222222222222"
+                }
+            },
+            "0x1111111111111111111111111111111111111111": {
+                "10": {
+                    "type": "0x0", "chainId": "0x1", "nonce": "0xa",
+                    "to": "0x3333333333333333333333333333333333333333",
+                    "gas": "0x5208", "gasPrice": "0x4a817c800", "value": "0xde0b6b3a7640000",
+                    "input": "0x", "v": "0x25", "r": "0x1", "s": "0x1",
+                    "hash": "0xaa0000000000000000000000000000000000000000000000000000000000000a",
+                    "blockHash": null, "blockNumber": null, "transactionIndex": null,
+                    "from": "0x1111111111111111111111111111111111111111"
+                },
+                "9": {
+                    "type": "0x0", "chainId": "0x1", "nonce": "0x9",
+                    "to": "0x3333333333333333333333333333333333333333",
+                    "gas": "0x5208", "gasPrice": "0x4a817c800", "value": "0x0",
+                    "input": "0x", "v": "0x25", "r": "0x1", "s": "0x1",
+                    "hash": "0xaa00000000000000000000000000000000000000000000000000000000000009",
+                    "blockHash": null, "blockNumber": null, "transactionIndex": null,
+                    "from": "0x1111111111111111111111111111111111111111"
+                }
+            }
+        },
+        "queued": {
+            "0x1111111111111111111111111111111111111111": {
+                "12": {
+                    "type": "0x0", "chainId": "0x1", "nonce": "0xc",
+                    "to": null,
+                    "gas": "0x5208", "gasPrice": "0x4a817c800", "value": "0x0",
+                    "input": "0x6080", "v": "0x25", "r": "0x1", "s": "0x1",
+                    "hash": "0xaa0000000000000000000000000000000000000000000000000000000000000c",
+                    "blockHash": null, "blockNumber": null, "transactionIndex": null,
+                    "from": "0x1111111111111111111111111111111111111111"
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_pool_content_sorted_by_sender_and_nonce() {
+        let content: TxPoolContent = serde_json::from_str(CONTENT).unwrap();
+        let txs = content.into_txs();
+
+        let order: Vec<(Address, u64, bool)> = txs
+            .iter()
+            .map(|tx| (tx.from, tx.nonce, tx.queued))
+            .collect();
+        let (a, b) = (Address::repeat_byte(0x11), Address::repeat_byte(0x22));
+        // Numeric nonce order, not the string order of the keys
+        assert_eq!(
+            order,
+            [(a, 9, false), (a, 10, false), (a, 12, true), (b, 7, false)]
+        );
+
+        let legacy = &txs[1];
+        assert_eq!(legacy.max_fee, 20_000_000_000);
+        assert_eq!(legacy.priority_fee, None);
+        assert_eq!(legacy.value, U256::from(10).pow(U256::from(18)));
+        assert!(matches!(legacy.tx_type, TxType::Legacy));
+
+        let creation = &txs[2];
+        assert_eq!(creation.to, None);
+        assert_eq!(creation.method, None);
+
+        let transfer = &txs[3];
+        assert_eq!(transfer.max_fee, 30_000_000_000);
+        assert_eq!(transfer.priority_fee, Some(1_000_000_000));
+        assert_eq!(transfer.gas_limit, 60_000);
+        assert_eq!(transfer.method.as_deref(), Some("transfer"));
+    }
+
+    #[test]
+    fn test_pool_status() {
+        let status: TxPoolStatus =
+            serde_json::from_str(r#"{"pending": "0x10", "queued": "0x3"}"#).unwrap();
+        assert_eq!(status.pending.to::<u64>(), 16);
+        assert_eq!(status.queued.to::<u64>(), 3);
+
+        // Nodes without a queue leave sections out of the content
+        let content: TxPoolContent = serde_json::from_str(r#"{"pending": {}}"#).unwrap();
+        assert!(content.into_txs().is_empty());
+    }
+}
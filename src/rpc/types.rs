@@ -1,8 +1,9 @@
 use alloy::{
     consensus::{Transaction as TxTrait, Typed2718},
     network::TransactionResponse,
-    primitives::{keccak256, Address, Bytes, U256},
+    primitives::{keccak256, Address, Bytes, Selector, B256, U256},
 };
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::helper::*;
@@ -11,7 +12,7 @@ use super::helper::*;
 // Data Types
 // ============================================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BlockInfo {
     pub number: u64,
     pub hash: String,
@@ -35,8 +36,11 @@ pub struct BlockInfo {
     pub excess_blob_gas: Option<u64>,
     // New computed fields
     pub blob_count: usize,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub total_value_transferred: U256,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub total_fees: U256,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub burnt_fees: U256,
     pub builder_tag: Option<String>,
 }
@@ -46,6 +50,11 @@ impl BlockInfo {
         let extra_data = format!("{}", block.header.extra_data);
         let extra_data_decoded = Self::try_decode_extra_data(&block.header.extra_data);
         let builder_tag = detect_builder_tag(&block.header.extra_data, block.header.beneficiary);
+        let burnt_fees = block
+            .header
+            .base_fee_per_gas
+            .map(|base_fee| U256::from(base_fee) * U256::from(block.header.gas_used))
+            .unwrap_or_default();
 
         Self {
             number: block.header.number,
@@ -72,7 +81,7 @@ impl BlockInfo {
             blob_count: 0,
             total_value_transferred: U256::ZERO,
             total_fees: U256::ZERO,
-            burnt_fees: U256::ZERO,
+            burnt_fees,
             builder_tag,
         }
     }
@@ -89,7 +98,7 @@ impl BlockInfo {
 }
 
 /// Decoded log/event
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DecodedLog {
     pub address: String,
     pub topics: Vec<String>,
@@ -98,30 +107,262 @@ pub struct DecodedLog {
     pub decoded_params: Vec<DecodedParam>, // Individual decoded parameters
 }
 
-/// A decoded event parameter
-#[derive(Debug, Clone)]
+impl DecodedLog {
+    /// Decode a log with the built-in event table: well-known events get named
+    /// parameters, anything else its topics and first data words
+    pub fn from_log(log: &alloy::rpc::types::Log) -> Self {
+        // Known event signatures
+        let transfer_sig = keccak256("Transfer(address,address,uint256)");
+        let approval_sig = keccak256("Approval(address,address,uint256)");
+        let swap_v2_sig = keccak256("Swap(address,uint256,uint256,uint256,uint256,address)");
+        let deposit_sig = keccak256("Deposit(address,uint256)");
+        let withdrawal_sig = keccak256("Withdrawal(address,uint256)");
+
+        let topics: Vec<String> = log.topics().iter().map(|t| format!("{t:?}")).collect();
+
+        let event_name = log.topics().first().and_then(decode_event_signature);
+
+        let mut decoded_params: Vec<DecodedParam> = Vec::new();
+
+        // Decode data based on event type
+        if let Some(topic0) = log.topics().first() {
+            if topic0 == &transfer_sig && log.topics().len() >= 3 {
+                // ERC-20 Transfer: from and to in topics, amount in data
+                let from = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
+                let to = format!("0x{}", hex_encode(&log.topics()[2].as_slice()[12..]));
+                let amount = if log.data().data.len() >= 32 {
+                    U256::from_be_slice(&log.data().data[..32])
+                } else {
+                    U256::ZERO
+                };
+
+                decoded_params.push(DecodedParam {
+                    name: "from".to_string(),
+                    value: from,
+                    is_address: true,
+                    ..Default::default()
+                });
+                decoded_params.push(DecodedParam {
+                    name: "to".to_string(),
+                    value: to,
+                    is_address: true,
+                    ..Default::default()
+                });
+                decoded_params.push(DecodedParam {
+                    name: "value".to_string(),
+                    value: format_u256_decimals(amount, 18),
+                    is_address: false,
+                    ..Default::default()
+                });
+            } else if topic0 == &approval_sig && log.topics().len() >= 3 {
+                // Approval: owner and spender in topics, amount in data
+                let owner = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
+                let spender = format!("0x{}", hex_encode(&log.topics()[2].as_slice()[12..]));
+                let amount = if log.data().data.len() >= 32 {
+                    U256::from_be_slice(&log.data().data[..32])
+                } else {
+                    U256::ZERO
+                };
+
+                let amount_display = if amount == U256::MAX {
+                    "unlimited".to_string()
+                } else {
+                    format_u256_decimals(amount, 18)
+                };
+
+                decoded_params.push(DecodedParam {
+                    name: "owner".to_string(),
+                    value: owner,
+                    is_address: true,
+                    ..Default::default()
+                });
+                decoded_params.push(DecodedParam {
+                    name: "spender".to_string(),
+                    value: spender,
+                    is_address: true,
+                    ..Default::default()
+                });
+                decoded_params.push(DecodedParam {
+                    name: "value".to_string(),
+                    value: amount_display,
+                    is_address: false,
+                    ..Default::default()
+                });
+            } else if topic0 == &swap_v2_sig && log.topics().len() >= 2 {
+                // Uniswap V2 Swap: sender in topic, amounts in data, to in data
+                let sender = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
+                decoded_params.push(DecodedParam {
+                    name: "sender".to_string(),
+                    value: sender,
+                    is_address: true,
+                    ..Default::default()
+                });
+
+                if log.data().data.len() >= 128 {
+                    let amount0_in = U256::from_be_slice(&log.data().data[0..32]);
+                    let amount1_in = U256::from_be_slice(&log.data().data[32..64]);
+                    let amount0_out = U256::from_be_slice(&log.data().data[64..96]);
+                    let amount1_out = U256::from_be_slice(&log.data().data[96..128]);
+
+                    decoded_params.push(DecodedParam {
+                        name: "amount0In".to_string(),
+                        value: format_u256_decimals(amount0_in, 18),
+                        is_address: false,
+                        ..Default::default()
+                    });
+                    decoded_params.push(DecodedParam {
+                        name: "amount1In".to_string(),
+                        value: format_u256_decimals(amount1_in, 18),
+                        is_address: false,
+                        ..Default::default()
+                    });
+                    decoded_params.push(DecodedParam {
+                        name: "amount0Out".to_string(),
+                        value: format_u256_decimals(amount0_out, 18),
+                        is_address: false,
+                        ..Default::default()
+                    });
+                    decoded_params.push(DecodedParam {
+                        name: "amount1Out".to_string(),
+                        value: format_u256_decimals(amount1_out, 18),
+                        is_address: false,
+                        ..Default::default()
+                    });
+                }
+                if log.data().data.len() >= 160 {
+                    let to = format!("0x{}", hex_encode(&log.data().data[140..160]));
+                    decoded_params.push(DecodedParam {
+                        name: "to".to_string(),
+                        value: to,
+                        is_address: true,
+                        ..Default::default()
+                    });
+                }
+            } else if topic0 == &deposit_sig && log.topics().len() >= 2 {
+                // WETH Deposit
+                let dst = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
+                let amount = if log.data().data.len() >= 32 {
+                    U256::from_be_slice(&log.data().data[..32])
+                } else {
+                    U256::ZERO
+                };
+                decoded_params.push(DecodedParam {
+                    name: "dst".to_string(),
+                    value: dst,
+                    is_address: true,
+                    ..Default::default()
+                });
+                decoded_params.push(DecodedParam {
+                    name: "wad".to_string(),
+                    value: format_u256_decimals(amount, 18),
+                    is_address: false,
+                    ..Default::default()
+                });
+            } else if topic0 == &withdrawal_sig && log.topics().len() >= 2 {
+                // WETH Withdrawal
+                let src = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
+                let amount = if log.data().data.len() >= 32 {
+                    U256::from_be_slice(&log.data().data[..32])
+                } else {
+                    U256::ZERO
+                };
+                decoded_params.push(DecodedParam {
+                    name: "src".to_string(),
+                    value: src,
+                    is_address: true,
+                    ..Default::default()
+                });
+                decoded_params.push(DecodedParam {
+                    name: "wad".to_string(),
+                    value: format_u256_decimals(amount, 18),
+                    is_address: false,
+                    ..Default::default()
+                });
+            } else {
+                // Generic: decode topics and data as best we can
+                // Topics after topic0 are indexed params (addresses are 32 bytes, last 20 are the address)
+                for (i, topic) in log.topics().iter().enumerate().skip(1) {
+                    // Try to interpret as address (last 20 bytes)
+                    let bytes = topic.as_slice();
+                    if bytes[..12].iter().all(|&b| b == 0) {
+                        // Looks like an address
+                        decoded_params.push(DecodedParam {
+                            name: format!("topic{i}"),
+                            value: format!("0x{}", hex_encode(&bytes[12..])),
+                            is_address: true,
+                            ..Default::default()
+                        });
+                    } else {
+                        // Treat as uint256
+                        let val = U256::from_be_slice(bytes);
+                        decoded_params.push(DecodedParam {
+                            name: format!("topic{i}"),
+                            value: format!("{val}"),
+                            is_address: false,
+                            ..Default::default()
+                        });
+                    }
+                }
+                // Decode data as uint256 chunks
+                let data = &log.data().data;
+                let num_chunks = data.len() / 32;
+                for i in 0..num_chunks.min(4) {
+                    // Max 4 data params
+                    let start = i * 32;
+                    let end = start + 32;
+                    if end <= data.len() {
+                        let val = U256::from_be_slice(&data[start..end]);
+                        decoded_params.push(DecodedParam {
+                            name: format!("data{i}"),
+                            value: format_u256_decimals(val, 18),
+                            is_address: false,
+                            ..Default::default()
+                        });
+                    }
+                }
+            }
+        }
+
+        Self {
+            address: format!("{:?}", log.address()),
+            topics,
+            data: format!("0x{}", hex_encode(log.data().data.as_ref())),
+            event_name,
+            decoded_params,
+        }
+    }
+}
+
+/// A decoded function argument or event parameter
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DecodedParam {
     pub name: String,     // Parameter name like "from", "to", "value"
     pub value: String,    // Decoded value
     pub is_address: bool, // Whether this is a navigable address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>, // Solidity type, when decoded from an ABI
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<DecodedParam>, // Tuple fields / array elements
 }
 
 /// Token transfer extracted from logs
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenTransfer {
     pub token_address: String,
     pub from: String,
     pub to: String,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub amount: U256,
     pub token_symbol: Option<String>,
     pub decimals: Option<u8>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TxInfo {
     pub hash: String,
     pub from: String,
     pub to: Option<String>,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub value: U256,
     pub gas_price: Option<u128>,
     pub gas_limit: u64,
@@ -145,13 +386,65 @@ pub struct TxInfo {
     pub from_ens: Option<String>,
     pub to_ens: Option<String>,
     // New computed fields
+    #[serde(serialize_with = "serialize_opt_u256_decimal")]
     pub actual_fee: Option<U256>,
     pub decoded_method: Option<String>,
+    pub decoded_inputs: Vec<DecodedParam>, // Call arguments, when a loaded ABI matches
+    pub revert_reason: Option<String>,     // Why a failed tx reverted (replayed via eth_call)
     pub logs: Vec<DecodedLog>,
     pub token_transfers: Vec<TokenTransfer>,
+    pub state_changes: Vec<AccountStateDiff>, // Empty until traced with get_state_diff
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// How one account's state changed in a transaction.
+/// Fields that didn't change are `None`; storage lists only modified slots.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AccountStateDiff {
+    pub address: String,
+    #[serde(serialize_with = "serialize_opt_u256_decimal")]
+    pub balance_before: Option<U256>,
+    #[serde(serialize_with = "serialize_opt_u256_decimal")]
+    pub balance_after: Option<U256>,
+    pub nonce_before: Option<u64>,
+    pub nonce_after: Option<u64>,
+    pub code_size_before: Option<usize>, // Set when the code changed (e.g. contract creation)
+    pub code_size_after: Option<usize>,
+    pub storage: Vec<StorageChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StorageChange {
+    pub slot: B256,
+    pub before: B256,
+    pub after: B256,
+}
+
+/// One call frame of a transaction's execution trace
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CallTrace {
+    pub call_type: String, // CALL, DELEGATECALL, STATICCALL, CREATE, SELFDESTRUCT...
+    pub from: String,
+    pub to: Option<String>, // Created contract for CREATE frames
+    #[serde(serialize_with = "serialize_u256_decimal")]
+    pub value: U256,
+    pub gas: u64,
+    pub gas_used: u64,
+    pub input: Bytes,
+    pub output: Bytes,
+    pub method: Option<String>, // Decoded from the selector or a loaded ABI
+    pub error: Option<String>,
+    pub revert_reason: Option<String>,
+    pub calls: Vec<CallTrace>,
+}
+
+impl CallTrace {
+    /// Number of frames in this subtree, including this one
+    pub fn call_count(&self) -> usize {
+        1 + self.calls.iter().map(CallTrace::call_count).sum::<usize>()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum TxType {
     Legacy,     // Type 0
     AccessList, // Type 1 (EIP-2930)
@@ -204,234 +497,39 @@ impl TxInfo {
 
         // Decode method selector
         let decoded_method = if tx.input().len() >= 4 {
-            decode_function_selector(tx.input()).map(String::from)
+            decode_function_selector(tx.input())
         } else {
             None
         };
 
         // Process logs
         let (logs, token_transfers) = if let Some(r) = receipt {
-            let mut decoded_logs = Vec::new();
-            let mut transfers = Vec::new();
-
-            // Known event signatures
             let transfer_sig = keccak256("Transfer(address,address,uint256)");
-            let approval_sig = keccak256("Approval(address,address,uint256)");
-            let swap_v2_sig = keccak256("Swap(address,uint256,uint256,uint256,uint256,address)");
-            let deposit_sig = keccak256("Deposit(address,uint256)");
-            let withdrawal_sig = keccak256("Withdrawal(address,uint256)");
-
-            for log in r.inner.logs() {
-                let topics: Vec<String> = log.topics().iter().map(|t| format!("{t:?}")).collect();
-
-                let event_name = log
-                    .topics()
-                    .first()
-                    .and_then(|t| decode_event_signature(t))
-                    .map(String::from);
-
-                let mut decoded_params: Vec<DecodedParam> = Vec::new();
-
-                // Decode data based on event type
-                if let Some(topic0) = log.topics().first() {
-                    if topic0 == &transfer_sig && log.topics().len() >= 3 {
-                        // ERC-20 Transfer: from and to in topics, amount in data
-                        let from = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
-                        let to = format!("0x{}", hex_encode(&log.topics()[2].as_slice()[12..]));
-                        let amount = if log.data().data.len() >= 32 {
-                            U256::from_be_slice(&log.data().data[..32])
-                        } else {
-                            U256::ZERO
-                        };
-
-                        transfers.push(TokenTransfer {
-                            token_address: format!("{:?}", log.address()),
-                            from: from.clone(),
-                            to: to.clone(),
-                            amount,
-                            token_symbol: None,
-                            decimals: None,
-                        });
-
-                        decoded_params.push(DecodedParam {
-                            name: "from".to_string(),
-                            value: from,
-                            is_address: true,
-                        });
-                        decoded_params.push(DecodedParam {
-                            name: "to".to_string(),
-                            value: to,
-                            is_address: true,
-                        });
-                        decoded_params.push(DecodedParam {
-                            name: "value".to_string(),
-                            value: format_u256_decimals(amount, 18),
-                            is_address: false,
-                        });
-                    } else if topic0 == &approval_sig && log.topics().len() >= 3 {
-                        // Approval: owner and spender in topics, amount in data
-                        let owner = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
-                        let spender =
-                            format!("0x{}", hex_encode(&log.topics()[2].as_slice()[12..]));
-                        let amount = if log.data().data.len() >= 32 {
-                            U256::from_be_slice(&log.data().data[..32])
-                        } else {
-                            U256::ZERO
-                        };
-
-                        let amount_display = if amount == U256::MAX {
-                            "unlimited".to_string()
-                        } else {
-                            format_u256_decimals(amount, 18)
-                        };
-
-                        decoded_params.push(DecodedParam {
-                            name: "owner".to_string(),
-                            value: owner,
-                            is_address: true,
-                        });
-                        decoded_params.push(DecodedParam {
-                            name: "spender".to_string(),
-                            value: spender,
-                            is_address: true,
-                        });
-                        decoded_params.push(DecodedParam {
-                            name: "value".to_string(),
-                            value: amount_display,
-                            is_address: false,
-                        });
-                    } else if topic0 == &swap_v2_sig && log.topics().len() >= 2 {
-                        // Uniswap V2 Swap: sender in topic, amounts in data, to in data
-                        let sender = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
-                        decoded_params.push(DecodedParam {
-                            name: "sender".to_string(),
-                            value: sender,
-                            is_address: true,
-                        });
-
-                        if log.data().data.len() >= 128 {
-                            let amount0_in = U256::from_be_slice(&log.data().data[0..32]);
-                            let amount1_in = U256::from_be_slice(&log.data().data[32..64]);
-                            let amount0_out = U256::from_be_slice(&log.data().data[64..96]);
-                            let amount1_out = U256::from_be_slice(&log.data().data[96..128]);
-
-                            decoded_params.push(DecodedParam {
-                                name: "amount0In".to_string(),
-                                value: format_u256_decimals(amount0_in, 18),
-                                is_address: false,
-                            });
-                            decoded_params.push(DecodedParam {
-                                name: "amount1In".to_string(),
-                                value: format_u256_decimals(amount1_in, 18),
-                                is_address: false,
-                            });
-                            decoded_params.push(DecodedParam {
-                                name: "amount0Out".to_string(),
-                                value: format_u256_decimals(amount0_out, 18),
-                                is_address: false,
-                            });
-                            decoded_params.push(DecodedParam {
-                                name: "amount1Out".to_string(),
-                                value: format_u256_decimals(amount1_out, 18),
-                                is_address: false,
-                            });
-                        }
-                        if log.data().data.len() >= 160 {
-                            let to = format!("0x{}", hex_encode(&log.data().data[140..160]));
-                            decoded_params.push(DecodedParam {
-                                name: "to".to_string(),
-                                value: to,
-                                is_address: true,
-                            });
-                        }
-                    } else if topic0 == &deposit_sig && log.topics().len() >= 2 {
-                        // WETH Deposit
-                        let dst = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
-                        let amount = if log.data().data.len() >= 32 {
-                            U256::from_be_slice(&log.data().data[..32])
-                        } else {
-                            U256::ZERO
-                        };
-                        decoded_params.push(DecodedParam {
-                            name: "dst".to_string(),
-                            value: dst,
-                            is_address: true,
-                        });
-                        decoded_params.push(DecodedParam {
-                            name: "wad".to_string(),
-                            value: format_u256_decimals(amount, 18),
-                            is_address: false,
-                        });
-                    } else if topic0 == &withdrawal_sig && log.topics().len() >= 2 {
-                        // WETH Withdrawal
-                        let src = format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..]));
-                        let amount = if log.data().data.len() >= 32 {
-                            U256::from_be_slice(&log.data().data[..32])
-                        } else {
-                            U256::ZERO
-                        };
-                        decoded_params.push(DecodedParam {
-                            name: "src".to_string(),
-                            value: src,
-                            is_address: true,
-                        });
-                        decoded_params.push(DecodedParam {
-                            name: "wad".to_string(),
-                            value: format_u256_decimals(amount, 18),
-                            is_address: false,
-                        });
+            let logs = r.inner.logs().iter().map(DecodedLog::from_log).collect();
+
+            // ERC-20 Transfer: from and to in topics, amount in data
+            let transfers = r
+                .inner
+                .logs()
+                .iter()
+                .filter(|log| {
+                    log.topics().first() == Some(&transfer_sig) && log.topics().len() >= 3
+                })
+                .map(|log| TokenTransfer {
+                    token_address: format!("{:?}", log.address()),
+                    from: format!("0x{}", hex_encode(&log.topics()[1].as_slice()[12..])),
+                    to: format!("0x{}", hex_encode(&log.topics()[2].as_slice()[12..])),
+                    amount: if log.data().data.len() >= 32 {
+                        U256::from_be_slice(&log.data().data[..32])
                     } else {
-                        // Generic: decode topics and data as best we can
-                        // Topics after topic0 are indexed params (addresses are 32 bytes, last 20 are the address)
-                        for (i, topic) in log.topics().iter().enumerate().skip(1) {
-                            // Try to interpret as address (last 20 bytes)
-                            let bytes = topic.as_slice();
-                            if bytes[..12].iter().all(|&b| b == 0) {
-                                // Looks like an address
-                                decoded_params.push(DecodedParam {
-                                    name: format!("topic{i}"),
-                                    value: format!("0x{}", hex_encode(&bytes[12..])),
-                                    is_address: true,
-                                });
-                            } else {
-                                // Treat as uint256
-                                let val = U256::from_be_slice(bytes);
-                                decoded_params.push(DecodedParam {
-                                    name: format!("topic{i}"),
-                                    value: format!("{val}"),
-                                    is_address: false,
-                                });
-                            }
-                        }
-                        // Decode data as uint256 chunks
-                        let data = &log.data().data;
-                        let num_chunks = data.len() / 32;
-                        for i in 0..num_chunks.min(4) {
-                            // Max 4 data params
-                            let start = i * 32;
-                            let end = start + 32;
-                            if end <= data.len() {
-                                let val = U256::from_be_slice(&data[start..end]);
-                                decoded_params.push(DecodedParam {
-                                    name: format!("data{i}"),
-                                    value: format_u256_decimals(val, 18),
-                                    is_address: false,
-                                });
-                            }
-                        }
-                    }
-                }
-
-                decoded_logs.push(DecodedLog {
-                    address: format!("{:?}", log.address()),
-                    topics,
-                    data: format!("0x{}", hex_encode(log.data().data.as_ref())),
-                    event_name,
-                    decoded_params,
-                });
-            }
-
-            (decoded_logs, transfers)
+                        U256::ZERO
+                    },
+                    token_symbol: None,
+                    decimals: None,
+                })
+                .collect();
+
+            (logs, transfers)
         } else {
             (Vec::new(), Vec::new())
         };
@@ -465,18 +563,22 @@ impl TxInfo {
             to_ens: None,
             actual_fee,
             decoded_method,
+            decoded_inputs: Vec::new(),
+            revert_reason: None,
             logs,
             token_transfers,
+            state_changes: Vec::new(),
         }
     }
 }
 
 /// Lightweight transaction summary for block list view
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TxSummary {
     pub hash: String,
     pub from: String,
     pub to: Option<String>,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub value: U256,
     pub gas_limit: u64,
     pub tx_type: TxType,
@@ -487,6 +589,7 @@ pub struct TxSummary {
     pub method_selector: Option<String>,
     pub decoded_method: Option<String>,
     pub blob_count: usize,
+    #[serde(serialize_with = "serialize_opt_u256_decimal")]
     pub fee_paid: Option<U256>,
 }
 
@@ -513,7 +616,7 @@ impl TxSummary {
         let decoded_method = if input.len() >= 4 {
             decode_function_selector(input).map(|s| {
                 // Extract just the function name
-                s.split('(').next().unwrap_or(s).to_string()
+                s.split('(').next().unwrap_or(&s).to_string()
             })
         } else {
             None
@@ -543,48 +646,157 @@ impl TxSummary {
 }
 
 /// Block-level statistics computed from transactions
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct BlockStats {
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub total_value_transferred: U256,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub total_fees: U256,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub burnt_fees: U256,
     pub blob_count: usize,
 }
 
 /// Token balance for a specific token
-#[derive(Debug, Clone)]
+/// An ERC-20 token whose balance is looked up on address pages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnownToken {
+    pub symbol: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    pub address: Address,
+    #[serde(default = "default_decimals")]
+    pub decimals: u8,
+}
+
+fn default_decimals() -> u8 {
+    18
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenBalance {
     pub symbol: String,
     pub name: String,
     pub address: Address,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub balance: U256,
     pub decimals: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AddressInfo {
     pub address: Address,
+    #[serde(serialize_with = "serialize_u256_decimal")]
     pub balance: U256,
     pub nonce: u64,
     pub is_contract: bool,
     pub code_size: Option<usize>,
-    pub proxy_impl: Option<Address>,
+    pub proxy: Option<ProxyInfo>,
     pub token_info: Option<TokenInfo>,
     pub ens_name: Option<String>,
     pub owner: Option<String>,
     pub token_balances: Vec<TokenBalance>,
+    /// Block the state was read at, for historical lookups (None = latest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<u64>,
+}
+
+/// Proxy pattern a contract was recognized as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyKind {
+    /// EIP-1967 implementation slot (transparent and UUPS proxies)
+    Eip1967,
+    /// EIP-1967 beacon slot; the implementation comes from the beacon
+    Eip1967Beacon,
+    /// Pre-EIP-1967 OpenZeppelin (zeppelinos) slots
+    OpenZeppelinLegacy,
+    /// EIP-1822 UUPS `PROXIABLE` slot
+    Eip1822,
+    /// EIP-1167 minimal proxy (clone)
+    MinimalProxy,
+    /// Safe proxy delegating to a singleton
+    Safe,
+    /// EIP-2535 diamond with facets
+    Diamond,
 }
 
-#[derive(Debug, Clone)]
+impl ProxyKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ProxyKind::Eip1967 => "EIP-1967",
+            ProxyKind::Eip1967Beacon => "EIP-1967 Beacon",
+            ProxyKind::OpenZeppelinLegacy => "OpenZeppelin (legacy)",
+            ProxyKind::Eip1822 => "EIP-1822 UUPS",
+            ProxyKind::MinimalProxy => "EIP-1167 Minimal",
+            ProxyKind::Safe => "Safe",
+            ProxyKind::Diamond => "Diamond (EIP-2535)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProxyInfo {
+    pub kind: ProxyKind,
+    pub implementation: Option<Address>, // None for diamonds, which use facets
+    pub admin: Option<Address>,
+    pub beacon: Option<Address>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub facets: Vec<Facet>,
+}
+
+impl ProxyInfo {
+    pub fn new(kind: ProxyKind, implementation: Option<Address>) -> Self {
+        Self {
+            kind,
+            implementation,
+            admin: None,
+            beacon: None,
+            facets: Vec::new(),
+        }
+    }
+
+    /// Related addresses with their labels, in display order
+    pub fn links(&self) -> Vec<(&'static str, Address)> {
+        let mut links = Vec::new();
+        if let Some(implementation) = self.implementation {
+            let label = if self.kind == ProxyKind::Safe {
+                "Singleton"
+            } else {
+                "Implementation"
+            };
+            links.push((label, implementation));
+        }
+        if let Some(beacon) = self.beacon {
+            links.push(("Beacon", beacon));
+        }
+        if let Some(admin) = self.admin {
+            links.push(("Admin", admin));
+        }
+        links.extend(self.facets.iter().map(|facet| ("Facet", facet.address)));
+        links
+    }
+}
+
+/// A diamond facet and the function selectors it serves
+#[derive(Debug, Clone, Serialize)]
+pub struct Facet {
+    pub address: Address,
+    pub selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenInfo {
     pub name: Option<String>,
     pub symbol: Option<String>,
     pub decimals: Option<u8>,
+    #[serde(serialize_with = "serialize_opt_u256_decimal")]
     pub total_supply: Option<U256>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NetworkInfo {
+    pub chain_id: Option<u64>,
     pub latest_block: u64,
     pub gas_price: u128,
     pub client_version: String,
@@ -604,6 +816,7 @@ mod tests {
             name: "from".to_string(),
             value: "0x742d35Cc6634C0532925a3b844Bc9e7595f8fE31".to_string(),
             is_address: true,
+            ..Default::default()
         };
         assert!(param.is_address);
         assert_eq!(param.name, "from");
@@ -615,6 +828,7 @@ mod tests {
             name: "amount".to_string(),
             value: "1000.5".to_string(),
             is_address: false,
+            ..Default::default()
         };
         assert!(!param.is_address);
     }
@@ -637,4 +851,37 @@ mod tests {
         assert_eq!(TxType::EIP1559.as_str(), "EIP-1559 (Type 2)");
         assert_eq!(TxType::Blob.as_str(), "Blob (Type 3)");
     }
+
+    // ==================== Serialization tests ====================
+
+    #[test]
+    fn test_serialize_u256_as_decimal_string() {
+        let balance = TokenBalance {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            address: Address::ZERO,
+            balance: U256::from(10u64).pow(U256::from(30)),
+            decimals: 6,
+        };
+        let json = serde_json::to_value(&balance).unwrap();
+        assert_eq!(json["balance"], "1000000000000000000000000000000");
+        assert_eq!(json["decimals"], 6);
+    }
+
+    #[test]
+    fn test_serialize_optional_u256() {
+        let info = TokenInfo {
+            name: None,
+            symbol: Some("TKN".to_string()),
+            decimals: Some(18),
+            total_supply: Some(U256::from(42u64)),
+        };
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["total_supply"], "42");
+        assert!(json["name"].is_null());
+
+        let stats = BlockStats::default();
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["burnt_fees"], "0");
+    }
 }
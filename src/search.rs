@@ -1,14 +1,32 @@
+use crate::rpc::{block_label, parse_block_id};
+use alloy::eips::{BlockId, BlockNumberOrTag};
+use alloy::primitives::{Selector, B256};
+
 /// Represents the type of search query
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SearchQuery {
     /// Ethereum address (0x + 40 hex chars)
     Address(String),
-    /// Transaction hash (0x + 64 hex chars)
-    TxHash(String),
+    /// Transaction or block hash (0x + 64 hex chars)
+    Hash32(String),
     /// Block number (decimal or hex with 0x prefix)
     BlockNumber(u64),
     /// ENS name (contains . and valid characters)
     EnsName(String),
+    /// Address or ENS name as of a block: `addr@19000000`, `vitalik.eth@finalized`
+    AtBlock(Box<SearchQuery>, BlockId),
+    /// Block tag: `latest`, `finalized`, `safe`, `pending` or `earliest`
+    BlockTag(BlockNumberOrTag),
+    /// Blocks before a tag: `latest-10`
+    RelativeBlock(BlockNumberOrTag, u64),
+    /// Last block at or before a UTC time, as unix seconds: `@2024-03-01T12:00Z`
+    Timestamp(u64),
+    /// Function or error selector: `sel:0x…` (8 hex chars)
+    Selector(Selector),
+    /// Event topic: `topic:0x…` (64 hex chars)
+    Topic(B256),
+    /// Text signature to hash: `transfer(address,uint256)`
+    Signature(String),
     /// Invalid or unrecognized query
     Invalid(String),
 }
@@ -18,6 +36,34 @@ impl SearchQuery {
     pub fn parse(input: &str) -> Self {
         let trimmed = input.trim();
 
+        if let Some(time) = trimmed.strip_prefix('@') {
+            return match parse_utc_timestamp(time.trim()) {
+                Ok(timestamp) => Self::Timestamp(timestamp),
+                Err(reason) => Self::Invalid(reason),
+            };
+        }
+        if let Some((target, block)) = trimmed.rsplit_once('@') {
+            return Self::parse_at_block(target, block);
+        }
+        if let Some(topic) = trimmed.strip_prefix("topic:") {
+            return match topic.trim().parse::<B256>() {
+                Ok(topic) => Self::Topic(topic),
+                Err(_) => Self::Invalid(format!("Invalid topic: {}", topic.trim())),
+            };
+        }
+        if let Some(selector) = trimmed.strip_prefix("sel:") {
+            return match selector.trim().parse::<Selector>() {
+                Ok(selector) => Self::Selector(selector),
+                Err(_) => Self::Invalid(format!("Invalid selector: {}", selector.trim())),
+            };
+        }
+        if let Some(query) = Self::parse_block_tag(trimmed) {
+            return query;
+        }
+        if trimmed.contains('(') {
+            return Self::Signature(trimmed.to_string());
+        }
+
         // Check if it looks like an ENS name (contains a dot, ends with known TLD)
         if Self::looks_like_ens(trimmed) {
             return Self::EnsName(trimmed.to_lowercase());
@@ -36,8 +82,8 @@ impl SearchQuery {
             match hex_part.len() {
                 // Address: 40 hex chars
                 40 => Self::Address(trimmed.to_lowercase()),
-                // Tx hash: 64 hex chars
-                64 => Self::TxHash(trimmed.to_lowercase()),
+                // Tx or block hash: 64 hex chars
+                64 => Self::Hash32(trimmed.to_lowercase()),
                 // Could be a hex block number
                 1..=16 => match u64::from_str_radix(hex_part, 16) {
                     Ok(num) => Self::BlockNumber(num),
@@ -60,6 +106,52 @@ impl SearchQuery {
         }
     }
 
+    /// `latest`, `finalized`, ... optionally followed by `-N`
+    fn parse_block_tag(input: &str) -> Option<Self> {
+        let (tag, offset) = match input.split_once('-') {
+            Some((tag, offset)) => (tag.trim(), Some(offset.trim())),
+            None => (input, None),
+        };
+        let tag = match tag.to_lowercase().as_str() {
+            "latest" => BlockNumberOrTag::Latest,
+            "finalized" => BlockNumberOrTag::Finalized,
+            "safe" => BlockNumberOrTag::Safe,
+            "pending" => BlockNumberOrTag::Pending,
+            "earliest" => BlockNumberOrTag::Earliest,
+            _ => return None,
+        };
+        Some(match offset {
+            None => Self::BlockTag(tag),
+            // `safe-wallet.eth` is an ENS name, not an offset from `safe`
+            Some(offset) if offset.is_empty() || !offset.bytes().all(|b| b.is_ascii_digit()) => {
+                return None
+            }
+            Some(offset) => match offset.parse::<u64>() {
+                Ok(offset) => Self::RelativeBlock(tag, offset),
+                Err(_) => Self::Invalid(format!("Invalid block offset: {offset}")),
+            },
+        })
+    }
+
+    fn parse_at_block(target: &str, block: &str) -> Self {
+        let block = block.trim();
+        if block.is_empty() {
+            return Self::Invalid("Missing block after @".to_string());
+        }
+        let block = match parse_block_id(block) {
+            Ok(block) => block,
+            Err(e) => return Self::Invalid(e.to_string()),
+        };
+        match Self::parse(target) {
+            query @ (Self::Address(_) | Self::EnsName(_)) => Self::AtBlock(Box::new(query), block),
+            Self::Invalid(reason) => Self::Invalid(reason),
+            _ => Self::Invalid(format!(
+                "Only addresses can be viewed at a block: {}",
+                target.trim()
+            )),
+        }
+    }
+
     /// Check if a string looks like an ENS name
     fn looks_like_ens(s: &str) -> bool {
         // Must contain at least one dot
@@ -86,14 +178,118 @@ impl SearchQuery {
     pub fn description(&self) -> String {
         match self {
             Self::Address(addr) => format!("Address: {addr}"),
-            Self::TxHash(hash) => format!("Transaction: {hash}"),
+            Self::Hash32(hash) => format!("Transaction or block: {hash}"),
             Self::BlockNumber(num) => format!("Block: {num}"),
             Self::EnsName(name) => format!("ENS: {name}"),
+            Self::BlockTag(tag) => format!("Block: {tag}"),
+            Self::RelativeBlock(tag, offset) => format!("Block: {tag}-{offset}"),
+            Self::Timestamp(timestamp) => {
+                format!("Block at {}", format_utc_timestamp(*timestamp))
+            }
+            Self::Selector(selector) => format!("Selector: {selector}"),
+            Self::Topic(topic) => format!("Topic: {topic}"),
+            Self::Signature(signature) => format!("Signature: {signature}"),
+            Self::AtBlock(query, block) => {
+                format!("{} at block {}", query.description(), block_label(*block))
+            }
             Self::Invalid(reason) => format!("Invalid: {reason}"),
         }
     }
 }
 
+/// Parse `2024-03-01`, `2024-03-01T12:00Z`, `2024-03-01 12:00:30` (all UTC)
+/// or plain unix seconds
+pub fn parse_utc_timestamp(input: &str) -> Result<u64, String> {
+    let invalid =
+        || format!("Invalid time: {input} (expected e.g. 2024-03-01T12:00Z or unix seconds)");
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        return input.parse().map_err(|_| invalid());
+    }
+
+    let input = input.strip_suffix(['Z', 'z']).unwrap_or(input);
+    let (date, time) = match input.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (input, None),
+    };
+    let number = |s: &str, len: usize| -> Option<u64> {
+        (s.len() == len && s.chars().all(|c| c.is_ascii_digit())).then(|| s.parse().ok())?
+    };
+
+    let mut date_parts = date.split('-');
+    let (year, month, day) = match (
+        date_parts.next().and_then(|y| number(y, 4)),
+        date_parts.next().and_then(|m| number(m, 2)),
+        date_parts.next().and_then(|d| number(d, 2)),
+        date_parts.next(),
+    ) {
+        (Some(y), Some(m), Some(d), None) if (1..=12).contains(&m) && (1..=31).contains(&d) => {
+            (y, m, d)
+        }
+        _ => return Err(invalid()),
+    };
+
+    let seconds_of_day = match time {
+        None => 0,
+        Some(time) => {
+            if time.contains(['+', '-']) {
+                return Err(format!("Only UTC times are supported: {input}"));
+            }
+            let parts: Vec<_> = time.split(':').map(|p| number(p, 2)).collect();
+            match parts.as_slice() {
+                [Some(h), Some(m)] if *h < 24 && *m < 60 => h * 3600 + m * 60,
+                [Some(h), Some(m), Some(s)] if *h < 24 && *m < 60 && *s < 60 => {
+                    h * 3600 + m * 60 + s
+                }
+                _ => return Err(invalid()),
+            }
+        }
+    };
+
+    let days = days_from_civil(year, month, day).ok_or_else(invalid)?;
+    Ok(days * 86400 + seconds_of_day)
+}
+
+/// `2024-03-01 12:00:00 UTC`
+pub fn format_utc_timestamp(timestamp: u64) -> String {
+    let (year, month, day) = civil_from_days(timestamp / 86400);
+    let secs = timestamp % 86400;
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date (Howard Hinnant's
+/// algorithm), or None before 1970
+fn days_from_civil(year: u64, month: u64, day: u64) -> Option<u64> {
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    (era * 146097 + day_of_era).checked_sub(719468)
+}
+
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,7 +303,7 @@ mod tests {
     #[test]
     fn test_parse_tx_hash() {
         let hash = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
-        assert!(matches!(SearchQuery::parse(hash), SearchQuery::TxHash(_)));
+        assert!(matches!(SearchQuery::parse(hash), SearchQuery::Hash32(_)));
     }
 
     #[test]
@@ -162,4 +358,138 @@ mod tests {
             panic!("Expected EnsName variant");
         }
     }
+
+    #[test]
+    fn test_parse_at_block() {
+        let addr = "0x742d35Cc6634C0532925a3b844Bc9e7595f8fE31";
+        assert_eq!(
+            SearchQuery::parse(&format!("{addr}@19000000")),
+            SearchQuery::AtBlock(
+                Box::new(SearchQuery::Address(addr.to_lowercase())),
+                BlockId::number(19_000_000)
+            )
+        );
+        assert_eq!(
+            SearchQuery::parse("vitalik.eth @ finalized"),
+            SearchQuery::AtBlock(
+                Box::new(SearchQuery::EnsName("vitalik.eth".to_string())),
+                BlockId::finalized()
+            )
+        );
+
+        assert!(matches!(
+            SearchQuery::parse(&format!("{addr}@")),
+            SearchQuery::Invalid(_)
+        ));
+        assert!(matches!(
+            SearchQuery::parse(&format!("{addr}@yesterday")),
+            SearchQuery::Invalid(_)
+        ));
+        // Only addresses have state to view at a block
+        assert!(matches!(
+            SearchQuery::parse("12345@100"),
+            SearchQuery::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_block_tags() {
+        assert_eq!(
+            SearchQuery::parse("latest"),
+            SearchQuery::BlockTag(BlockNumberOrTag::Latest)
+        );
+        assert_eq!(
+            SearchQuery::parse("Finalized"),
+            SearchQuery::BlockTag(BlockNumberOrTag::Finalized)
+        );
+        assert_eq!(
+            SearchQuery::parse("latest-10"),
+            SearchQuery::RelativeBlock(BlockNumberOrTag::Latest, 10)
+        );
+        assert_eq!(
+            SearchQuery::parse("safe - 3"),
+            SearchQuery::RelativeBlock(BlockNumberOrTag::Safe, 3)
+        );
+        assert!(matches!(
+            SearchQuery::parse("latest-x"),
+            SearchQuery::Invalid(_)
+        ));
+        // Hyphenated ENS names starting with a tag
+        assert_eq!(
+            SearchQuery::parse("safe-wallet.eth"),
+            SearchQuery::EnsName("safe-wallet.eth".to_string())
+        );
+        assert_eq!(
+            SearchQuery::parse("Latest-News.eth"),
+            SearchQuery::EnsName("latest-news.eth".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamps() {
+        assert_eq!(
+            SearchQuery::parse("@2024-03-01T12:00Z"),
+            SearchQuery::Timestamp(1_709_294_400)
+        );
+        assert_eq!(
+            SearchQuery::parse("@2024-03-01 12:00:30"),
+            SearchQuery::Timestamp(1_709_294_430)
+        );
+        assert_eq!(
+            SearchQuery::parse("@2024-03-01"),
+            SearchQuery::Timestamp(1_709_251_200)
+        );
+        assert_eq!(
+            SearchQuery::parse("@1709294400"),
+            SearchQuery::Timestamp(1_709_294_400)
+        );
+        for bad in [
+            "@",
+            "@2024-13-01",
+            "@2024-03-01T25:00Z",
+            "@2024-03-01T12:00+02:00",
+            "@soon",
+        ] {
+            assert!(
+                matches!(SearchQuery::parse(bad), SearchQuery::Invalid(_)),
+                "{bad}"
+            );
+        }
+
+        assert_eq!(
+            format_utc_timestamp(1_709_294_430),
+            "2024-03-01 12:00:30 UTC"
+        );
+        assert_eq!(format_utc_timestamp(0), "1970-01-01 00:00:00 UTC");
+    }
+
+    #[test]
+    fn test_parse_selector_topic_and_signature() {
+        assert_eq!(
+            SearchQuery::parse("sel:0xa9059cbb"),
+            SearchQuery::Selector(Selector::from([0xa9, 0x05, 0x9c, 0xbb]))
+        );
+        // Bare 8-hex-digit input stays a block number
+        assert_eq!(
+            SearchQuery::parse("0x01312d00"),
+            SearchQuery::BlockNumber(20_000_000)
+        );
+        assert!(matches!(
+            SearchQuery::parse("sel:0x1234"),
+            SearchQuery::Invalid(_)
+        ));
+        let topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        assert_eq!(
+            SearchQuery::parse(&format!("topic:{topic}")),
+            SearchQuery::Topic(topic.parse().unwrap())
+        );
+        assert!(matches!(
+            SearchQuery::parse("topic:0x1234"),
+            SearchQuery::Invalid(_)
+        ));
+        assert_eq!(
+            SearchQuery::parse(" transfer(address,uint256) "),
+            SearchQuery::Signature("transfer(address,uint256)".to_string())
+        );
+    }
 }
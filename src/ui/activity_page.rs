@@ -0,0 +1,322 @@
+use super::helper::*;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::NAV_HELP;
+
+use crate::app::ActivityResult;
+use crate::rpc::{ActivityEvent, ActivityKind, ActivityScan, TokenHolding, MAX_ACTIVITY_EVENTS};
+use alloy::primitives::{Address, U256};
+
+pub fn draw_activity(frame: &mut Frame, result: &ActivityResult) {
+    let area = frame.area();
+    let scan = &result.scan;
+    let padded = padded_rect(area, 1);
+
+    // Holdings take at most a third of the screen; transfers get the rest
+    let holdings_height = (scan.holdings.len().max(1) as u16 + 2).min(padded.height / 3);
+    let chunks = Layout::vertical([
+        Constraint::Length(6),               // Summary
+        Constraint::Length(holdings_height), // Holdings
+        Constraint::Min(5),                  // Transfers
+        Constraint::Length(1),               // Nav help
+    ])
+    .split(padded);
+
+    draw_summary(frame, scan, chunks[0]);
+    draw_holdings(frame, result, chunks[1]);
+    draw_events(frame, result, chunks[2]);
+
+    let help = Paragraph::new(NAV_HELP)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[3]);
+}
+
+fn draw_summary(frame: &mut Frame, scan: &ActivityScan, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 📜 Token Activity ");
+
+    let transfers = scan
+        .events
+        .iter()
+        .filter(|e| e.kind == ActivityKind::Transfer)
+        .count();
+    let mut lines = vec![
+        format_kv("Address", &format!("{:?}", scan.address)),
+        format_kv(
+            "Blocks",
+            &format!(
+                "{} – {} ({} blocks)",
+                scan.from_block,
+                scan.to_block,
+                scan.to_block - scan.from_block + 1
+            ),
+        ),
+        format_kv(
+            "Found",
+            &format!(
+                "{transfers} transfers • {} approvals • {} tokens",
+                scan.events.len() - transfers,
+                scan.holdings.len()
+            ),
+        ),
+    ];
+    if scan.truncated() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "⚠ Stopped after {MAX_ACTIVITY_EVENTS} events; blocks before {} were not scanned",
+                scan.scanned_from
+            ),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_holdings(frame: &mut Frame, result: &ActivityResult, area: Rect) {
+    let scan = &result.scan;
+    let title = if result.list_mode {
+        " Holdings from transfers [Tab to select] ".to_string()
+    } else {
+        " Holdings from transfers [selected] ".to_string()
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(if result.list_mode {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default().fg(Color::Cyan)
+        })
+        .title(title);
+
+    if scan.holdings.is_empty() {
+        let empty = Paragraph::new("No token transfers in these blocks")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let visible_count = area.height.saturating_sub(2) as usize;
+    let selected = (!result.list_mode).then_some(result.selected_index);
+    let start = selected.map_or(0, |i| i.saturating_sub(visible_count / 2));
+    let items: Vec<ListItem> = scan
+        .holdings
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible_count)
+        .map(|(i, holding)| format_holding_item(holding, selected == Some(i)))
+        .collect();
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn format_holding_item<'a>(holding: &TokenHolding, selected: bool) -> ListItem<'a> {
+    let mut spans = vec![
+        Span::styled(
+            format!(
+                " {:<10}",
+                token_label(holding.symbol.as_deref(), holding.token)
+            ),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            format!(" {} ", truncate_hash(&format!("{:?}", holding.token))),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled("│ ", Style::default().fg(Color::DarkGray)),
+    ];
+    if holding.nft {
+        let held = match holding.token_ids.len() {
+            0 => "none held".to_string(),
+            n => {
+                let ids: Vec<String> = holding
+                    .token_ids
+                    .iter()
+                    .take(5)
+                    .map(|id| format!("#{id}"))
+                    .collect();
+                let more = if n > 5 {
+                    format!(" +{} more", n - 5)
+                } else {
+                    String::new()
+                };
+                format!("holds {}{more}", ids.join(", "))
+            }
+        };
+        spans.push(Span::styled(
+            "ERC-721 ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        spans.push(Span::styled(held, Style::default().fg(Color::White)));
+    } else {
+        let (sign, net) = if holding.received >= holding.sent {
+            ("+", holding.received - holding.sent)
+        } else {
+            ("-", holding.sent - holding.received)
+        };
+        spans.push(Span::styled("net ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(
+            format!("{sign}{}", format_amount(net, holding.decimals)),
+            Style::default().fg(if sign == "+" {
+                Color::Green
+            } else {
+                Color::Red
+            }),
+        ));
+        spans.push(Span::styled(
+            format!(
+                "  (in {} • out {})",
+                format_amount(holding.received, holding.decimals),
+                format_amount(holding.sent, holding.decimals)
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let style = if selected {
+        Style::default().bg(Color::Cyan).fg(Color::Black)
+    } else {
+        Style::default()
+    };
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+fn draw_events(frame: &mut Frame, result: &ActivityResult, area: Rect) {
+    let scan = &result.scan;
+    let title = if result.list_mode {
+        format!(" Transfers & Approvals ({}) [selected] ", scan.events.len())
+    } else {
+        format!(
+            " Transfers & Approvals ({}) [Tab to select] ",
+            scan.events.len()
+        )
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(if result.list_mode {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        })
+        .title(title);
+
+    if scan.events.is_empty() {
+        let empty = Paragraph::new("No Transfer or Approval events involve this address")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Account for header row and borders
+    let visible_count = area.height.saturating_sub(3) as usize;
+    let selected = result.list_mode.then_some(result.selected_index);
+    let start = selected.map_or(0, |i| i.saturating_sub(visible_count / 2));
+
+    let header = format!(
+        " {:>10} │ {:<8} │ {:>18} {:<10} │ {:^19} │ {}",
+        "Block", "Type", "Amount", "Token", "Counterparty", "Tx"
+    );
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::DarkGray),
+    )))];
+    items.extend(
+        scan.events
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_count)
+            .map(|(i, event)| format_event_item(scan, event, selected == Some(i))),
+    );
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn format_event_item<'a>(
+    scan: &ActivityScan,
+    event: &ActivityEvent,
+    selected: bool,
+) -> ListItem<'a> {
+    let address = scan.address;
+    let (label, color, counterparty) = match event.kind {
+        ActivityKind::Transfer if event.from == address && event.to == address => {
+            ("SELF", Color::Gray, event.to)
+        }
+        ActivityKind::Transfer if event.to == address => ("IN", Color::Green, event.from),
+        ActivityKind::Transfer => ("OUT", Color::Red, event.to),
+        ActivityKind::Approval if event.from == address => ("APPROVE", Color::Magenta, event.to),
+        ActivityKind::Approval => ("SPENDER", Color::Magenta, event.from),
+    };
+
+    let holding = scan.holding(event.token);
+    let amount = match (event.amount, event.token_id) {
+        (Some(amount), _) if event.kind == ActivityKind::Approval && amount == U256::MAX => {
+            "unlimited".to_string()
+        }
+        (Some(amount), _) => format_amount(amount, holding.and_then(|h| h.decimals)),
+        (None, Some(id)) => format!("#{id}"),
+        (None, None) => String::new(),
+    };
+    let token = token_label(holding.and_then(|h| h.symbol.as_deref()), event.token);
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" {:>10}", event.block),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{label:<8}"), Style::default().fg(color)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{amount:>18}"), Style::default().fg(Color::Yellow)),
+        Span::styled(format!(" {token:<10}"), Style::default().fg(Color::Yellow)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            truncate_hash(&format!("{counterparty:?}")),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            truncate_hash(&format!("{:?}", event.tx_hash)),
+            Style::default().fg(Color::Gray),
+        ),
+    ]);
+
+    let style = if selected {
+        Style::default().bg(Color::Cyan).fg(Color::Black)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+/// Symbol, or the start of the address for tokens without one
+fn token_label(symbol: Option<&str>, token: Address) -> String {
+    match symbol {
+        Some(symbol) if symbol.chars().count() > 10 => {
+            format!("{}…", symbol.chars().take(9).collect::<String>())
+        }
+        Some(symbol) => symbol.to_string(),
+        None => format!("{token:?}")[..10].to_string(),
+    }
+}
+
+/// Token amount in whole units, or raw if the decimals are unknown
+fn format_amount(amount: U256, decimals: Option<u8>) -> String {
+    match decimals {
+        Some(decimals) => format_token_amount(amount, decimals),
+        None => amount.to_string(),
+    }
+}
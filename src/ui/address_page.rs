@@ -1,22 +1,29 @@
 use super::helper::*;
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
-use crate::ui::{NAV_HELP_NO_LIST, NAV_HELP_SIMPLE};
+use crate::ui::{
+    NAV_HELP_ACTIVITY, NAV_HELP_ADDRESS, NAV_HELP_ADDRESS_NO_LIST, NAV_HELP_ADDRESS_TOKEN,
+    NAV_HELP_ADDRESS_TOKEN_NO_LIST, NAV_HELP_CALL, NAV_HELP_STORAGE,
+};
 
-use crate::app::AddressResult;
+use crate::app::{AddressResult, BlockPrompt, CallConsole, StorageInspector};
+use crate::rpc::{
+    word_as_address, word_as_bool, CallResult, DecodedParam, StorageRead, DEFAULT_ACTIVITY_RANGE,
+};
+use alloy::primitives::U256;
 
-pub fn draw_address_result(frame: &mut Frame, result: &AddressResult) {
+pub fn draw_address_result(frame: &mut Frame, result: &AddressResult, symbol: &str) {
     let area = frame.area();
     let info = &result.info;
 
     let addr_type = if info.is_contract {
-        if info.proxy_impl.is_some() {
+        if info.proxy.is_some() {
             "Proxy Contract"
         } else if info.token_info.is_some() {
             "ERC-20 Token"
@@ -27,16 +34,35 @@ pub fn draw_address_result(frame: &mut Frame, result: &AddressResult) {
         "EOA"
     };
 
+    // Inputs + status line + the results, inside borders
+    let panel_height = if let Some(inspector) = &result.storage {
+        5 + 3 * inspector.reads.len() as u16
+    } else if let Some(console) = &result.call {
+        5 + console
+            .results
+            .iter()
+            .map(|r| call_result_lines(r).len() as u16)
+            .sum::<u16>()
+    } else if result.activity.is_some() {
+        5
+    } else {
+        0
+    };
     let chunks = Layout::vertical([
-        Constraint::Min(10),   // Address info
-        Constraint::Length(1), // Nav help
+        Constraint::Min(10),              // Address info
+        Constraint::Length(panel_height), // Storage inspector / call console / activity scan
+        Constraint::Length(1),            // Nav help
     ])
     .split(padded_rect(area, 1));
 
+    let title = match info.block {
+        Some(number) => format!(" 👤 {addr_type} @ block {number} "),
+        None => format!(" 👤 {addr_type} "),
+    };
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
-        .title(format!(" 👤 {addr_type} "));
+        .title(title);
 
     let mut lines = vec![];
 
@@ -54,8 +80,17 @@ pub fn draw_address_result(frame: &mut Frame, result: &AddressResult) {
     }
 
     lines.push(format_kv("Address", &format!("{:?}", info.address)));
+    if let Some(number) = info.block {
+        lines.push(Line::from(vec![
+            Span::styled("State at Block: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(number.to_string(), Style::default().fg(Color::Yellow)),
+        ]));
+    }
     lines.push(Line::from(""));
-    lines.push(format_kv("ETH Balance", &format_eth(info.balance)));
+    lines.push(format_kv(
+        &format!("{symbol} Balance"),
+        &format_native(info.balance, symbol),
+    ));
     lines.push(format_kv("Nonce", &info.nonce.to_string()));
 
     if let Some(size) = info.code_size {
@@ -68,13 +103,25 @@ pub fn draw_address_result(frame: &mut Frame, result: &AddressResult) {
     }
 
     // Proxy info
-    if let Some(impl_addr) = &info.proxy_impl {
+    if let Some(proxy) = &info.proxy {
         lines.push(Line::from(""));
-        lines.push(format_kv_link(
-            "Implementation",
-            &format!("{impl_addr:?}"),
-            result.selected_link == 0,
-        ));
+        lines.push(Line::from(vec![Span::styled(
+            format!("── Proxy: {} ──", proxy.kind.name()),
+            Style::default().fg(Color::Magenta),
+        )]));
+        if proxy.implementation.is_none() && proxy.facets.is_empty() {
+            lines.push(format_kv("Implementation", "unknown"));
+        }
+        for (i, (label, address)) in proxy.links().into_iter().enumerate() {
+            let value = match proxy.facets.iter().find(|f| f.address == address) {
+                Some(facet) => match facet.selectors.len() {
+                    1 => format!("{address:?} (1 selector)"),
+                    n => format!("{address:?} ({n} selectors)"),
+                },
+                None => format!("{address:?}"),
+            };
+            lines.push(format_kv_link(label, &value, result.selected_link == i));
+        }
     }
 
     // Token info (for ERC-20 contracts being viewed)
@@ -130,14 +177,237 @@ pub fn draw_address_result(frame: &mut Frame, result: &AddressResult) {
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, chunks[0]);
 
-    let nav_help = if info.proxy_impl.is_some() {
-        NAV_HELP_SIMPLE
+    if let Some(inspector) = &result.storage {
+        draw_storage_inspector(frame, inspector, info.block, chunks[1]);
+    }
+    if let Some(console) = &result.call {
+        draw_call_console(frame, console, info.block, chunks[1]);
+    }
+    if let Some(prompt) = &result.activity {
+        draw_activity_prompt(frame, prompt, info.block, chunks[1]);
+    }
+
+    let nav_help = if result.storage.is_some() {
+        NAV_HELP_STORAGE
+    } else if result.call.is_some() {
+        NAV_HELP_CALL
+    } else if result.activity.is_some() {
+        NAV_HELP_ACTIVITY
+    } else if info.token_info.is_some() && info.proxy.is_some() {
+        NAV_HELP_ADDRESS_TOKEN
+    } else if info.token_info.is_some() {
+        NAV_HELP_ADDRESS_TOKEN_NO_LIST
+    } else if info.proxy.is_some() {
+        NAV_HELP_ADDRESS
     } else {
-        NAV_HELP_NO_LIST
+        NAV_HELP_ADDRESS_NO_LIST
     };
 
     let help = Paragraph::new(nav_help)
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
-    frame.render_widget(help, chunks[1]);
+    frame.render_widget(help, chunks[2]);
+}
+
+fn draw_storage_inspector(
+    frame: &mut Frame,
+    inspector: &StorageInspector,
+    page_block: Option<u64>,
+    area: Rect,
+) {
+    let hint = if inspector.pending {
+        Line::from(Span::styled(
+            "Reading...",
+            Style::default().fg(Color::Yellow),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "e.g. 5 • 0x1f • 3[0xabc…] • balances[0xabc…] @ 3 • 7[0xabc…][1]+1",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+    draw_block_prompt(
+        frame,
+        area,
+        " 🗄 Storage ",
+        &inspector.prompt,
+        [
+            ("Slot:", String::new()),
+            ("Block:", block_placeholder(page_block)),
+        ],
+        hint,
+        inspector
+            .reads
+            .iter()
+            .flat_map(storage_read_lines)
+            .collect(),
+    );
+}
+
+/// A read as its slot, raw word and the word decoded as uint/address/bool
+fn storage_read_lines(read: &StorageRead) -> Vec<Line<'static>> {
+    let mut decoded = vec![
+        Span::styled("    uint ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            U256::from_be_bytes(read.value.0).to_string(),
+            Style::default().fg(Color::White),
+        ),
+    ];
+    if let Some(address) = word_as_address(read.value) {
+        decoded.push(Span::styled(
+            " • address ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        decoded.push(Span::styled(
+            format!("{address:?}"),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if let Some(value) = word_as_bool(read.value) {
+        decoded.push(Span::styled(
+            " • bool ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        decoded.push(Span::styled(
+            value.to_string(),
+            Style::default().fg(Color::White),
+        ));
+    }
+
+    vec![
+        Line::from(vec![
+            Span::styled(
+                format!("  {}", read.expr),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                format!(
+                    " @ {} → slot {}",
+                    read.block,
+                    format_storage_word(read.slot)
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("    bytes32 ", Style::default().fg(Color::DarkGray)),
+            Span::styled(read.value.to_string(), Style::default().fg(Color::White)),
+        ]),
+        Line::from(decoded),
+    ]
+}
+
+fn draw_call_console(
+    frame: &mut Frame,
+    console: &CallConsole,
+    page_block: Option<u64>,
+    area: Rect,
+) {
+    let hint = if console.pending {
+        Line::from(Span::styled(
+            "Calling...",
+            Style::default().fg(Color::Yellow),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "e.g. totalSupply()(uint256) • balanceOf(address)(uint256) 0xabc… • name() returns (string)",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+    draw_block_prompt(
+        frame,
+        area,
+        " 📞 Call ",
+        &console.prompt,
+        [
+            ("Call:", String::new()),
+            ("Block:", block_placeholder(page_block)),
+        ],
+        hint,
+        console.results.iter().flat_map(call_result_lines).collect(),
+    );
+}
+
+/// What an empty block field stands for: the page's block, or latest
+fn block_placeholder(page_block: Option<u64>) -> String {
+    page_block.map_or("latest".to_string(), |number| number.to_string())
+}
+
+fn draw_activity_prompt(
+    frame: &mut Frame,
+    prompt: &BlockPrompt,
+    page_block: Option<u64>,
+    area: Rect,
+) {
+    draw_block_prompt(
+        frame,
+        area,
+        " 📜 Scan Activity ",
+        prompt,
+        [
+            (
+                "From:",
+                format!("{DEFAULT_ACTIVITY_RANGE} blocks before the end"),
+            ),
+            ("To:", block_placeholder(page_block)),
+        ],
+        Line::from(Span::styled(
+            "Finds token Transfer/Approval events with eth_getLogs • large ranges take a while",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Vec::new(),
+    );
+}
+
+/// A call as its signature and block, then one line per return value
+fn call_result_lines(result: &CallResult) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!("  {}", result.call),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            format!(" @ {}", result.block),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])];
+    if result.outputs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "    (no return data)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (i, output) in result.outputs.iter().enumerate() {
+        push_output_lines(&mut lines, output, i, 2);
+    }
+    lines
+}
+
+fn push_output_lines(
+    lines: &mut Vec<Line<'static>>,
+    param: &DecodedParam,
+    index: usize,
+    depth: usize,
+) {
+    let name = if param.name.is_empty() {
+        index.to_string()
+    } else {
+        param.name.clone()
+    };
+    let label = match &param.ty {
+        Some(ty) => format!("{}{name} ({ty}) ", "  ".repeat(depth)),
+        None => format!("{}{name} ", "  ".repeat(depth)),
+    };
+    let value_color = if param.is_address {
+        Color::Cyan
+    } else {
+        Color::White
+    };
+    lines.push(Line::from(vec![
+        Span::styled(label, Style::default().fg(Color::DarkGray)),
+        Span::styled(param.value.clone(), Style::default().fg(value_color)),
+    ]));
+    for (i, child) in param.children.iter().enumerate() {
+        push_output_lines(lines, child, i, depth + 1);
+    }
 }
@@ -12,7 +12,7 @@ use crate::ui::NAV_HELP;
 
 use crate::app::BlockResult;
 
-pub fn draw_block_result(frame: &mut Frame, result: &BlockResult) {
+pub fn draw_block_result(frame: &mut Frame, result: &BlockResult, symbol: &str) {
     let area = frame.area();
     let info = &result.info;
     let padded = padded_rect(area, 1);
@@ -110,15 +110,15 @@ pub fn draw_block_result(frame: &mut Frame, result: &BlockResult) {
     lines.push(Line::from(""));
     lines.push(format_kv(
         "Value Transferred",
-        &format_eth(result.stats.total_value_transferred),
+        &format_native(result.stats.total_value_transferred, symbol),
     ));
     lines.push(format_kv(
         "Total Fees",
-        &format_eth(result.stats.total_fees),
+        &format_native(result.stats.total_fees, symbol),
     ));
     lines.push(format_kv(
         "Burnt Fees",
-        &format_eth(result.stats.burnt_fees),
+        &format_native(result.stats.burnt_fees, symbol),
     ));
 
     // Blob info
@@ -192,7 +192,7 @@ pub fn draw_block_result(frame: &mut Frame, result: &BlockResult) {
                 .take(visible_count)
                 .map(|(i, tx)| {
                     let is_selected = result.list_mode && i == result.selected_index;
-                    format_tx_list_item(i, tx, is_selected)
+                    format_tx_list_item(i, tx, is_selected, symbol)
                 }),
         );
 
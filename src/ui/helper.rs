@@ -2,10 +2,12 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::ListItem,
+    widgets::{Block, Borders, ListItem, Paragraph},
+    Frame,
 };
 
-use crate::rpc::{TxSummary, TxType};
+use crate::app::BlockPrompt;
+use crate::rpc::{DecodedParam, TxSummary, TxType};
 
 // ============================================================================
 // Helper Functions
@@ -37,7 +39,12 @@ pub fn format_addr_fixed_width(addr: &str, ens: Option<&str>) -> String {
     }
 }
 
-pub fn format_tx_list_item<'a>(index: usize, tx: &TxSummary, selected: bool) -> ListItem<'a> {
+pub fn format_tx_list_item<'a>(
+    index: usize,
+    tx: &TxSummary,
+    selected: bool,
+    symbol: &str,
+) -> ListItem<'a> {
     // Format addresses to fixed width
     let from_display = format_addr_fixed_width(&tx.from, tx.from_ens.as_deref());
     let to_display = if tx.is_contract_creation {
@@ -74,18 +81,15 @@ pub fn format_tx_list_item<'a>(index: usize, tx: &TxSummary, selected: bool) ->
         format!("{}B", tx.input_size)
     };
 
-    let value_str = format_eth(tx.value);
+    let value_str = format_native(tx.value, symbol);
     let fee_str = tx
         .fee_paid
-        .map(format_eth)
+        .map(|fee| format_native(fee, symbol))
         .unwrap_or_else(|| "—".to_string());
 
     // Enhanced format with tx hash, type, addresses, method, value, and fee
     let line = Line::from(vec![
-        Span::styled(
-            format!("{index:>3} "),
-            Style::default().fg(Color::DarkGray),
-        ),
+        Span::styled(format!("{index:>3} "), Style::default().fg(Color::DarkGray)),
         Span::styled(type_indicator, Style::default().fg(Color::DarkGray)),
         Span::styled(" ", Style::default()),
         Span::styled(
@@ -235,19 +239,27 @@ pub fn format_gwei(wei: u128) -> String {
     }
 }
 
-pub fn format_eth(wei: alloy::primitives::U256) -> String {
+/// Format a wei amount in the chain's native currency (e.g. "1.500000 ETH")
+pub fn format_native(wei: alloy::primitives::U256, symbol: &str) -> String {
     let wei_str = wei.to_string();
     if wei_str.len() <= 18 {
         let eth = wei.to_string().parse::<f64>().unwrap_or(0.0) / 1e18;
-        format!("{eth:.6} ETH")
+        format!("{eth:.6} {symbol}")
     } else {
         let len = wei_str.len();
         let decimal_pos = len - 18;
         let (whole, frac) = wei_str.split_at(decimal_pos);
-        format!("{}.{:.6} ETH", whole, &frac[..6.min(frac.len())])
+        format!("{}.{:.6} {symbol}", whole, &frac[..6.min(frac.len())])
     }
 }
 
+/// Format a 32-byte storage word as hex without leading zeros (`0x0` for zero)
+pub fn format_storage_word(word: alloy::primitives::B256) -> String {
+    let hex = alloy::hex::encode(word);
+    let trimmed = hex.trim_start_matches('0');
+    format!("0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+}
+
 pub fn format_token_amount(amount: alloy::primitives::U256, decimals: u8) -> String {
     let amount_str = amount.to_string();
     let dec = decimals as usize;
@@ -315,6 +327,95 @@ pub fn padded_rect(area: Rect, padding: u16) -> Rect {
     }
 }
 
+/// Render decoded params as an indented tree (name, type and value per line).
+/// Nested values aren't navigable; only top-level log addresses are links.
+pub fn push_param_tree<'a>(lines: &mut Vec<Line<'a>>, params: &'a [DecodedParam], indent: usize) {
+    for param in params {
+        let value_color = if param.is_address {
+            Color::Cyan
+        } else {
+            Color::Yellow
+        };
+        let mut spans = vec![Span::styled(
+            format!("{:indent$}{}", "", param.name),
+            Style::default().fg(Color::DarkGray),
+        )];
+        if let Some(ty) = &param.ty {
+            spans.push(Span::styled(
+                format!(" {ty}"),
+                Style::default().fg(Color::Blue),
+            ));
+        }
+        spans.push(Span::styled(": ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(&param.value, Style::default().fg(value_color)));
+        lines.push(Line::from(spans));
+        push_param_tree(lines, &param.children, indent + 2);
+    }
+}
+
+/// A block prompt in a bordered panel: its two fields, each labelled and
+/// showing `placeholder` while empty, then its error or `hint`, then `rest`.
+/// The cursor goes in the focused field.
+pub fn draw_block_prompt<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    prompt: &'a BlockPrompt,
+    fields: [(&str, String); 2],
+    hint: Line<'a>,
+    rest: Vec<Line<'a>>,
+) {
+    const LABEL_WIDTH: usize = 7;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(title.to_string());
+
+    let inputs = [
+        (&prompt.input, !prompt.block_focused),
+        (&prompt.block_input, prompt.block_focused),
+    ];
+    let mut lines: Vec<Line> = inputs
+        .into_iter()
+        .zip(fields)
+        .map(|((input, focused), (label, placeholder))| {
+            let value = match input.value() {
+                "" => Span::styled(placeholder, Style::default().fg(Color::DarkGray)),
+                value if focused => Span::styled(value, Style::default().fg(Color::White)),
+                value => Span::styled(value, Style::default().fg(Color::Gray)),
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{label:LABEL_WIDTH$}"),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                value,
+            ])
+        })
+        .collect();
+    lines.push(match &prompt.error {
+        Some(error) => Line::from(Span::styled(
+            format!("✗ {error}"),
+            Style::default().fg(Color::Red),
+        )),
+        None => hint,
+    });
+    lines.extend(rest);
+
+    let inner = block.inner(area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    let (input, row) = match prompt.block_focused {
+        true => (&prompt.block_input, 1),
+        false => (&prompt.input, 0),
+    };
+    let cursor_x = inner.x + LABEL_WIDTH as u16 + input.visual_cursor() as u16;
+    if cursor_x < inner.x + inner.width {
+        frame.set_cursor_position((cursor_x, inner.y + row));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,30 +468,47 @@ mod tests {
         assert_eq!(format_gwei(wei), "0.5000 gwei");
     }
 
-    // ==================== format_eth tests ====================
+    // ==================== format_native tests ====================
 
     #[test]
     fn test_format_eth_zero() {
-        assert_eq!(format_eth(U256::ZERO), "0.000000 ETH");
+        assert_eq!(format_native(U256::ZERO, "ETH"), "0.000000 ETH");
     }
 
     #[test]
     fn test_format_eth_one() {
         let one_eth = U256::from(10u64).pow(U256::from(18));
-        let formatted = format_eth(one_eth);
+        let formatted = format_native(one_eth, "ETH");
         assert!(formatted.starts_with("1."));
         assert!(formatted.ends_with(" ETH"));
     }
 
+    #[test]
+    fn test_format_native_symbol() {
+        let amount = U256::from(25u64) * U256::from(10u64).pow(U256::from(17));
+        assert_eq!(format_native(amount, "POL"), "2.500000 POL");
+    }
+
     #[test]
     fn test_format_eth_small_fraction() {
         // 0.001 ETH
         let small = U256::from(10u64).pow(U256::from(15));
-        let formatted = format_eth(small);
+        let formatted = format_native(small, "ETH");
         assert!(formatted.starts_with("0.00"));
         assert!(formatted.ends_with(" ETH"));
     }
 
+    #[test]
+    fn test_format_storage_word() {
+        use alloy::primitives::B256;
+        assert_eq!(format_storage_word(B256::ZERO), "0x0");
+        assert_eq!(format_storage_word(B256::with_last_byte(0xc8)), "0xc8");
+        assert_eq!(
+            format_storage_word(B256::repeat_byte(0xff)),
+            format!("0x{}", "f".repeat(64))
+        );
+    }
+
     // ==================== format_token_amount tests ====================
 
     #[test]
@@ -0,0 +1,140 @@
+use super::helper::*;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::NAV_HELP_SIMPLE;
+
+use crate::app::LiveBlocksResult;
+use crate::rpc::BlockInfo;
+
+pub fn draw_live_blocks(frame: &mut Frame, result: &LiveBlocksResult, symbol: &str) {
+    let area = frame.area();
+
+    let chunks = Layout::vertical([
+        Constraint::Min(5),    // Block table
+        Constraint::Length(1), // Nav help
+    ])
+    .split(padded_rect(area, 1));
+
+    let title = match result.blocks.first() {
+        Some(head) if result.selected_index == 0 => {
+            format!(" 📡 Live Blocks • head #{} [following] ", head.number)
+        }
+        Some(head) => format!(" 📡 Live Blocks • head #{} ", head.number),
+        None => " 📡 Live Blocks ".to_string(),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(title);
+
+    if result.blocks.is_empty() {
+        let waiting = Paragraph::new("Waiting for new blocks...")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(waiting, chunks[0]);
+    } else {
+        // Account for header row and borders
+        let visible_count = (chunks[0].height.saturating_sub(3)) as usize;
+        let start = result.selected_index.saturating_sub(visible_count / 2);
+
+        let mut items: Vec<ListItem> = vec![format_live_block_header()];
+        items.extend(
+            result
+                .blocks
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(visible_count)
+                .map(|(i, info)| format_live_block_item(info, i == result.selected_index, symbol)),
+        );
+
+        let list = List::new(items).block(block);
+        frame.render_widget(list, chunks[0]);
+    }
+
+    let help = Paragraph::new(NAV_HELP_SIMPLE)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+fn format_live_block_header<'a>() -> ListItem<'a> {
+    let header = format!(
+        " {:>10} │ {:>13} │ {:>5} │ {:>16} │ {:>12} │ {:>14} │ {}",
+        "Block", "Age", "Txs", "Gas Used", "Base Fee", "Burnt", "Builder"
+    );
+    ListItem::new(Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::DarkGray),
+    )))
+}
+
+fn format_live_block_item<'a>(info: &BlockInfo, selected: bool, symbol: &str) -> ListItem<'a> {
+    let gas_pct = if info.gas_limit > 0 {
+        (info.gas_used as f64 / info.gas_limit as f64) * 100.0
+    } else {
+        0.0
+    };
+    let gas_color = if gas_pct > 90.0 {
+        Color::Red
+    } else if gas_pct > 70.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    let base_fee = info
+        .base_fee
+        .map(|f| format_gwei(f as u128))
+        .unwrap_or_else(|| "N/A".to_string());
+    let builder = info.builder_tag.as_deref().unwrap_or("—");
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" {:>10}", format!("#{}", info.number)),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:>13}", format_timestamp(info.timestamp)),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:>5}", info.tx_count),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!(
+                "{:>16}",
+                format!("{} ({gas_pct:.0}%)", format_gas(info.gas_used))
+            ),
+            Style::default().fg(gas_color),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{base_fee:>12}"), Style::default().fg(Color::White)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:>14}", format_native(info.burnt_fees, symbol)),
+            Style::default().fg(Color::Magenta),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(builder.to_string(), Style::default().fg(Color::Yellow)),
+    ]);
+
+    let style = if selected {
+        Style::default().bg(Color::Cyan).fg(Color::Black)
+    } else {
+        Style::default()
+    };
+
+    ListItem::new(line).style(style)
+}
@@ -0,0 +1,294 @@
+use super::helper::*;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::{NAV_HELP_LOGS, NAV_HELP_LOG_QUERY};
+
+use crate::app::{LogQueryForm, LogsResult, LOG_QUERY_FIELDS};
+use crate::rpc::{LogEntry, DEFAULT_LOG_RANGE};
+
+// Width of the field labels, so inputs line up
+const LABEL_WIDTH: u16 = 10;
+
+pub fn draw_log_query(frame: &mut Frame, form: &LogQueryForm) {
+    let area = frame.area();
+    let chunks = Layout::vertical([
+        Constraint::Length(2),  // Spacing
+        Constraint::Length(11), // Form
+        Constraint::Min(0),     // Padding
+        Constraint::Length(1),  // Nav help
+    ])
+    .split(padded_rect(area, 1));
+
+    let form_area = centered_rect(80, chunks[1]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(" 🔎 Log Query ");
+
+    let placeholders = [
+        "any contract • separate several with commas".to_string(),
+        "Transfer(address indexed from, address indexed to, uint256 value) or topic hash"
+            .to_string(),
+        "any • address, number, hash or \"string\"; commas for alternatives".to_string(),
+        "any".to_string(),
+        "any".to_string(),
+        format!("{DEFAULT_LOG_RANGE} blocks before the end"),
+        "latest".to_string(),
+    ];
+    let mut lines: Vec<Line> = LOG_QUERY_FIELDS
+        .iter()
+        .zip(&form.inputs)
+        .zip(placeholders)
+        .enumerate()
+        .map(|(i, ((label, input), placeholder))| {
+            let focused = form.focus == i;
+            let value = match input.value() {
+                "" => Span::styled(placeholder, Style::default().fg(Color::DarkGray)),
+                value => Span::styled(
+                    value,
+                    Style::default().fg(if focused { Color::White } else { Color::Gray }),
+                ),
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{:<width$}",
+                        format!("{label}:"),
+                        width = LABEL_WIDTH as usize
+                    ),
+                    Style::default().fg(if focused {
+                        Color::Yellow
+                    } else {
+                        Color::DarkGray
+                    }),
+                ),
+                value,
+            ])
+        })
+        .collect();
+    lines.push(Line::default());
+    lines.push(if let Some(error) = &form.error {
+        Line::from(Span::styled(
+            format!("✗ {error}"),
+            Style::default().fg(Color::Red),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "Runs eth_getLogs newest first • signatures without `indexed` index their first arguments",
+            Style::default().fg(Color::DarkGray),
+        ))
+    });
+
+    let inner = block.inner(form_area);
+    frame.render_widget(Paragraph::new(lines).block(block), form_area);
+
+    let input = &form.inputs[form.focus];
+    let width = inner.width.saturating_sub(LABEL_WIDTH) as usize;
+    let scroll = input.visual_scroll(width);
+    let cursor_x = inner.x + LABEL_WIDTH + input.visual_cursor().saturating_sub(scroll) as u16;
+    if cursor_x < inner.x + inner.width {
+        frame.set_cursor_position((cursor_x, inner.y + form.focus as u16));
+    }
+
+    let help = Paragraph::new(NAV_HELP_LOG_QUERY)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[3]);
+}
+
+pub fn draw_logs(frame: &mut Frame, result: &LogsResult) {
+    let area = frame.area();
+    let chunks = Layout::vertical([
+        Constraint::Length(6),  // Summary
+        Constraint::Min(5),     // Logs
+        Constraint::Length(12), // Selected log
+        Constraint::Length(1),  // Nav help
+    ])
+    .split(padded_rect(area, 1));
+
+    draw_summary(frame, result, chunks[0]);
+    draw_entries(frame, result, chunks[1]);
+    draw_selected(frame, result, chunks[2]);
+
+    let help = Paragraph::new(NAV_HELP_LOGS)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[3]);
+}
+
+fn draw_summary(frame: &mut Frame, result: &LogsResult, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 🔎 Event Logs ");
+
+    let page = &result.page;
+    let status = if result.pending {
+        Span::styled(
+            "⏳ Loading older logs...",
+            Style::default().fg(Color::Yellow),
+        )
+    } else if let Some(error) = &result.error {
+        Span::styled(format!("✗ {error}"), Style::default().fg(Color::Red))
+    } else if result.has_more() {
+        Span::styled(
+            format!(
+                "Press n for logs before block {} (query starts at {})",
+                page.scanned_from, result.from_block
+            ),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        Span::styled(
+            "All blocks in the range loaded",
+            Style::default().fg(Color::DarkGray),
+        )
+    };
+    let lines = vec![
+        format_kv("Query", &result.query.label()),
+        format_kv(
+            "Blocks",
+            &format!(
+                "{} – {} ({} blocks)",
+                page.scanned_from,
+                page.to_block,
+                page.to_block - page.scanned_from + 1
+            ),
+        ),
+        format_kv("Found", &format!("{} logs", page.entries.len())),
+        Line::from(status),
+    ];
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_entries(frame: &mut Frame, result: &LogsResult, area: Rect) {
+    let entries = &result.page.entries;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(" Logs ({}) ", entries.len()));
+
+    if entries.is_empty() {
+        let empty = Paragraph::new("No logs match in these blocks")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Account for header row and borders
+    let visible_count = area.height.saturating_sub(3) as usize;
+    let start = result.selected_index.saturating_sub(visible_count / 2);
+
+    let header = format!(
+        " {:>10} │ {:>5} │ {:^19} │ {:<24} │ {}",
+        "Block", "Index", "Contract", "Event", "Tx"
+    );
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::DarkGray),
+    )))];
+    items.extend(
+        entries
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_count)
+            .map(|(i, entry)| format_entry_item(entry, i == result.selected_index)),
+    );
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn format_entry_item<'a>(entry: &LogEntry, selected: bool) -> ListItem<'a> {
+    // Just the event name; the full signature is in the selected log pane
+    let event = match &entry.log.event_name {
+        Some(signature) => signature.split('(').next().unwrap_or(signature).to_string(),
+        None => entry
+            .log
+            .topics
+            .first()
+            .map_or("(anonymous)".to_string(), |topic| truncate_hash(topic)),
+    };
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" {:>10}", entry.block),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:>5}", entry.log_index),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            truncate_hash(&entry.log.address),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{event:<24}"), Style::default().fg(Color::Yellow)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            truncate_hash(&format!("{:?}", entry.tx_hash)),
+            Style::default().fg(Color::Gray),
+        ),
+    ]);
+
+    let style = if selected {
+        Style::default().bg(Color::Cyan).fg(Color::Black)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+fn draw_selected(frame: &mut Frame, result: &LogsResult, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(" Selected Log ");
+
+    let Some(entry) = result.page.entries.get(result.selected_index) else {
+        frame.render_widget(block, area);
+        return;
+    };
+    let log = &entry.log;
+
+    let mut lines = vec![
+        format_kv("Contract", &log.address),
+        format_kv("Tx", &format!("{:?}", entry.tx_hash)),
+        Line::from(Span::styled(
+            format!("  {}", log.event_name.as_deref().unwrap_or("Unknown Event")),
+            Style::default().fg(Color::White),
+        )),
+    ];
+    if log.decoded_params.is_empty() {
+        // Undecoded: show the raw topics and data
+        for (i, topic) in log.topics.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("    topic{i}: "),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(topic.as_str(), Style::default().fg(Color::Yellow)),
+            ]));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("    data: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(log.data.as_str(), Style::default().fg(Color::Yellow)),
+        ]));
+    } else {
+        push_param_tree(&mut lines, &log.decoded_params, 4);
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -0,0 +1,194 @@
+use super::helper::*;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::NAV_HELP_SIMPLE;
+
+use crate::app::{MempoolResult, MempoolSource, MEMPOOL_FEED_CAPACITY};
+use crate::rpc::PoolTx;
+
+pub fn draw_mempool(frame: &mut Frame, result: &MempoolResult, symbol: &str) {
+    let area = frame.area();
+
+    let chunks = Layout::vertical([
+        Constraint::Length(4), // Summary
+        Constraint::Min(5),    // Transactions
+        Constraint::Length(1), // Nav help
+    ])
+    .split(padded_rect(area, 1));
+
+    draw_summary(frame, result, chunks[0]);
+    draw_transactions(frame, result, symbol, chunks[1]);
+
+    let help = Paragraph::new(NAV_HELP_SIMPLE)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
+
+fn draw_summary(frame: &mut Frame, result: &MempoolResult, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" ⏳ Mempool ");
+
+    let pool = &result.pool;
+    let source = match result.source {
+        MempoolSource::Loading if result.error.is_some() => format_kv(
+            "Source",
+            "txpool_content unavailable • WebSocket/IPC endpoints follow newPendingTransactions instead",
+        ),
+        MempoolSource::Loading => format_kv("Source", "Loading txpool_content..."),
+        MempoolSource::TxPool => {
+            let mut senders: Vec<_> = pool.txs.iter().map(|tx| tx.from).collect();
+            senders.dedup();
+            format_kv(
+                "Source",
+                &format!(
+                    "txpool_content • {} pending • {} queued • {} senders",
+                    pool.pending,
+                    pool.queued,
+                    senders.len()
+                ),
+            )
+        }
+        MempoolSource::Subscription => format_kv(
+            "Source",
+            &format!(
+                "newPendingTransactions (node has no txpool API) • newest {MEMPOOL_FEED_CAPACITY} kept"
+            ),
+        ),
+    };
+    let status = match &result.error {
+        Some(error) => Line::from(Span::styled(
+            format!("✗ {error}"),
+            Style::default().fg(Color::Red),
+        )),
+        None => Line::from(Span::styled(
+            "Enter opens a transaction • queued transactions wait on an earlier nonce",
+            Style::default().fg(Color::DarkGray),
+        )),
+    };
+
+    frame.render_widget(Paragraph::new(vec![source, status]).block(block), area);
+}
+
+fn draw_transactions(frame: &mut Frame, result: &MempoolResult, symbol: &str, area: Rect) {
+    let txs = &result.pool.txs;
+    let title = match result.source {
+        MempoolSource::Subscription if result.selected_index == 0 && !txs.is_empty() => {
+            format!(" Pending Transactions ({}) [following] ", txs.len())
+        }
+        MempoolSource::Subscription => format!(" Pending Transactions ({}) ", txs.len()),
+        _ => format!(" Transactions by Sender ({}) ", txs.len()),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(title);
+
+    if txs.is_empty() {
+        let message = match result.source {
+            MempoolSource::Loading if result.error.is_some() => "No transactions to show",
+            MempoolSource::Loading => "Loading...",
+            MempoolSource::TxPool => "The pool is empty",
+            MempoolSource::Subscription => "Waiting for pending transactions...",
+        };
+        let empty = Paragraph::new(message)
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Account for header row and borders
+    let visible_count = area.height.saturating_sub(3) as usize;
+    let start = result.selected_index.saturating_sub(visible_count / 2);
+
+    let header = format!(
+        " {:^19} │ {:>6} │ {:<7} │ {:<30} │ {:>14} │ {}",
+        "Sender", "Nonce", "Status", "To", "Value", "Max fee / tip"
+    );
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::DarkGray),
+    )))];
+    items.extend(
+        txs.iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_count)
+            .map(|(i, tx)| {
+                // In a txpool snapshot, a sender's later nonces follow its first
+                let same_sender = result.source == MempoolSource::TxPool
+                    && i > start
+                    && txs[i - 1].from == tx.from;
+                format_pool_tx_item(tx, same_sender, i == result.selected_index, symbol)
+            }),
+    );
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn format_pool_tx_item<'a>(
+    tx: &PoolTx,
+    same_sender: bool,
+    selected: bool,
+    symbol: &str,
+) -> ListItem<'a> {
+    let sender = if same_sender {
+        String::new()
+    } else {
+        truncate_hash(&format!("{:?}", tx.from))
+    };
+    let (status, status_color) = if tx.queued {
+        ("QUEUED", Color::Yellow)
+    } else {
+        ("PENDING", Color::Green)
+    };
+    let to = match (tx.to, &tx.method) {
+        (None, _) => "Contract creation".to_string(),
+        (Some(to), Some(method)) => {
+            let method: String = method.chars().take(10).collect();
+            format!("{} {method}", truncate_hash(&format!("{to:?}")))
+        }
+        (Some(to), None) => truncate_hash(&format!("{to:?}")),
+    };
+    let fees = match tx.priority_fee {
+        Some(tip) => format!("{} / {}", format_gwei(tx.max_fee), format_gwei(tip)),
+        None => format_gwei(tx.max_fee),
+    };
+
+    let line = Line::from(vec![
+        Span::styled(format!(" {sender:<19}"), Style::default().fg(Color::Cyan)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:>6}", tx.nonce),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{status:<7}"), Style::default().fg(status_color)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{to:<30}"), Style::default().fg(Color::Gray)),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:>14}", format_native(tx.value, symbol)),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(fees, Style::default().fg(Color::White)),
+    ]);
+
+    let style = if selected {
+        Style::default().bg(Color::Cyan).fg(Color::Black)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
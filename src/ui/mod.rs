@@ -1,11 +1,25 @@
+mod activity_page;
 mod address_page;
 mod block_page;
-mod helper;
+pub(crate) mod helper;
+mod live_page;
+mod logs_page;
+mod mempool_page;
+mod signature_page;
+mod token_page;
+mod trace_page;
 mod tx_page;
 
+use activity_page::draw_activity;
 use address_page::draw_address_result;
 use block_page::draw_block_result;
 use helper::*;
+use live_page::draw_live_blocks;
+use logs_page::{draw_log_query, draw_logs};
+use mempool_page::draw_mempool;
+use signature_page::draw_signature_lookup;
+use token_page::draw_token;
+use trace_page::draw_trace;
 use tx_page::draw_tx_result;
 
 use ratatui::{
@@ -17,6 +31,7 @@ use ratatui::{
 };
 
 use crate::app::{App, Screen};
+use crate::rpc::chain_info;
 
 const TITLE_ART: &str = r#"
 ████████╗██████╗ ███████╗██╗  ██╗
@@ -29,15 +44,46 @@ const TITLE_ART: &str = r#"
 
 const NAV_HELP: &str = "↑↓ navigate • Enter select • Tab toggle • b back • h home • Esc quit";
 const NAV_HELP_SIMPLE: &str = "↑↓ navigate • Enter select • b back • h home • Esc quit";
+const NAV_HELP_TRACE: &str =
+    "↑↓ navigate • ←→ caller/target • Enter select • Tab fold • b back • h home • Esc quit";
+const NAV_HELP_TX: &str =
+    "↑↓ navigate • Enter select • t trace • d state changes • b back • h home • Esc quit";
 const NAV_HELP_NO_LIST: &str = "b back • h home • Esc quit";
+const NAV_HELP_ADDRESS: &str =
+    "↑↓ navigate • Enter select • s storage • c call • a activity • l logs • b back • h home • Esc quit";
+const NAV_HELP_ADDRESS_NO_LIST: &str =
+    "s storage • c call • a activity • l logs • b back • h home • Esc quit";
+const NAV_HELP_ADDRESS_TOKEN: &str =
+    "↑↓ navigate • Enter select • s storage • c call • a activity • l logs • t token • b back • h home • Esc quit";
+const NAV_HELP_ADDRESS_TOKEN_NO_LIST: &str =
+    "s storage • c call • a activity • l logs • t token • b back • h home • Esc quit";
+const NAV_HELP_STORAGE: &str = "Enter read • Tab slot/block • Esc close";
+const NAV_HELP_CALL: &str = "Enter call • Tab call/block • Esc close";
+const NAV_HELP_ACTIVITY: &str = "Enter scan • Tab from/to • Esc close";
+const NAV_HELP_TOKEN: &str =
+    "↑↓ navigate • Enter select • Tab toggle • w window • l lookup • b back • h home • Esc quit";
+const NAV_HELP_TOKEN_LOOKUP: &str = "Enter look up • Tab/↑↓ fields • Esc close";
+const NAV_HELP_LOG_QUERY: &str = "Enter query • Tab/↑↓ fields • Esc back";
+const NAV_HELP_LOGS: &str =
+    "↑↓ navigate • Enter open tx • n older logs • b back • h home • Esc quit";
 
 pub fn draw(frame: &mut Frame, app: &App) {
     match &app.screen {
         Screen::Home => draw_home(frame, app),
         Screen::Loading(msg) => draw_loading(frame, msg),
-        Screen::BlockResult(result) => draw_block_result(frame, result),
-        Screen::TxResult(result) => draw_tx_result(frame, result),
-        Screen::AddressResult(result) => draw_address_result(frame, result),
+        Screen::BlockResult(result) => draw_block_result(frame, result, app.native_symbol()),
+        Screen::TxResult(result) => draw_tx_result(frame, result, app.native_symbol()),
+        Screen::AddressResult(result) => {
+            draw_address_result(frame, result, app.native_symbol())
+        }
+        Screen::LiveBlocks(result) => draw_live_blocks(frame, result, app.native_symbol()),
+        Screen::Mempool(result) => draw_mempool(frame, result, app.native_symbol()),
+        Screen::Trace(result) => draw_trace(frame, result, app.native_symbol()),
+        Screen::Signature(lookup) => draw_signature_lookup(frame, lookup),
+        Screen::Activity(result) => draw_activity(frame, result),
+        Screen::Token(result) => draw_token(frame, result),
+        Screen::LogQuery(form) => draw_log_query(frame, form),
+        Screen::Logs(result) => draw_logs(frame, result),
         Screen::Error(msg) => draw_error(frame, msg),
     }
 }
@@ -93,7 +139,7 @@ fn draw_rpc_setup(frame: &mut Frame, app: &App, area: Rect) {
     ])
     .split(inner_area);
 
-    let label = Paragraph::new("Enter your Ethereum RPC URL (e.g., https://eth.llamarpc.com):")
+    let label = Paragraph::new("Enter your Ethereum RPC URL (https://, wss:// or an IPC socket path):")
         .style(Style::default().fg(Color::White));
     frame.render_widget(label, input_chunks[0]);
 
@@ -143,7 +189,7 @@ fn draw_search_home(frame: &mut Frame, app: &App, area: Rect) {
         Constraint::Length(history_height), // History
         Constraint::Length(1),              // Spacing
         Constraint::Length(1),              // RPC status
-        Constraint::Length(1),              // Help
+        Constraint::Length(2),              // Help
         Constraint::Min(0),                 // Network info
     ])
     .split(area);
@@ -177,10 +223,20 @@ fn draw_search_home(frame: &mut Frame, app: &App, area: Rect) {
         } else {
             url.clone()
         };
-        Line::from(vec![
-            Span::styled("RPC: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(truncated, Style::default().fg(Color::Green)),
-        ])
+        let mut spans = Vec::new();
+        if let Some(label) = app.profile_label() {
+            spans.push(Span::styled(label, Style::default().fg(Color::Cyan)));
+            spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
+        }
+        spans.push(Span::styled("RPC: ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(truncated, Style::default().fg(Color::Green)));
+        if let Some(actual) = app.chain_mismatch() {
+            spans.push(Span::styled(
+                format!("  ⚠ node reports chain {actual}"),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        Line::from(spans)
     } else {
         Line::from(vec![
             Span::styled("RPC: ", Style::default().fg(Color::DarkGray)),
@@ -195,7 +251,11 @@ fn draw_search_home(frame: &mut Frame, app: &App, area: Rect) {
     } else {
         "Enter to search • Esc to quit"
     };
-    let help = Paragraph::new(help_text)
+    let mut shortcuts = "Ctrl+L live blocks • Ctrl+T mempool • Ctrl+E logs".to_string();
+    if app.config.next_profile_name().is_some() {
+        shortcuts.push_str(" • Ctrl+P profile");
+    }
+    let help = Paragraph::new(vec![Line::from(help_text), Line::from(shortcuts)])
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
     frame.render_widget(help, chunks[8]);
@@ -252,10 +312,28 @@ fn draw_search_home(frame: &mut Frame, app: &App, area: Rect) {
             }
         }
 
-        lines.push(Line::from(vec![
-            Span::styled("Client: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&info.client_version, Style::default().fg(Color::Gray)),
-        ]));
+        let mut client_line = Vec::new();
+        if let Some(chain_id) = info.chain_id {
+            let chain = match chain_info(chain_id) {
+                Some(chain) => format!("{} ({chain_id})", chain.name),
+                None => chain_id.to_string(),
+            };
+            client_line.push(Span::styled(
+                "Chain: ",
+                Style::default().fg(Color::DarkGray),
+            ));
+            client_line.push(Span::styled(chain, Style::default().fg(Color::Gray)));
+            client_line.push(Span::raw("  "));
+        }
+        client_line.push(Span::styled(
+            "Client: ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        client_line.push(Span::styled(
+            &info.client_version,
+            Style::default().fg(Color::Gray),
+        ));
+        lines.push(Line::from(client_line));
 
         let net_para = Paragraph::new(lines)
             .block(net_block)
@@ -357,8 +435,14 @@ fn draw_loading(frame: &mut Frame, msg: &str) {
         / 100) as usize
         % spinner_frames.len();
 
-    let text = format!("{} {}", spinner_frames[idx], msg);
-    let paragraph = Paragraph::new(text)
+    let lines = vec![
+        Line::from(format!("{} {}", spinner_frames[idx], msg)),
+        Line::from(Span::styled(
+            "Backspace cancel • Esc quit",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let paragraph = Paragraph::new(lines)
         .block(block)
         .alignment(Alignment::Center)
         .style(Style::default().fg(Color::Yellow));
@@ -0,0 +1,78 @@
+use super::helper::*;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::rpc::SignatureLookup;
+use crate::ui::NAV_HELP_NO_LIST;
+
+pub fn draw_signature_lookup(frame: &mut Frame, lookup: &SignatureLookup) {
+    let area = frame.area();
+
+    let chunks = Layout::vertical([
+        Constraint::Min(5),    // Lookup
+        Constraint::Length(1), // Nav help
+    ])
+    .split(padded_rect(area, 1));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 🔎 Signature Lookup ");
+
+    let mut lines = vec![Line::from(Span::styled(
+        lookup.query.clone(),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    lines.push(Line::from(""));
+    if let Some(selector) = lookup.selector {
+        lines.push(format_kv("Selector", &selector.to_string()));
+    }
+    if let Some(topic) = lookup.topic {
+        lines.push(format_kv("Event Topic", &topic.to_string()));
+    }
+
+    let sections = [
+        ("Functions", &lookup.functions, lookup.selector.is_some()),
+        ("Errors", &lookup.errors, lookup.selector.is_some()),
+        ("Events", &lookup.events, lookup.topic.is_some()),
+    ];
+    for (title, signatures, searched) in sections {
+        if !searched || (signatures.is_empty() && title == "Errors") {
+            continue;
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("── {title} ──"),
+            Style::default().fg(Color::Yellow),
+        )));
+        if signatures.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No known signature",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for signature in signatures {
+            lines.push(Line::from(Span::styled(
+                format!("  {signature}"),
+                Style::default().fg(Color::White),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, chunks[0]);
+
+    let help = Paragraph::new(NAV_HELP_NO_LIST)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
@@ -0,0 +1,471 @@
+use super::helper::*;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::{NAV_HELP_ACTIVITY, NAV_HELP_TOKEN, NAV_HELP_TOKEN_LOOKUP};
+
+use crate::app::{BlockPrompt, TokenLookupForm, TokenResult, TOKEN_LOOKUP_FIELDS};
+use crate::rpc::{
+    ActivityEvent, TokenFlow, TokenLookup, TokenScan, DEFAULT_TOKEN_RANGE, MAX_TOKEN_TRANSFERS,
+};
+use alloy::primitives::{Address, U256};
+
+// Width of the lookup field labels, so inputs line up
+const LABEL_WIDTH: u16 = 9;
+
+pub fn draw_token(frame: &mut Frame, result: &TokenResult) {
+    let area = frame.area();
+    let scan = &result.scan;
+    let padded = padded_rect(area, 1);
+
+    // Inputs + status line + the results, inside borders
+    let panel_height = if let Some(form) = &result.lookup {
+        6 + form
+            .results
+            .iter()
+            .map(|lookup| lookup_lines(scan, lookup).len() as u16)
+            .sum::<u16>()
+    } else if result.window.is_some() {
+        5
+    } else {
+        0
+    };
+    // Top accounts take at most a third of the screen; transfers get the rest
+    let rows = scan.senders.len().max(scan.receivers.len()).max(1) as u16;
+    let accounts_height = (rows + 2).min(padded.height / 3);
+    let chunks = Layout::vertical([
+        Constraint::Length(7),               // Summary
+        Constraint::Length(accounts_height), // Top senders | top receivers
+        Constraint::Min(5),                  // Transfers
+        Constraint::Length(panel_height),    // Lookup / window prompt
+        Constraint::Length(1),               // Nav help
+    ])
+    .split(padded);
+
+    draw_summary(frame, scan, chunks[0]);
+    draw_accounts(frame, result, chunks[1]);
+    draw_transfers(frame, result, chunks[2]);
+
+    if let Some(form) = &result.lookup {
+        draw_lookup(frame, scan, form, chunks[3]);
+    }
+    if let Some(prompt) = &result.window {
+        draw_window_prompt(frame, prompt, chunks[3]);
+    }
+
+    let nav_help = if result.lookup.is_some() {
+        NAV_HELP_TOKEN_LOOKUP
+    } else if result.window.is_some() {
+        NAV_HELP_ACTIVITY
+    } else {
+        NAV_HELP_TOKEN
+    };
+    let help = Paragraph::new(nav_help)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[4]);
+}
+
+fn draw_summary(frame: &mut Frame, scan: &TokenScan, area: Rect) {
+    let info = &scan.info;
+    let title = match &info.symbol {
+        Some(symbol) => format!(" 🪙 Token: {symbol} "),
+        None => " 🪙 Token ".to_string(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(title);
+
+    let token = match &info.name {
+        Some(name) => format!("{name} • {:?}", scan.token),
+        None => format!("{:?}", scan.token),
+    };
+    let supply = match info.total_supply {
+        Some(supply) => match &info.symbol {
+            Some(symbol) => format!(
+                "{} {symbol} (block {})",
+                format_amount(scan, supply),
+                scan.to_block
+            ),
+            None => format!("{} (block {})", format_amount(scan, supply), scan.to_block),
+        },
+        None => "unknown".to_string(),
+    };
+    let mut lines = vec![
+        format_kv("Token", &token),
+        format_kv("Total Supply", &supply),
+        format_kv(
+            "Blocks",
+            &format!(
+                "{} – {} ({} blocks)",
+                scan.from_block,
+                scan.to_block,
+                scan.to_block - scan.from_block + 1
+            ),
+        ),
+        format_kv(
+            "Transfers",
+            &format!(
+                "{} • volume {} • minted {} • burned {}",
+                scan.transfers.len(),
+                format_amount(scan, scan.volume),
+                format_amount(scan, scan.minted),
+                format_amount(scan, scan.burned)
+            ),
+        ),
+    ];
+    if scan.truncated() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "⚠ Stopped after {MAX_TOKEN_TRANSFERS} transfers; blocks before {} were not scanned",
+                scan.scanned_from
+            ),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_accounts(frame: &mut Frame, result: &TokenResult, area: Rect) {
+    let scan = &result.scan;
+    let columns =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+
+    // The accounts list runs through the senders, then the receivers
+    let selected = (!result.list_mode).then_some(result.selected_index);
+    let sender_selected = selected.filter(|i| *i < scan.senders.len());
+    let receiver_selected = selected
+        .and_then(|i| i.checked_sub(scan.senders.len()))
+        .filter(|i| *i < scan.receivers.len());
+
+    draw_flows(
+        frame,
+        scan,
+        "Top Senders",
+        &scan.senders,
+        result.list_mode,
+        sender_selected,
+        columns[0],
+    );
+    draw_flows(
+        frame,
+        scan,
+        "Top Receivers",
+        &scan.receivers,
+        result.list_mode,
+        receiver_selected,
+        columns[1],
+    );
+}
+
+fn draw_flows(
+    frame: &mut Frame,
+    scan: &TokenScan,
+    label: &str,
+    flows: &[TokenFlow],
+    list_mode: bool,
+    selected: Option<usize>,
+    area: Rect,
+) {
+    let title = if list_mode {
+        format!(" {label} [Tab to select] ")
+    } else {
+        format!(" {label} [selected] ")
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(if list_mode {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default().fg(Color::Cyan)
+        })
+        .title(title);
+
+    if flows.is_empty() {
+        let empty = Paragraph::new("No transfers in these blocks")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let visible_count = area.height.saturating_sub(2) as usize;
+    let start = selected.map_or(0, |i| i.saturating_sub(visible_count / 2));
+    let items: Vec<ListItem> = flows
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible_count)
+        .map(|(i, flow)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!(" {}", truncate_hash(&format!("{:?}", flow.account))),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{:>18}", format_amount(scan, flow.amount)),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!(" ({} transfers)", flow.transfers),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]);
+            let style = if selected == Some(i) {
+                Style::default().bg(Color::Cyan).fg(Color::Black)
+            } else {
+                Style::default()
+            };
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn draw_transfers(frame: &mut Frame, result: &TokenResult, area: Rect) {
+    let scan = &result.scan;
+    let title = if result.list_mode {
+        format!(" Transfers ({}) [selected] ", scan.transfers.len())
+    } else {
+        format!(" Transfers ({}) [Tab to select] ", scan.transfers.len())
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(if result.list_mode {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        })
+        .title(title);
+
+    if scan.transfers.is_empty() {
+        let empty = Paragraph::new("No Transfer events in these blocks")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Account for header row and borders
+    let visible_count = area.height.saturating_sub(3) as usize;
+    let selected = result.list_mode.then_some(result.selected_index);
+    let start = selected.map_or(0, |i| i.saturating_sub(visible_count / 2));
+
+    let header = format!(
+        " {:>10} │ {:^19} │ {:^19} │ {:>18} │ {}",
+        "Block", "From", "To", "Amount", "Tx"
+    );
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::DarkGray),
+    )))];
+    items.extend(
+        scan.transfers
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_count)
+            .map(|(i, transfer)| format_transfer_item(scan, transfer, selected == Some(i))),
+    );
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn format_transfer_item<'a>(
+    scan: &TokenScan,
+    transfer: &ActivityEvent,
+    selected: bool,
+) -> ListItem<'a> {
+    // Mints come from and burns go to the zero address
+    let party = |address: Address, zero: &str| {
+        if address == Address::ZERO {
+            Span::styled(format!("{zero:^19}"), Style::default().fg(Color::Magenta))
+        } else {
+            Span::styled(
+                truncate_hash(&format!("{address:?}")),
+                Style::default().fg(Color::Cyan),
+            )
+        }
+    };
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" {:>10}", transfer.block),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        party(transfer.from, "MINT"),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        party(transfer.to, "BURN"),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!(
+                "{:>18}",
+                format_amount(scan, transfer.amount.unwrap_or_default())
+            ),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            truncate_hash(&format!("{:?}", transfer.tx_hash)),
+            Style::default().fg(Color::Gray),
+        ),
+    ]);
+
+    let style = if selected {
+        Style::default().bg(Color::Cyan).fg(Color::Black)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+fn draw_lookup(frame: &mut Frame, scan: &TokenScan, form: &TokenLookupForm, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(" 🔍 Balance & Allowance ");
+
+    let placeholders = ["0x… address", "none (balance only)", "latest"];
+    let mut lines: Vec<Line> = TOKEN_LOOKUP_FIELDS
+        .iter()
+        .zip(&form.inputs)
+        .zip(placeholders)
+        .enumerate()
+        .map(|(i, ((label, input), placeholder))| {
+            let focused = form.focus == i;
+            let value = match input.value() {
+                "" => Span::styled(placeholder, Style::default().fg(Color::DarkGray)),
+                value => Span::styled(
+                    value,
+                    Style::default().fg(if focused { Color::White } else { Color::Gray }),
+                ),
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{:<width$}",
+                        format!("{label}:"),
+                        width = LABEL_WIDTH as usize
+                    ),
+                    Style::default().fg(if focused {
+                        Color::Yellow
+                    } else {
+                        Color::DarkGray
+                    }),
+                ),
+                value,
+            ])
+        })
+        .collect();
+
+    lines.push(if let Some(error) = &form.error {
+        Line::from(Span::styled(
+            format!("✗ {error}"),
+            Style::default().fg(Color::Red),
+        ))
+    } else if form.pending {
+        Line::from(Span::styled(
+            "Looking up...",
+            Style::default().fg(Color::Yellow),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "Calls balanceOf(holder), and allowance(holder, spender) when a spender is given",
+            Style::default().fg(Color::DarkGray),
+        ))
+    });
+
+    for lookup in &form.results {
+        lines.extend(lookup_lines(scan, lookup));
+    }
+
+    let inner = block.inner(area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    let input = &form.inputs[form.focus];
+    let cursor_x = inner.x + LABEL_WIDTH + input.visual_cursor() as u16;
+    if cursor_x < inner.x + inner.width {
+        frame.set_cursor_position((cursor_x, inner.y + form.focus as u16));
+    }
+}
+
+/// A lookup as its holder and block, then the balance and any allowance
+fn lookup_lines(scan: &TokenScan, lookup: &TokenLookup) -> Vec<Line<'static>> {
+    let symbol = scan.info.symbol.clone().unwrap_or_default();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("  {:?}", lookup.holder),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!(" @ {}", lookup.block),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("    balance ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{} {symbol}", format_amount(scan, lookup.balance)),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    if let (Some(spender), Some(allowance)) = (lookup.spender, lookup.allowance) {
+        let allowance = if allowance == U256::MAX {
+            "unlimited".to_string()
+        } else {
+            format!("{} {symbol}", format_amount(scan, allowance))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "    allowance for {} ",
+                    truncate_hash(&format!("{spender:?}"))
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(allowance, Style::default().fg(Color::White)),
+        ]));
+    }
+    lines
+}
+
+fn draw_window_prompt(frame: &mut Frame, prompt: &BlockPrompt, area: Rect) {
+    draw_block_prompt(
+        frame,
+        area,
+        " 🪟 Block Window ",
+        prompt,
+        [
+            ("From:", format!("{DEFAULT_TOKEN_RANGE} blocks before the end")),
+            ("To:", "latest".to_string()),
+        ],
+        Line::from(Span::styled(
+            format!("Rescans Transfer events with eth_getLogs • stops after {MAX_TOKEN_TRANSFERS} transfers"),
+            Style::default().fg(Color::DarkGray),
+        )),
+        Vec::new(),
+    );
+}
+
+/// Token amount in whole units, or raw if the decimals are unknown
+fn format_amount(scan: &TokenScan, amount: U256) -> String {
+    match scan.info.decimals {
+        Some(decimals) => format_token_amount(amount, decimals),
+        None => amount.to_string(),
+    }
+}
@@ -0,0 +1,146 @@
+use super::helper::*;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::app::{TraceResult, TraceRow};
+use crate::ui::NAV_HELP_TRACE;
+
+pub fn draw_trace(frame: &mut Frame, result: &TraceResult, symbol: &str) {
+    let area = frame.area();
+
+    let chunks = Layout::vertical([
+        Constraint::Min(5),    // Call tree
+        Constraint::Length(1), // Nav help
+    ])
+    .split(padded_rect(area, 1));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(
+            " 🌳 Call Trace {} • {} calls ",
+            truncate_hash(&result.tx_hash),
+            result.root.call_count()
+        ));
+
+    let rows = result.visible_calls();
+
+    // Account for borders
+    let visible_count = (chunks[0].height.saturating_sub(2)) as usize;
+    let start = result.selected_index.saturating_sub(visible_count / 2);
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible_count)
+        .map(|(i, row)| {
+            // Which of the row's addresses Enter opens, if it's selected
+            let selected = (i == result.selected_index).then_some(result.from_selected);
+            format_trace_row(row, selected, symbol)
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    frame.render_widget(list, chunks[0]);
+
+    let help = Paragraph::new(NAV_HELP_TRACE)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// `selected` is None for rows other than the selected one, otherwise
+/// whether the caller (rather than the target) is the selected link
+fn format_trace_row<'a>(row: &TraceRow<'a>, selected: Option<bool>, symbol: &str) -> ListItem<'a> {
+    let call = row.call;
+    let indent = "  ".repeat(row.path.len());
+    let marker = if call.calls.is_empty() {
+        "•"
+    } else if row.collapsed {
+        "▸"
+    } else {
+        "▾"
+    };
+
+    let type_color = match call.call_type.as_str() {
+        "DELEGATECALL" | "CALLCODE" => Color::Magenta,
+        "STATICCALL" => Color::Blue,
+        t if t.starts_with("CREATE") => Color::Green,
+        "SELFDESTRUCT" => Color::Red,
+        _ => Color::Cyan,
+    };
+    let link_style = |selected: bool| {
+        if selected {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::UNDERLINED)
+        }
+    };
+
+    let method = match &call.method {
+        Some(method) => method.clone(),
+        None if call.call_type.starts_with("CREATE") || call.input.is_empty() => String::new(),
+        // Unknown selector
+        None => format!(
+            "0x{}",
+            alloy::hex::encode(&call.input[..4.min(call.input.len())])
+        ),
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format!(" {indent}{marker} "),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(
+            format!("{:<12} ", call.call_type),
+            Style::default().fg(type_color),
+        ),
+        Span::styled(
+            truncate_hash(&call.from),
+            link_style(selected == Some(true)),
+        ),
+        Span::styled(" → ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            call.to.clone().unwrap_or_else(|| "?".to_string()),
+            link_style(selected == Some(false)),
+        ),
+    ];
+    if !method.is_empty() {
+        spans.push(Span::styled(
+            format!(" {method}"),
+            Style::default().fg(Color::White),
+        ));
+    }
+    if !call.value.is_zero() {
+        spans.push(Span::styled(
+            format!("  {}", format_native(call.value, symbol)),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    spans.push(Span::styled(
+        format!("  gas {}", format_gas(call.gas_used)),
+        Style::default().fg(Color::DarkGray),
+    ));
+    if let Some(error) = &call.error {
+        let reason = call.revert_reason.as_deref().unwrap_or(error);
+        spans.push(Span::styled(
+            format!("  ✗ {reason}"),
+            Style::default().fg(Color::Red),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
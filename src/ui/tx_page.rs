@@ -7,11 +7,13 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{TxResult, MAX_VISIBLE_LOGS, MAX_VISIBLE_TRANSFERS};
+use crate::app::{
+    StateDiffStatus, TxResult, MAX_VISIBLE_LOGS, MAX_VISIBLE_STATE_CHANGES, MAX_VISIBLE_TRANSFERS,
+};
 use crate::rpc::TxType;
-use crate::ui::NAV_HELP_SIMPLE;
+use crate::ui::NAV_HELP_TX;
 
-pub fn draw_tx_result(frame: &mut Frame, result: &TxResult) {
+pub fn draw_tx_result(frame: &mut Frame, result: &TxResult, symbol: &str) {
     let area = frame.area();
     let info = &result.info;
 
@@ -44,6 +46,13 @@ pub fn draw_tx_result(frame: &mut Frame, result: &TxResult) {
         lines.push(format_kv("Method", method));
     }
 
+    if let Some(ref reason) = info.revert_reason {
+        lines.push(Line::from(vec![
+            Span::styled("Revert Reason: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(reason.clone(), Style::default().fg(Color::Red)),
+        ]));
+    }
+
     // From (link 0) - show ENS name if available
     let from_display = format_address_with_ens(&info.from, info.from_ens.as_deref());
     lines.push(format_kv_link(
@@ -67,11 +76,11 @@ pub fn draw_tx_result(frame: &mut Frame, result: &TxResult) {
     }
 
     lines.push(Line::from(""));
-    lines.push(format_kv("Value", &format_eth(info.value)));
+    lines.push(format_kv("Value", &format_native(info.value, symbol)));
 
     // Actual fee paid
     if let Some(fee) = info.actual_fee {
-        lines.push(format_kv("Fee Paid", &format_eth(fee)));
+        lines.push(format_kv("Fee Paid", &format_native(fee, symbol)));
     }
 
     // Gas info
@@ -168,8 +177,10 @@ pub fn draw_tx_result(frame: &mut Frame, result: &TxResult) {
             Span::styled("  ", Style::default()),
             Span::styled(display_data, Style::default().fg(Color::DarkGray)),
         ]));
+        // Arguments decoded with a user-supplied ABI
+        push_param_tree(&mut lines, &info.decoded_inputs, 2);
     } else {
-        lines.push(format_kv("Input", "None (ETH transfer)"));
+        lines.push(format_kv("Input", &format!("None ({symbol} transfer)")));
     }
 
     // Token transfers - scrollable list with navigable addresses
@@ -376,6 +387,7 @@ pub fn draw_tx_result(frame: &mut Frame, result: &TxResult) {
                         Span::styled(&param.value, Style::default().fg(Color::Yellow)),
                     ]));
                 }
+                push_param_tree(&mut lines, &param.children, 11);
             }
         }
 
@@ -394,14 +406,141 @@ pub fn draw_tx_result(frame: &mut Frame, result: &TxResult) {
         }
     }
 
+    // State changes of mined transactions are traced when asked for
+    if info.state_changes.is_empty() && info.block_number.is_some() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            "── State Changes ──",
+            Style::default().fg(Color::Green),
+        )]));
+        let (status, color) = match &result.state_diff {
+            StateDiffStatus::NotLoaded => (
+                "  Press d to trace them (debug_traceTransaction prestateTracer)".to_string(),
+                Color::DarkGray,
+            ),
+            StateDiffStatus::Loading => ("  Tracing state changes...".to_string(), Color::Yellow),
+            StateDiffStatus::Loaded => ("  No state changes".to_string(), Color::DarkGray),
+            StateDiffStatus::Unsupported => (
+                "  This RPC endpoint doesn't offer debug_traceTransaction".to_string(),
+                Color::Yellow,
+            ),
+            StateDiffStatus::Failed(error) => (format!("  ✗ {error}"), Color::Red),
+        };
+        lines.push(Line::from(Span::styled(status, Style::default().fg(color))));
+    }
+
+    // State changes (prestateTracer diff) - one navigable address per account
+    if !info.state_changes.is_empty() {
+        lines.push(Line::from(""));
+
+        let header_text = if result.state_scroll > 0 {
+            format!(
+                "── State Changes ({}) ── ↑{} more",
+                info.state_changes.len(),
+                result.state_scroll
+            )
+        } else {
+            format!("── State Changes ({}) ──", info.state_changes.len())
+        };
+        lines.push(Line::from(vec![Span::styled(
+            header_text,
+            Style::default().fg(Color::Green),
+        )]));
+
+        let visible_end =
+            (result.state_scroll + MAX_VISIBLE_STATE_CHANGES).min(info.state_changes.len());
+        link_idx += result.state_scroll;
+
+        for (i, account) in info.state_changes[result.state_scroll..visible_end]
+            .iter()
+            .enumerate()
+        {
+            let account_num = result.state_scroll + i + 1; // 1-indexed
+            let addr_style = if result.selected_link == link_idx {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::UNDERLINED)
+            };
+            link_idx += 1;
+
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {account_num:>3}. "),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(&account.address, addr_style),
+            ]));
+
+            if let (Some(before), Some(after)) = (account.balance_before, account.balance_after) {
+                lines.push(state_change_line(
+                    "Balance",
+                    format_native(before, symbol),
+                    format_native(after, symbol),
+                ));
+            }
+            if let (Some(before), Some(after)) = (account.nonce_before, account.nonce_after) {
+                lines.push(state_change_line(
+                    "Nonce",
+                    before.to_string(),
+                    after.to_string(),
+                ));
+            }
+            if let (Some(before), Some(after)) = (account.code_size_before, account.code_size_after)
+            {
+                lines.push(state_change_line(
+                    "Code",
+                    format!("{before} bytes"),
+                    format!("{after} bytes"),
+                ));
+            }
+            for change in &account.storage {
+                lines.push(state_change_line(
+                    &format!("Slot {}", format_storage_word(change.slot)),
+                    format_storage_word(change.before),
+                    format_storage_word(change.after),
+                ));
+            }
+        }
+
+        link_idx += info.state_changes.len() - visible_end;
+
+        if visible_end < info.state_changes.len() {
+            lines.push(Line::from(vec![Span::styled(
+                format!(
+                    "  ↓ {} more accounts",
+                    info.state_changes.len() - visible_end
+                ),
+                Style::default().fg(Color::DarkGray),
+            )]));
+        }
+    }
+
     // Suppress unused variable warning
     let _ = link_idx;
 
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, chunks[0]);
 
-    let help = Paragraph::new(NAV_HELP_SIMPLE)
+    let help = Paragraph::new(NAV_HELP_TX)
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
     frame.render_widget(help, chunks[1]);
 }
+
+/// `label: before → after`, indented under a state change account
+fn state_change_line(label: &str, before: String, after: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!("         {label}: "),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(before, Style::default().fg(Color::Yellow)),
+        Span::styled(" → ", Style::default().fg(Color::DarkGray)),
+        Span::styled(after, Style::default().fg(Color::Green)),
+    ])
+}
@@ -0,0 +1,166 @@
+//! Token activity UI tests
+
+use super::*;
+use alloy::primitives::B256;
+use tbex::app::{ActivityResult, AddressResult, BlockPrompt};
+use tbex::rpc::{ActivityEvent, ActivityKind, ActivityScan, TokenHolding};
+
+const HOLDER: Address = Address::repeat_byte(0xaa);
+const USDC: Address = Address::repeat_byte(0x70);
+const NFT: Address = Address::repeat_byte(0x71);
+
+fn mock_scan() -> ActivityScan {
+    let event = |block: u64, kind, from, to, amount, token_id: Option<U256>| ActivityEvent {
+        block,
+        tx_hash: B256::repeat_byte(block as u8),
+        log_index: 0,
+        token: if token_id.is_some() { NFT } else { USDC },
+        kind,
+        from,
+        to,
+        amount,
+        token_id,
+    };
+    let other = Address::repeat_byte(0x11);
+    ActivityScan {
+        address: HOLDER,
+        from_block: 18_950_001,
+        to_block: 19_000_000,
+        scanned_from: 18_950_001,
+        events: vec![
+            event(
+                19_000_000,
+                ActivityKind::Approval,
+                HOLDER,
+                other,
+                Some(U256::MAX),
+                None,
+            ),
+            event(
+                18_990_000,
+                ActivityKind::Transfer,
+                other,
+                HOLDER,
+                None,
+                Some(U256::from(7)),
+            ),
+            event(
+                18_980_000,
+                ActivityKind::Transfer,
+                HOLDER,
+                other,
+                Some(U256::from(2_500_000)),
+                None,
+            ),
+        ],
+        holdings: vec![
+            TokenHolding {
+                token: USDC,
+                symbol: Some("USDC".to_string()),
+                decimals: Some(6),
+                sent: U256::from(2_500_000),
+                ..Default::default()
+            },
+            TokenHolding {
+                token: NFT,
+                symbol: Some("BAYC".to_string()),
+                token_ids: vec![U256::from(7)],
+                nft: true,
+                ..Default::default()
+            },
+        ],
+    }
+}
+
+fn activity_screen(scan: ActivityScan) -> Screen {
+    Screen::Activity(ActivityResult {
+        scan,
+        selected_index: 0,
+        list_mode: true,
+    })
+}
+
+#[test]
+fn test_activity_screen_shows_summary_and_holdings() {
+    let app = create_test_app(activity_screen(mock_scan()), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Token Activity"));
+    assert!(buffer_contains(
+        &buffer,
+        "18950001 – 19000000 (50000 blocks)"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "2 transfers • 1 approvals • 2 tokens"
+    ));
+    assert!(buffer_contains(&buffer, "net -2.5"));
+    assert!(buffer_contains(&buffer, "ERC-721 holds #7"));
+}
+
+#[test]
+fn test_activity_screen_lists_events() {
+    let app = create_test_app(activity_screen(mock_scan()), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(
+        &buffer,
+        "Transfers & Approvals (3) [selected]"
+    ));
+    assert!(buffer_contains(&buffer, "APPROVE"));
+    assert!(buffer_contains(&buffer, "unlimited USDC"));
+    assert!(buffer_contains(&buffer, "#7 BAYC"));
+    assert!(buffer_contains(&buffer, "OUT"));
+    assert!(buffer_contains(&buffer, "2.5000 USDC"));
+    assert!(buffer_contains(&buffer, "18980000"));
+}
+
+#[test]
+fn test_activity_screen_empty_and_truncated() {
+    let scan = ActivityScan {
+        events: vec![],
+        holdings: vec![],
+        scanned_from: 18_990_000,
+        ..mock_scan()
+    };
+    let app = create_test_app(activity_screen(scan), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(
+        &buffer,
+        "blocks before 18990000 were not scanned"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "No token transfers in these blocks"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "No Transfer or Approval events involve this address"
+    ));
+}
+
+#[test]
+fn test_address_screen_shows_activity_prompt() {
+    let prompt = BlockPrompt {
+        input: "18000000".into(),
+        ..Default::default()
+    };
+    let mut info = mock_address_info_eoa();
+    info.block = Some(19_000_000);
+    let screen = Screen::AddressResult(AddressResult {
+        info,
+        selected_link: 0,
+        storage: None,
+        call: None,
+        activity: Some(prompt),
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Scan Activity"));
+    assert!(buffer_contains(&buffer, "From:  18000000"));
+    // The end defaults to the page's block
+    assert!(buffer_contains(&buffer, "To:    19000000"));
+    assert!(buffer_contains(&buffer, "Tab from/to"));
+}
@@ -8,6 +8,9 @@ fn test_address_screen_eoa_shows_address() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_eoa(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -25,6 +28,9 @@ fn test_address_screen_eoa_shows_balance() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_eoa(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -42,6 +48,9 @@ fn test_address_screen_eoa_shows_nonce() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_eoa(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -59,6 +68,9 @@ fn test_address_screen_eoa_shows_token_balances() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_eoa(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -78,6 +90,9 @@ fn test_address_screen_contract_shows_code_size() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_contract(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -96,6 +111,9 @@ fn test_address_screen_contract_shows_token_info() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_contract(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -114,6 +132,9 @@ fn test_address_screen_proxy_shows_implementation() {
     let screen = Screen::AddressResult(AddressResult {
         info: mock_address_info_contract(),
         selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -125,3 +146,293 @@ fn test_address_screen_proxy_shows_implementation() {
             || buffer_contains(&buffer, "43506849") // impl address start
     );
 }
+
+#[test]
+fn test_address_screen_uses_profile_native_symbol() {
+    use tbex::config::Profile;
+
+    let mut config = mock_config();
+    config.profiles.insert(
+        "polygon".to_string(),
+        Profile {
+            rpc_url: "https://polygon.example.com".to_string(),
+            native_symbol: Some("POL".to_string()),
+            ..Default::default()
+        },
+    );
+    config.active_profile = Some("polygon".to_string());
+    let mut app = tbex::app::App::new(config);
+    app.screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_eoa(),
+        selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
+    });
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "POL Balance"));
+    assert!(!buffer_contains(&buffer, "ETH Balance"));
+}
+
+#[test]
+fn test_address_screen_uses_detected_chain_native_symbol() {
+    let mut app = tbex::app::App::new(mock_config());
+    app.network_info = Some(NetworkInfo {
+        chain_id: Some(137),
+        ..mock_network_info()
+    });
+    app.screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_eoa(),
+        selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
+    });
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "POL Balance"));
+}
+
+#[test]
+fn test_address_screen_shows_storage_inspector() {
+    use alloy::primitives::B256;
+    use tbex::app::{BlockPrompt, StorageInspector};
+    use tbex::rpc::StorageRead;
+
+    let mut owner = [0u8; 32];
+    owner[12..].copy_from_slice(&[0x22; 20]);
+    let inspector = StorageInspector {
+        prompt: BlockPrompt {
+            input: "balances[0x1111111111111111111111111111111111111111] @ 3".into(),
+            ..Default::default()
+        },
+        reads: vec![
+            StorageRead {
+                expr: "0".to_string(),
+                block: "19000000".to_string(),
+                slot: B256::ZERO,
+                value: B256::from(owner),
+            },
+            StorageRead {
+                expr: "5".to_string(),
+                block: "latest".to_string(),
+                slot: B256::from(U256::from(5)),
+                value: B256::from(U256::from(1)),
+            },
+        ],
+        ..Default::default()
+    };
+    let screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_contract(),
+        selected_link: 0,
+        storage: Some(inspector),
+        call: None,
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 50);
+
+    assert!(buffer_contains(&buffer, "Storage"));
+    assert!(buffer_contains(&buffer, "balances[0x1111"));
+    assert!(buffer_contains(&buffer, "Block: latest"));
+    // Word decoded as an address
+    assert!(buffer_contains(&buffer, "0 @ 19000000 → slot 0x0"));
+    assert!(buffer_contains(
+        &buffer,
+        "address 0x2222222222222222222222222222222222222222"
+    ));
+    // Word decoded as a bool
+    assert!(buffer_contains(&buffer, "uint 1 • bool true"));
+    assert!(buffer_contains(&buffer, "Tab slot/block"));
+}
+
+#[test]
+fn test_address_screen_storage_inspector_error() {
+    let inspector = tbex::app::StorageInspector {
+        prompt: tbex::app::BlockPrompt {
+            error: Some("Invalid slot: foo".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_contract(),
+        selected_link: 0,
+        storage: Some(inspector),
+        call: None,
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "✗ Invalid slot: foo"));
+}
+
+#[test]
+fn test_address_screen_storage_inspector_defaults_to_page_block() {
+    let mut info = mock_address_info_contract();
+    info.block = Some(19_000_000);
+    let screen = Screen::AddressResult(AddressResult {
+        info,
+        selected_link: 0,
+        storage: Some(tbex::app::StorageInspector::default()),
+        call: None,
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Block: 19000000"));
+}
+
+#[test]
+fn test_address_screen_shows_call_console() {
+    use tbex::app::{BlockPrompt, CallConsole};
+    use tbex::rpc::{CallResult, DecodedParam};
+
+    let console = CallConsole {
+        prompt: BlockPrompt {
+            input: "getReserves()(uint112,uint112,uint32)".into(),
+            block_input: "19000000".into(),
+            ..Default::default()
+        },
+        results: vec![CallResult {
+            call: "owner()(address)".to_string(),
+            block: "latest".to_string(),
+            outputs: vec![DecodedParam {
+                value: "0x2222222222222222222222222222222222222222".to_string(),
+                is_address: true,
+                ty: Some("address".to_string()),
+                ..Default::default()
+            }],
+        }],
+        ..Default::default()
+    };
+    let screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_contract(),
+        selected_link: 0,
+        storage: None,
+        call: Some(console),
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 50);
+
+    assert!(buffer_contains(
+        &buffer,
+        "Call:  getReserves()(uint112,uint112,uint32)"
+    ));
+    assert!(buffer_contains(&buffer, "Block: 19000000"));
+    assert!(buffer_contains(&buffer, "owner()(address) @ latest"));
+    assert!(buffer_contains(
+        &buffer,
+        "0 (address) 0x2222222222222222222222222222222222222222"
+    ));
+    assert!(buffer_contains(&buffer, "Tab call/block"));
+}
+
+#[test]
+fn test_address_screen_call_console_error() {
+    let console = tbex::app::CallConsole {
+        prompt: tbex::app::BlockPrompt {
+            error: Some("Reverted: Ownable: caller is not the owner".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_contract(),
+        selected_link: 0,
+        storage: None,
+        call: Some(console),
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "✗ Reverted: Ownable"));
+}
+
+#[test]
+fn test_address_screen_shows_proxy_kind_and_admin() {
+    let screen = Screen::AddressResult(AddressResult {
+        info: mock_address_info_contract(),
+        selected_link: 1,
+        storage: None,
+        call: None,
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "Proxy: EIP-1967"));
+    assert!(buffer_contains(
+        &buffer,
+        "Implementation: 0x43506849d7c04f9138d1a2050bbf3a0c054402dd"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "Admin: 0x807a96288a1a408dbc13de2b1d087d10356395d2"
+    ));
+}
+
+#[test]
+fn test_address_screen_shows_diamond_facets() {
+    use alloy::primitives::Selector;
+    use tbex::rpc::Facet;
+
+    let mut info = mock_address_info_contract();
+    info.proxy = Some(ProxyInfo {
+        facets: vec![
+            Facet {
+                address: Address::repeat_byte(0x11),
+                selectors: vec![Selector::repeat_byte(1), Selector::repeat_byte(2)],
+            },
+            Facet {
+                address: Address::repeat_byte(0x22),
+                selectors: vec![Selector::repeat_byte(3)],
+            },
+        ],
+        ..ProxyInfo::new(ProxyKind::Diamond, None)
+    });
+    let screen = Screen::AddressResult(AddressResult {
+        info,
+        selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "Proxy: Diamond (EIP-2535)"));
+    assert!(buffer_contains(
+        &buffer,
+        "Facet: 0x1111111111111111111111111111111111111111 (2 selectors)"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "Facet: 0x2222222222222222222222222222222222222222 (1 selector)"
+    ));
+    assert!(!buffer_contains(&buffer, "Implementation"));
+}
+
+#[test]
+fn test_address_screen_shows_historical_block() {
+    let screen = Screen::AddressResult(AddressResult {
+        info: AddressInfo {
+            block: Some(19_000_000),
+            ..mock_address_info_eoa()
+        },
+        selected_link: 0,
+        storage: None,
+        call: None,
+        activity: None,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "EOA @ block 19000000"));
+    assert!(buffer_contains(&buffer, "State at Block: 19000000"));
+}
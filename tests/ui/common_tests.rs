@@ -1,7 +1,7 @@
 //! Common UI tests - error, loading, layout, and navigation
 
 use super::*;
-use tbex::app::{AddressResult, BlockResult, Screen, TxResult};
+use tbex::app::{AddressResult, BlockResult, Screen, StateDiffStatus, TxResult};
 use tbex::rpc::BlockStats;
 
 // ==================== Error Screen Tests ====================
@@ -37,6 +37,14 @@ fn test_loading_screen_shows_message() {
     );
 }
 
+#[test]
+fn test_loading_screen_shows_cancel_hint() {
+    let app = create_test_app(Screen::Loading("Loading...".to_string()), true);
+    let buffer = render_to_buffer(&app, 80, 30);
+
+    assert!(buffer_contains(&buffer, "Backspace cancel"));
+}
+
 // ==================== Navigation Help Tests ====================
 
 #[test]
@@ -56,6 +64,8 @@ fn test_screens_show_navigation_help() {
             selected_link: 0,
             transfer_scroll: 0,
             log_scroll: 0,
+            state_scroll: 0,
+            state_diff: StateDiffStatus::NotLoaded,
         }),
     ];
 
@@ -96,10 +106,15 @@ fn test_small_terminal_renders_without_panic() {
             selected_link: 0,
             transfer_scroll: 0,
             log_scroll: 0,
+            state_scroll: 0,
+            state_diff: StateDiffStatus::NotLoaded,
         }),
         Screen::AddressResult(AddressResult {
             info: mock_address_info_eoa(),
             selected_link: 0,
+            storage: None,
+            call: None,
+            activity: None,
         }),
         Screen::Error("Error".to_string()),
         Screen::Loading("Loading...".to_string()),
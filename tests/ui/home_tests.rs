@@ -66,3 +66,64 @@ fn test_home_screen_no_rpc_shows_setup() {
             || buffer_contains(&buffer, "endpoint")
     );
 }
+
+#[test]
+fn test_home_screen_shows_active_profile() {
+    use tbex::config::Profile;
+
+    let mut config = mock_config();
+    for (name, display_name, chain_id) in [("base", "Base", 8453), ("mainnet", "Ethereum", 1)] {
+        config.profiles.insert(
+            name.to_string(),
+            Profile {
+                rpc_url: format!("https://{name}.example.com"),
+                chain_id: Some(chain_id),
+                display_name: Some(display_name.to_string()),
+                recent_searches: vec![format!("{name}.eth")],
+                ..Default::default()
+            },
+        );
+    }
+    config.active_profile = Some("base".to_string());
+    let mut app = tbex::app::App::new(config);
+    app.network_info = Some(mock_network_info());
+    let buffer = render_to_buffer(&app, 100, 30);
+
+    assert!(buffer_contains(&buffer, "Base (chain 8453)"));
+    assert!(buffer_contains(&buffer, "https://base.example.com"));
+    assert!(buffer_contains(&buffer, "Ctrl+P profile"));
+    // History belongs to the active profile
+    assert!(buffer_contains(&buffer, "base.eth"));
+    assert!(!buffer_contains(&buffer, "vitalik.eth"));
+}
+
+#[test]
+fn test_home_screen_shows_detected_chain() {
+    let mut app = tbex::app::App::new(mock_config());
+    app.network_info = Some(mock_network_info());
+    let buffer = render_to_buffer(&app, 100, 30);
+
+    assert!(buffer_contains(&buffer, "Chain: Ethereum (1)"));
+    assert!(!buffer_contains(&buffer, "node reports chain"));
+}
+
+#[test]
+fn test_home_screen_warns_on_chain_mismatch() {
+    use tbex::config::Profile;
+
+    let mut config = mock_config();
+    config.profiles.insert(
+        "base".to_string(),
+        Profile {
+            rpc_url: "https://base.example.com".to_string(),
+            chain_id: Some(8453),
+            ..Default::default()
+        },
+    );
+    config.active_profile = Some("base".to_string());
+    let mut app = tbex::app::App::new(config);
+    app.network_info = Some(mock_network_info());
+    let buffer = render_to_buffer(&app, 120, 30);
+
+    assert!(buffer_contains(&buffer, "node reports chain 1"));
+}
@@ -0,0 +1,43 @@
+//! Live blocks page UI tests
+
+use super::*;
+use tbex::app::{LiveBlocksResult, Screen};
+
+fn mock_live_blocks() -> LiveBlocksResult {
+    let mut older = mock_block_info();
+    older.number -= 1;
+    older.builder_tag = None;
+    LiveBlocksResult {
+        blocks: vec![mock_block_info(), older],
+        selected_index: 0,
+    }
+}
+
+#[test]
+fn test_live_blocks_shows_block_rows() {
+    let app = create_test_app(Screen::LiveBlocks(mock_live_blocks()), true);
+    let buffer = render_to_buffer(&app, 120, 30);
+
+    assert!(buffer_contains(&buffer, "Live Blocks"));
+    assert!(buffer_contains(&buffer, "#19000000"));
+    assert!(buffer_contains(&buffer, "#18999999"));
+}
+
+#[test]
+fn test_live_blocks_shows_gas_fees_and_builder() {
+    let app = create_test_app(Screen::LiveBlocks(mock_live_blocks()), true);
+    let buffer = render_to_buffer(&app, 120, 30);
+
+    assert!(buffer_contains(&buffer, "15.00M (50%)"));
+    assert!(buffer_contains(&buffer, "30.00 gwei"));
+    assert!(buffer_contains(&buffer, "rsync"));
+    assert!(buffer_contains(&buffer, "Burnt"));
+}
+
+#[test]
+fn test_live_blocks_empty_shows_waiting() {
+    let app = create_test_app(Screen::LiveBlocks(LiveBlocksResult::default()), true);
+    let buffer = render_to_buffer(&app, 100, 20);
+
+    assert!(buffer_contains(&buffer, "Waiting for new blocks"));
+}
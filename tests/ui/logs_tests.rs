@@ -0,0 +1,137 @@
+//! Log query UI tests
+
+use super::*;
+use alloy::primitives::B256;
+use tbex::app::{LogQueryForm, LogsResult};
+use tbex::rpc::{LogEntry, LogPage, LogQuery};
+
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+fn mock_logs() -> LogsResult {
+    let transfer = mock_tx_info_with_transfers().logs.remove(0);
+    let unknown = DecodedLog {
+        address: USDC.to_string(),
+        topics: vec![format!("{:?}", B256::repeat_byte(0xee))],
+        data: "0x1234".to_string(),
+        event_name: None,
+        decoded_params: vec![],
+    };
+    LogsResult {
+        query: LogQuery::parse(USDC, "", ["", "", ""]).unwrap(),
+        from_block: 18_990_001,
+        page: LogPage {
+            to_block: 19_000_000,
+            scanned_from: 18_995_000,
+            entries: vec![
+                LogEntry {
+                    block: 19_000_000,
+                    tx_hash: B256::repeat_byte(0xaa),
+                    log_index: 12,
+                    log: transfer,
+                },
+                LogEntry {
+                    block: 18_999_000,
+                    tx_hash: B256::repeat_byte(0xbb),
+                    log_index: 3,
+                    log: unknown,
+                },
+            ],
+        },
+        selected_index: 0,
+        pending: false,
+        error: None,
+    }
+}
+
+#[test]
+fn test_log_query_form() {
+    let mut form = LogQueryForm::default();
+    form.inputs[0] = USDC.into();
+    form.focus = 1;
+    let app = create_test_app(Screen::LogQuery(form), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Log Query"));
+    assert!(buffer_contains(&buffer, &format!("Address:  {USDC}")));
+    // Empty fields show what they accept
+    assert!(buffer_contains(
+        &buffer,
+        "Event:    Transfer(address indexed from"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "From:     10000 blocks before the end"
+    ));
+    assert!(buffer_contains(&buffer, "To:       latest"));
+    assert!(buffer_contains(&buffer, "Tab/↑↓ fields"));
+}
+
+#[test]
+fn test_log_query_form_error() {
+    let form = LogQueryForm {
+        error: Some("Invalid address: 0x123".to_string()),
+        ..Default::default()
+    };
+    let app = create_test_app(Screen::LogQuery(form), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "✗ Invalid address: 0x123"));
+}
+
+#[test]
+fn test_logs_screen_lists_entries() {
+    let app = create_test_app(Screen::Logs(mock_logs()), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Event Logs"));
+    assert!(buffer_contains(&buffer, &format!("Any event on {}", USDC.to_lowercase())));
+    assert!(buffer_contains(
+        &buffer,
+        "18995000 – 19000000 (5001 blocks)"
+    ));
+    assert!(buffer_contains(&buffer, "Logs (2)"));
+    assert!(buffer_contains(&buffer, "Transfer"));
+    assert!(buffer_contains(
+        &buffer,
+        "Press n for logs before block 18995000"
+    ));
+
+    // The selected log shows its decoded parameters
+    assert!(buffer_contains(
+        &buffer,
+        "Transfer(address,address,uint256)"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "from: 0x1111111111111111111111111111111111111111"
+    ));
+}
+
+#[test]
+fn test_logs_screen_undecoded_and_loaded() {
+    let mut result = mock_logs();
+    result.selected_index = 1;
+    result.page.scanned_from = result.from_block;
+    let app = create_test_app(Screen::Logs(result), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Unknown Event"));
+    assert!(buffer_contains(
+        &buffer,
+        &format!("topic0: {:?}", B256::repeat_byte(0xee))
+    ));
+    assert!(buffer_contains(&buffer, "data: 0x1234"));
+    assert!(buffer_contains(&buffer, "All blocks in the range loaded"));
+}
+
+#[test]
+fn test_logs_screen_empty_and_pending() {
+    let mut result = mock_logs();
+    result.page.entries.clear();
+    result.pending = true;
+    let app = create_test_app(Screen::Logs(result), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "No logs match in these blocks"));
+    assert!(buffer_contains(&buffer, "Loading older logs..."));
+}
@@ -0,0 +1,104 @@
+//! Mempool UI tests
+
+use super::*;
+use alloy::primitives::B256;
+use tbex::app::{MempoolResult, MempoolSource};
+use tbex::rpc::{Mempool, PoolTx};
+
+fn mock_pool_tx(from: u8, nonce: u64, queued: bool) -> PoolTx {
+    PoolTx {
+        hash: B256::repeat_byte(from.wrapping_add(nonce as u8)),
+        from: Address::repeat_byte(from),
+        to: Some(Address::repeat_byte(0x33)),
+        nonce,
+        value: U256::from(10).pow(U256::from(18)),
+        gas_limit: 21_000,
+        max_fee: 30_000_000_000,
+        priority_fee: Some(2_000_000_000),
+        tx_type: TxType::EIP1559,
+        method: None,
+        queued,
+    }
+}
+
+fn mock_mempool(source: MempoolSource) -> MempoolResult {
+    MempoolResult {
+        source,
+        pool: Mempool {
+            pending: 2,
+            queued: 1,
+            txs: vec![
+                mock_pool_tx(0x11, 9, false),
+                mock_pool_tx(0x11, 10, false),
+                mock_pool_tx(0x11, 12, true),
+                PoolTx {
+                    to: None,
+                    priority_fee: None,
+                    ..mock_pool_tx(0x22, 0, false)
+                },
+            ],
+        },
+        selected_index: 0,
+        error: None,
+    }
+}
+
+#[test]
+fn test_mempool_txpool_snapshot() {
+    let app = create_test_app(Screen::Mempool(mock_mempool(MempoolSource::TxPool)), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "Mempool"));
+    assert!(buffer_contains(
+        &buffer,
+        "txpool_content • 2 pending • 1 queued • 2 senders"
+    ));
+    assert!(buffer_contains(&buffer, "Transactions by Sender (4)"));
+    assert!(buffer_contains(&buffer, "Max fee / tip"));
+    assert!(buffer_contains(&buffer, "QUEUED"));
+    assert!(buffer_contains(&buffer, "PENDING"));
+    assert!(buffer_contains(&buffer, "Contract creation"));
+    assert!(buffer_contains(&buffer, "30.00 gwei / 2.00 gwei"));
+
+    // A sender is shown once, on its lowest nonce
+    let rendered = buffer_to_string(&buffer);
+    assert_eq!(rendered.matches("0x11111111...111111").count(), 1);
+}
+
+#[test]
+fn test_mempool_subscription_feed() {
+    let app = create_test_app(
+        Screen::Mempool(mock_mempool(MempoolSource::Subscription)),
+        true,
+    );
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(
+        &buffer,
+        "newPendingTransactions (node has no txpool API)"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "Pending Transactions (4) [following]"
+    ));
+    // Every row names its sender
+    let rendered = buffer_to_string(&buffer);
+    assert_eq!(rendered.matches("0x11111111...111111").count(), 3);
+}
+
+#[test]
+fn test_mempool_unavailable() {
+    let result = MempoolResult {
+        error: Some("the method txpool_content does not exist".to_string()),
+        ..Default::default()
+    };
+    let app = create_test_app(Screen::Mempool(result), true);
+    let buffer = render_to_buffer(&app, 120, 40);
+
+    assert!(buffer_contains(&buffer, "txpool_content unavailable"));
+    assert!(buffer_contains(
+        &buffer,
+        "✗ the method txpool_content does not exist"
+    ));
+    assert!(buffer_contains(&buffer, "No transactions to show"));
+}
@@ -3,17 +3,24 @@
 //! These tests ensure the UI renders correctly by comparing against expected buffer output.
 //! Run with: cargo test --test ui_tests
 
+pub mod activity_tests;
 pub mod address_tests;
 pub mod block_tests;
 pub mod common_tests;
 pub mod home_tests;
+pub mod live_tests;
+pub mod logs_tests;
+pub mod mempool_tests;
+pub mod signature_tests;
+pub mod token_tests;
+pub mod trace_tests;
 pub mod tx_tests;
 
 use tbex::app::{App, Screen};
 use tbex::config::Config;
 use tbex::rpc::{
-    AddressInfo, BlockInfo, DecodedLog, DecodedParam, NetworkInfo, TokenBalance, TokenInfo,
-    TokenTransfer, TxInfo, TxSummary, TxType,
+    AddressInfo, BlockInfo, DecodedLog, DecodedParam, NetworkInfo, ProxyInfo, ProxyKind,
+    TokenBalance, TokenInfo, TokenTransfer, TxInfo, TxSummary, TxType,
 };
 use tbex::ui::draw;
 
@@ -30,6 +37,7 @@ pub fn mock_config() -> Config {
             "vitalik.eth".to_string(),
             "12345678".to_string(),
         ],
+        ..Default::default()
     }
 }
 
@@ -37,11 +45,13 @@ pub fn mock_config_no_rpc() -> Config {
     Config {
         rpc_url: None,
         recent_searches: vec![],
+        ..Default::default()
     }
 }
 
 pub fn mock_network_info() -> NetworkInfo {
     NetworkInfo {
+        chain_id: Some(1),
         latest_block: 19000000,
         gas_price: 30_000_000_000, // 30 gwei
         client_version: "Geth/v1.13.0".to_string(),
@@ -165,8 +175,11 @@ pub fn mock_tx_info() -> TxInfo {
         to_ens: Some("uniswap.eth".to_string()),
         actual_fee: Some(U256::from(3_250_000_000_000_000u128)), // 0.00325 ETH
         decoded_method: Some("transfer(address,uint256)".to_string()),
+        decoded_inputs: Vec::new(),
+        revert_reason: None,
         logs: vec![],
         token_transfers: vec![],
+        state_changes: vec![],
     }
 }
 
@@ -177,7 +190,7 @@ pub fn mock_tx_info_with_transfers() -> TxInfo {
             token_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
             from: "0x1111111111111111111111111111111111111111".to_string(),
             to: "0x2222222222222222222222222222222222222222".to_string(),
-            amount: U256::from(1000_000_000u128), // 1000 USDC
+            amount: U256::from(1_000_000_000u128), // 1000 USDC
             token_symbol: Some("USDC".to_string()),
             decimals: Some(6),
         },
@@ -201,16 +214,19 @@ pub fn mock_tx_info_with_transfers() -> TxInfo {
                     name: "from".to_string(),
                     value: "0x1111111111111111111111111111111111111111".to_string(),
                     is_address: true,
+                    ..Default::default()
                 },
                 DecodedParam {
                     name: "to".to_string(),
                     value: "0x2222222222222222222222222222222222222222".to_string(),
                     is_address: true,
+                    ..Default::default()
                 },
                 DecodedParam {
                     name: "value".to_string(),
                     value: "1000".to_string(),
                     is_address: false,
+                    ..Default::default()
                 },
             ],
         },
@@ -224,11 +240,13 @@ pub fn mock_tx_info_with_transfers() -> TxInfo {
                     name: "dst".to_string(),
                     value: "0x1111111111111111111111111111111111111111".to_string(),
                     is_address: true,
+                    ..Default::default()
                 },
                 DecodedParam {
                     name: "wad".to_string(),
                     value: "1.5".to_string(),
                     is_address: false,
+                    ..Default::default()
                 },
             ],
         },
@@ -244,7 +262,7 @@ pub fn mock_address_info_eoa() -> AddressInfo {
         nonce: 150,
         is_contract: false,
         code_size: None,
-        proxy_impl: None,
+        proxy: None,
         token_info: None,
         ens_name: Some("alice.eth".to_string()),
         owner: None,
@@ -257,7 +275,7 @@ pub fn mock_address_info_eoa() -> AddressInfo {
                     None,
                 )
                 .unwrap(),
-                balance: U256::from(10000_000_000u128), // 10000 USDC
+                balance: U256::from(10_000_000_000u128), // 10000 USDC
                 decimals: 6,
             },
             TokenBalance {
@@ -272,6 +290,7 @@ pub fn mock_address_info_eoa() -> AddressInfo {
                 decimals: 18,
             },
         ],
+        block: None,
     }
 }
 
@@ -283,9 +302,19 @@ pub fn mock_address_info_contract() -> AddressInfo {
         nonce: 1,
         is_contract: true,
         code_size: Some(15000),
-        proxy_impl: Some(
-            Address::parse_checksummed("0x43506849D7C04F9138D1A2050bbF3A0c054402dd", None).unwrap(),
-        ),
+        proxy: Some(ProxyInfo {
+            admin: Some(
+                Address::parse_checksummed("0x807a96288A1A408dBC13DE2b1d087d10356395d2", None)
+                    .unwrap(),
+            ),
+            ..ProxyInfo::new(
+                ProxyKind::Eip1967,
+                Some(
+                    Address::parse_checksummed("0x43506849D7C04F9138D1A2050bbF3A0c054402dd", None)
+                        .unwrap(),
+                ),
+            )
+        }),
         token_info: Some(TokenInfo {
             name: Some("USD Coin".to_string()),
             symbol: Some("USDC".to_string()),
@@ -295,6 +324,7 @@ pub fn mock_address_info_contract() -> AddressInfo {
         ens_name: None,
         owner: Some("0x807a96288A1A408dBC13DE2b1d087d10356395d2".to_string()),
         token_balances: vec![],
+        block: None,
     }
 }
 
@@ -0,0 +1,42 @@
+//! Signature lookup UI tests
+
+use super::*;
+use alloy::primitives::{keccak256, Selector};
+use tbex::rpc::SignatureLookup;
+
+#[test]
+fn test_signature_screen_shows_selector_matches() {
+    let lookup = SignatureLookup {
+        query: "0xa9059cbb".to_string(),
+        selector: Some(Selector::from([0xa9, 0x05, 0x9c, 0xbb])),
+        functions: vec![
+            "transfer(address,uint256)".to_string(),
+            "many_msg_babbage(bytes1)".to_string(),
+        ],
+        ..Default::default()
+    };
+    let app = create_test_app(Screen::Signature(lookup), true);
+    let buffer = render_to_buffer(&app, 100, 30);
+
+    assert!(buffer_contains(&buffer, "Signature Lookup"));
+    assert!(buffer_contains(&buffer, "Selector: 0xa9059cbb"));
+    assert!(buffer_contains(&buffer, "transfer(address,uint256)"));
+    assert!(buffer_contains(&buffer, "many_msg_babbage(bytes1)"));
+    // Only searched-for kinds get a section
+    assert!(!buffer_contains(&buffer, "Events"));
+}
+
+#[test]
+fn test_signature_screen_shows_unknown_topic() {
+    let lookup = SignatureLookup {
+        query: "Foo(uint256)".to_string(),
+        topic: Some(keccak256("Foo(uint256)")),
+        ..Default::default()
+    };
+    let app = create_test_app(Screen::Signature(lookup), true);
+    let buffer = render_to_buffer(&app, 120, 30);
+
+    assert!(buffer_contains(&buffer, "Event Topic: 0x"));
+    assert!(buffer_contains(&buffer, "── Events ──"));
+    assert!(buffer_contains(&buffer, "No known signature"));
+}
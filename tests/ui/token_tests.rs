@@ -0,0 +1,194 @@
+//! Token page UI tests
+
+use super::*;
+use alloy::primitives::B256;
+use tbex::app::{AddressResult, BlockPrompt, TokenLookupForm, TokenResult};
+use tbex::rpc::{ActivityEvent, ActivityKind, TokenFlow, TokenLookup, TokenScan};
+
+const USDC: Address = Address::repeat_byte(0x70);
+const ALICE: Address = Address::repeat_byte(0xaa);
+const BOB: Address = Address::repeat_byte(0xbb);
+
+fn mock_scan() -> TokenScan {
+    let transfer = |block: u64, from, to, amount: u64| ActivityEvent {
+        block,
+        tx_hash: B256::repeat_byte(block as u8),
+        log_index: 0,
+        token: USDC,
+        kind: ActivityKind::Transfer,
+        from,
+        to,
+        amount: Some(U256::from(amount)),
+        token_id: None,
+    };
+    let flow = |account, amount: u64, transfers| TokenFlow {
+        account,
+        amount: U256::from(amount),
+        transfers,
+    };
+    TokenScan {
+        token: USDC,
+        info: TokenInfo {
+            name: Some("USD Coin".to_string()),
+            symbol: Some("USDC".to_string()),
+            decimals: Some(6),
+            total_supply: Some(U256::from(25_000_000_000_000u64)),
+        },
+        from_block: 18_999_001,
+        to_block: 19_000_000,
+        scanned_from: 18_999_001,
+        transfers: vec![
+            transfer(18_999_990, ALICE, BOB, 2_500_000),
+            transfer(18_999_500, Address::ZERO, ALICE, 10_000_000),
+        ],
+        senders: vec![flow(ALICE, 2_500_000, 1)],
+        receivers: vec![flow(ALICE, 10_000_000, 1), flow(BOB, 2_500_000, 1)],
+        volume: U256::from(12_500_000),
+        minted: U256::from(10_000_000),
+        burned: U256::ZERO,
+    }
+}
+
+fn token_screen(scan: TokenScan) -> TokenResult {
+    TokenResult {
+        scan,
+        selected_index: 0,
+        list_mode: true,
+        window: None,
+        lookup: None,
+    }
+}
+
+#[test]
+fn test_token_screen_shows_summary_and_top_accounts() {
+    let app = create_test_app(Screen::Token(token_screen(mock_scan())), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+
+    assert!(buffer_contains(&buffer, "Token: USDC"));
+    assert!(buffer_contains(&buffer, "USD Coin • 0x7070"));
+    assert!(buffer_contains(
+        &buffer,
+        "Total Supply: 25000000 USDC (block 19000000)"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "18999001 – 19000000 (1000 blocks)"
+    ));
+    assert!(buffer_contains(
+        &buffer,
+        "2 • volume 12.5000 • minted 10 • burned 0"
+    ));
+    assert!(buffer_contains(&buffer, "Top Senders [Tab to select]"));
+    assert!(buffer_contains(&buffer, "Top Receivers [Tab to select]"));
+    assert!(buffer_contains(&buffer, "(1 transfers)"));
+    assert!(buffer_contains(&buffer, "w window • l lookup"));
+}
+
+#[test]
+fn test_token_screen_lists_transfers() {
+    let app = create_test_app(Screen::Token(token_screen(mock_scan())), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+
+    assert!(buffer_contains(&buffer, "Transfers (2) [selected]"));
+    assert!(buffer_contains(&buffer, "18999990"));
+    assert!(buffer_contains(&buffer, "2.5000"));
+    // Transfers from the zero address are mints
+    assert!(buffer_contains(&buffer, "MINT"));
+}
+
+#[test]
+fn test_token_screen_selects_receivers_after_senders() {
+    let mut result = token_screen(mock_scan());
+    result.list_mode = false;
+    result.selected_index = 2;
+    let app = create_test_app(Screen::Token(result), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+
+    assert!(buffer_contains(&buffer, "Top Receivers [selected]"));
+    assert!(buffer_contains(&buffer, "Transfers (2) [Tab to select]"));
+}
+
+#[test]
+fn test_token_screen_empty_and_truncated() {
+    let scan = TokenScan {
+        transfers: vec![],
+        senders: vec![],
+        receivers: vec![],
+        scanned_from: 18_999_800,
+        ..mock_scan()
+    };
+    let app = create_test_app(Screen::Token(token_screen(scan)), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+
+    assert!(buffer_contains(
+        &buffer,
+        "blocks before 18999800 were not scanned"
+    ));
+    assert!(buffer_contains(&buffer, "No transfers in these blocks"));
+    assert!(buffer_contains(
+        &buffer,
+        "No Transfer events in these blocks"
+    ));
+}
+
+#[test]
+fn test_token_screen_shows_lookup_results() {
+    let mut form = TokenLookupForm {
+        results: vec![TokenLookup {
+            holder: ALICE,
+            spender: Some(BOB),
+            block: "latest".to_string(),
+            balance: U256::from(7_500_000),
+            allowance: Some(U256::MAX),
+        }],
+        ..Default::default()
+    };
+    form.inputs[0] = format!("{ALICE:?}").into();
+    let mut result = token_screen(mock_scan());
+    result.lookup = Some(form);
+    let app = create_test_app(Screen::Token(result), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+
+    assert!(buffer_contains(&buffer, "Balance & Allowance"));
+    assert!(buffer_contains(&buffer, "Spender: none (balance only)"));
+    assert!(buffer_contains(&buffer, "balance 7.5000 USDC"));
+    assert!(buffer_contains(&buffer, "allowance for 0xbbbb"));
+    assert!(buffer_contains(&buffer, "unlimited"));
+    assert!(buffer_contains(&buffer, "Enter look up"));
+}
+
+#[test]
+fn test_token_screen_shows_window_prompt() {
+    let mut result = token_screen(mock_scan());
+    result.window = Some(BlockPrompt {
+        input: "18990000".into(),
+        ..Default::default()
+    });
+    let app = create_test_app(Screen::Token(result), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+
+    assert!(buffer_contains(&buffer, "Block Window"));
+    assert!(buffer_contains(&buffer, "From:  18990000"));
+    assert!(buffer_contains(&buffer, "To:    latest"));
+    assert!(buffer_contains(&buffer, "Tab from/to"));
+}
+
+#[test]
+fn test_address_screen_offers_token_page_for_tokens() {
+    let screen = |info| {
+        Screen::AddressResult(AddressResult {
+            info,
+            selected_link: 0,
+            storage: None,
+            call: None,
+            activity: None,
+        })
+    };
+    let app = create_test_app(screen(mock_address_info_contract()), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+    assert!(buffer_contains(&buffer, "t token"));
+
+    let app = create_test_app(screen(mock_address_info_eoa()), true);
+    let buffer = render_to_buffer(&app, 140, 40);
+    assert!(!buffer_contains(&buffer, "t token"));
+}
@@ -0,0 +1,131 @@
+//! Call trace page UI tests
+
+use super::*;
+use tbex::app::{App, NavLink, Screen, StateDiffStatus, TraceResult};
+use tbex::rpc::CallTrace;
+
+fn mock_trace() -> TraceResult {
+    let inner = CallTrace {
+        call_type: "DELEGATECALL".to_string(),
+        from: "0x2222222222222222222222222222222222222222".to_string(),
+        to: Some("0x3333333333333333333333333333333333333333".to_string()),
+        gas_used: 5_000,
+        method: Some("transfer(address,uint256)".to_string()),
+        error: Some("execution reverted".to_string()),
+        revert_reason: Some("ERC20: insufficient balance".to_string()),
+        ..Default::default()
+    };
+    let root = CallTrace {
+        call_type: "CALL".to_string(),
+        from: "0x1111111111111111111111111111111111111111".to_string(),
+        to: Some("0x2222222222222222222222222222222222222222".to_string()),
+        value: U256::from(10u64).pow(U256::from(18)),
+        gas_used: 21_000,
+        calls: vec![inner],
+        ..Default::default()
+    };
+    TraceResult::new(
+        "0xaaaa1111bbbb2222cccc3333dddd4444eeee5555ffff6666aaaa7777bbbb8888".to_string(),
+        root,
+    )
+}
+
+#[test]
+fn test_trace_shows_call_tree() {
+    let app = create_test_app(Screen::Trace(mock_trace()), true);
+    let buffer = render_to_buffer(&app, 140, 20);
+
+    assert!(buffer_contains(&buffer, "Call Trace"));
+    assert!(buffer_contains(&buffer, "2 calls"));
+    assert!(buffer_contains(&buffer, "CALL"));
+    assert!(buffer_contains(&buffer, "DELEGATECALL"));
+    assert!(buffer_contains(
+        &buffer,
+        "0x3333333333333333333333333333333333333333"
+    ));
+    assert!(buffer_contains(&buffer, "transfer(address,uint256)"));
+}
+
+#[test]
+fn test_trace_shows_value_and_revert_reason() {
+    let app = create_test_app(Screen::Trace(mock_trace()), true);
+    let buffer = render_to_buffer(&app, 160, 20);
+
+    assert!(buffer_contains(&buffer, "1.000000 ETH"));
+    assert!(buffer_contains(&buffer, "ERC20: insufficient balance"));
+}
+
+#[test]
+fn test_trace_collapsed_hides_children() {
+    let mut trace = mock_trace();
+    trace.toggle_selected();
+    let app = create_test_app(Screen::Trace(trace), true);
+    let buffer = render_to_buffer(&app, 140, 20);
+
+    assert!(buffer_contains(&buffer, "▸"));
+    assert!(!buffer_contains(&buffer, "DELEGATECALL"));
+}
+
+#[test]
+fn test_tx_screen_shows_trace_hint() {
+    let app = create_test_app(
+        Screen::TxResult(tbex::app::TxResult {
+            info: mock_tx_info(),
+            selected_link: 0,
+            transfer_scroll: 0,
+            log_scroll: 0,
+            state_scroll: 0,
+            state_diff: StateDiffStatus::NotLoaded,
+        }),
+        true,
+    );
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "t trace"));
+}
+
+#[test]
+fn test_trace_links_caller_and_target() {
+    let mut app = create_test_app(Screen::Trace(mock_trace()), true);
+    let buffer = render_to_buffer(&app, 140, 20);
+    assert!(buffer_contains(&buffer, "0x11111111...111111 → 0x2222"));
+    assert!(buffer_contains(&buffer, "←→ caller/target"));
+
+    let link = |app: &App| match app.get_selected_link() {
+        Some(NavLink::Address(address)) => address,
+        link => panic!("Expected an address link, got {link:?}"),
+    };
+    assert_eq!(link(&app), "0x2222222222222222222222222222222222222222");
+    app.select_left();
+    assert_eq!(link(&app), "0x1111111111111111111111111111111111111111");
+
+    // The caller stays selected on the next row
+    app.select_next();
+    assert_eq!(link(&app), "0x2222222222222222222222222222222222222222");
+    app.select_right();
+    assert_eq!(link(&app), "0x3333333333333333333333333333333333333333");
+}
+
+#[test]
+fn test_trace_links_created_contract() {
+    let root = CallTrace {
+        call_type: "CREATE2".to_string(),
+        from: "0x1111111111111111111111111111111111111111".to_string(),
+        to: Some("0x4444444444444444444444444444444444444444".to_string()),
+        ..Default::default()
+    };
+    let app = create_test_app(
+        Screen::Trace(TraceResult::new("0xhash".to_string(), root)),
+        true,
+    );
+    let buffer = render_to_buffer(&app, 140, 20);
+
+    assert!(buffer_contains(
+        &buffer,
+        "CREATE2      0x11111111...111111 → 0x4444444444444444444444444444444444444444"
+    ));
+    assert!(matches!(
+        app.get_selected_link(),
+        Some(NavLink::Address(address)) if address == "0x4444444444444444444444444444444444444444"
+    ));
+}
@@ -1,7 +1,7 @@
 //! Transaction page UI tests
 
 use super::*;
-use tbex::app::{Screen, TxResult};
+use tbex::app::{Screen, StateDiffStatus, TxResult};
 
 #[test]
 fn test_tx_screen_shows_hash() {
@@ -10,6 +10,8 @@ fn test_tx_screen_shows_hash() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -29,6 +31,8 @@ fn test_tx_screen_shows_from_to() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -50,6 +54,8 @@ fn test_tx_screen_shows_value() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -69,6 +75,8 @@ fn test_tx_screen_shows_gas_info() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -89,6 +97,8 @@ fn test_tx_screen_shows_status() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -108,6 +118,8 @@ fn test_tx_screen_shows_method() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 100, 40);
@@ -120,6 +132,69 @@ fn test_tx_screen_shows_method() {
     );
 }
 
+#[test]
+fn test_tx_screen_shows_revert_reason() {
+    let mut info = mock_tx_info();
+    info.status = Some(false);
+    info.revert_reason = Some("ERC20InsufficientBalance(0x1111, 5, 10)".to_string());
+    let screen = Screen::TxResult(TxResult {
+        info,
+        selected_link: 0,
+        transfer_scroll: 0,
+        log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 100, 40);
+
+    assert!(buffer_contains(&buffer, "Failed"));
+    assert!(buffer_contains(
+        &buffer,
+        "Revert Reason: ERC20InsufficientBalance(0x1111, 5, 10)"
+    ));
+}
+
+#[test]
+fn test_tx_screen_shows_abi_decoded_arguments() {
+    let mut info = mock_tx_info();
+    info.decoded_inputs = vec![
+        DecodedParam {
+            name: "order".to_string(),
+            value: "(2 fields)".to_string(),
+            ty: Some("tuple".to_string()),
+            children: vec![DecodedParam {
+                name: "maker".to_string(),
+                value: "0x3333333333333333333333333333333333333333".to_string(),
+                is_address: true,
+                ty: Some("address".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        DecodedParam {
+            name: "deadline".to_string(),
+            value: "1700000000".to_string(),
+            ty: Some("uint256".to_string()),
+            ..Default::default()
+        },
+    ];
+    let screen = Screen::TxResult(TxResult {
+        info,
+        selected_link: 0,
+        transfer_scroll: 0,
+        log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 100, 50);
+
+    assert!(buffer_contains(&buffer, "order tuple"));
+    assert!(buffer_contains(&buffer, "maker address: 0x3333"));
+    assert!(buffer_contains(&buffer, "deadline uint256: 1700000000"));
+}
+
 #[test]
 fn test_tx_screen_shows_token_transfers() {
     let screen = Screen::TxResult(TxResult {
@@ -127,6 +202,8 @@ fn test_tx_screen_shows_token_transfers() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 120, 50);
@@ -147,6 +224,8 @@ fn test_tx_screen_shows_logs() {
         selected_link: 0,
         transfer_scroll: 0,
         log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::NotLoaded,
     });
     let app = create_test_app(screen, true);
     let buffer = render_to_buffer(&app, 120, 50);
@@ -159,3 +238,82 @@ fn test_tx_screen_shows_logs() {
             || buffer_contains(&buffer, "Event")
     );
 }
+
+#[test]
+fn test_tx_screen_shows_state_changes() {
+    use alloy::primitives::B256;
+    use tbex::rpc::{AccountStateDiff, StorageChange};
+
+    let mut info = mock_tx_info();
+    info.state_changes = vec![
+        AccountStateDiff {
+            address: "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+            nonce_before: Some(41),
+            nonce_after: Some(42),
+            ..Default::default()
+        },
+        AccountStateDiff {
+            address: "0xdac17f958d2ee523a2206206994597c13d831ec7".to_string(),
+            storage: vec![StorageChange {
+                slot: B256::with_last_byte(2),
+                before: B256::with_last_byte(0x64),
+                after: B256::ZERO,
+            }],
+            ..Default::default()
+        },
+    ];
+    let screen = Screen::TxResult(TxResult {
+        info,
+        selected_link: 0,
+        transfer_scroll: 0,
+        log_scroll: 0,
+        state_scroll: 0,
+        state_diff: StateDiffStatus::Loaded,
+    });
+    let app = create_test_app(screen, true);
+    let buffer = render_to_buffer(&app, 120, 50);
+
+    assert!(buffer_contains(&buffer, "State Changes (2)"));
+    assert!(buffer_contains(&buffer, "Nonce: 41 → 42"));
+    assert!(buffer_contains(&buffer, "Slot 0x2: 0x64 → 0x0"));
+}
+
+#[test]
+fn test_tx_screen_state_changes_on_demand() {
+    let screen = |state_diff| {
+        Screen::TxResult(TxResult {
+            info: mock_tx_info(),
+            selected_link: 0,
+            transfer_scroll: 0,
+            log_scroll: 0,
+            state_scroll: 0,
+            state_diff,
+        })
+    };
+
+    let app = create_test_app(screen(StateDiffStatus::NotLoaded), true);
+    let buffer = render_to_buffer(&app, 120, 50);
+    assert!(buffer_contains(&buffer, "── State Changes ──"));
+    assert!(buffer_contains(&buffer, "Press d to trace them"));
+    assert!(buffer_contains(&buffer, "d state changes"));
+
+    let app = create_test_app(screen(StateDiffStatus::Loading), true);
+    let buffer = render_to_buffer(&app, 120, 50);
+    assert!(buffer_contains(&buffer, "Tracing state changes..."));
+
+    // A missing debug API reads differently from a tx without changes
+    let app = create_test_app(screen(StateDiffStatus::Unsupported), true);
+    let buffer = render_to_buffer(&app, 120, 50);
+    assert!(buffer_contains(
+        &buffer,
+        "This RPC endpoint doesn't offer debug_traceTransaction"
+    ));
+
+    let app = create_test_app(screen(StateDiffStatus::Loaded), true);
+    let buffer = render_to_buffer(&app, 120, 50);
+    assert!(buffer_contains(&buffer, "No state changes"));
+
+    let app = create_test_app(screen(StateDiffStatus::Failed("timed out".into())), true);
+    let buffer = render_to_buffer(&app, 120, 50);
+    assert!(buffer_contains(&buffer, "✗ timed out"));
+}